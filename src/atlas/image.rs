@@ -1,10 +1,12 @@
 use std::fmt;
 use std::fs::File;
-use std::io::Read;
+use std::io::{Cursor, Read};
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use ::image as img;
+use img::AnimationDecoder;
 use vulkano::format::{Format as VkFormat, NumericType as VkFormatType};
 use vulkano::image::{ImageAccess, ImageDimensions as VkImgDimensions, SampleCount};
 
@@ -97,6 +99,29 @@ pub struct Image {
     pub(super) atlas_ready: bool,
 }
 
+/// A single frame of an animated image.
+#[derive(Debug, Clone)]
+pub struct ImageFrame {
+    /// The fully composited frame.
+    pub image: Image,
+    /// How long this frame should be displayed for.
+    pub duration: Duration,
+}
+
+/// Frame durations at or below this are treated as `DEFAULT_FRAME_DURATION`. This matches what
+/// browsers do for gifs that were authored with a zero delay.
+const MIN_FRAME_DURATION: Duration = Duration::from_millis(10);
+const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(100);
+
+/// Returns the duration a frame is displayed for. See `MIN_FRAME_DURATION`.
+pub(super) fn frame_duration(duration: Duration) -> Duration {
+    if duration <= MIN_FRAME_DURATION {
+        DEFAULT_FRAME_DURATION
+    } else {
+        duration
+    }
+}
+
 fn image_atlas_compatible(img: &dyn ImageAccess) -> Result<(), String> {
     if img.samples() != SampleCount::Sample1 {
         return Err(String::from("Source image must not be multisampled. "));
@@ -220,6 +245,114 @@ impl Image {
         .map_err(|e| format!("Invalid Image: {}", e))
     }
 
+    /// Load all frames of an animated image from bytes. This uses the `image` crate.
+    ///
+    /// Supports animated gif, apng & animated webp. Any other format, or a non-animated image
+    /// of a supported format, results in a single frame with a zero duration.
+    pub fn load_frames_from_bytes(bytes: &[u8]) -> Result<Vec<ImageFrame>, String> {
+        let format = match img::guess_format(bytes) {
+            Ok(ok) => ok,
+            Err(e) => return Err(format!("Failed to guess image type for data: {}", e)),
+        };
+
+        let frames = match format {
+            img::ImageFormat::Gif => {
+                img::codecs::gif::GifDecoder::new(Cursor::new(bytes))
+                    .map_err(|e| format!("Failed to read image: {}", e))?
+                    .into_frames()
+                    .collect_frames()
+            },
+            img::ImageFormat::Png => {
+                let decoder = img::codecs::png::PngDecoder::new(Cursor::new(bytes))
+                    .map_err(|e| format!("Failed to read image: {}", e))?;
+
+                if !decoder.is_apng() {
+                    return Ok(vec![Self::single_frame(bytes)?]);
+                }
+
+                decoder.apng().into_frames().collect_frames()
+            },
+            img::ImageFormat::WebP => {
+                let decoder = img::codecs::webp::WebPDecoder::new(Cursor::new(bytes))
+                    .map_err(|e| format!("Failed to read image: {}", e))?;
+
+                if !decoder.has_animation() {
+                    return Ok(vec![Self::single_frame(bytes)?]);
+                }
+
+                decoder.into_frames().collect_frames()
+            },
+            _ => return Ok(vec![Self::single_frame(bytes)?]),
+        }
+        .map_err(|e| format!("Failed to decode frames: {}", e))?;
+
+        if frames.is_empty() {
+            return Err(String::from("Image contains no frames."));
+        }
+
+        frames
+            .into_iter()
+            .map(|frame| {
+                let (numer, denom) = frame.delay().numer_denom_ms();
+                let duration = frame_duration(Duration::from_micros(
+                    (numer as u64 * 1000) / denom.max(1) as u64,
+                ));
+
+                let buffer = frame.into_buffer();
+
+                let image = Image::new(
+                    ImageType::LRGBA,
+                    ImageDims {
+                        w: buffer.width(),
+                        h: buffer.height(),
+                    },
+                    ImageData::D8(buffer.into_raw()),
+                )
+                .map_err(|e| format!("Invalid Image: {}", e))?;
+
+                Ok(ImageFrame {
+                    image,
+                    duration,
+                })
+            })
+            .collect()
+    }
+
+    fn single_frame(bytes: &[u8]) -> Result<ImageFrame, String> {
+        Ok(ImageFrame {
+            image: Self::load_from_bytes(bytes)?,
+            duration: Duration::ZERO,
+        })
+    }
+
+    /// Load all frames of an animated image from a path. This reads the file and passes it to
+    /// `Image::load_frames_from_bytes()`.
+    pub fn load_frames_from_path<P: AsRef<Path>>(path: P) -> Result<Vec<ImageFrame>, String> {
+        let mut handle = match File::open(path) {
+            Ok(ok) => ok,
+            Err(e) => return Err(format!("Failed to open file: {}", e)),
+        };
+
+        let mut bytes = Vec::new();
+
+        if let Err(e) = handle.read_to_end(&mut bytes) {
+            return Err(format!("Failed to read file: {}", e));
+        }
+
+        Self::load_frames_from_bytes(&bytes)
+    }
+
+    /// Load all frames of an animated image from a url. This uses `curl` to fetch the data from
+    /// the url and pass it to `Image::load_frames_from_bytes()`.
+    pub fn load_frames_from_url<U: AsRef<str>>(url: U) -> Result<Vec<ImageFrame>, String> {
+        let bytes = match http::get_bytes(url) {
+            Ok(ok) => ok,
+            Err(e) => return Err(format!("Failed to retreive url data: {}", e)),
+        };
+
+        Self::load_frames_from_bytes(&bytes)
+    }
+
     /// Load an image from a path. This reads the file and passes it to `Image::load_from_bytes()`.
     pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
         let mut handle = match File::open(path) {
@@ -1,8 +1,12 @@
 pub mod image;
+#[cfg(feature = "svg")]
+pub mod svg;
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+#[cfg(feature = "svg")]
+use std::time::SystemTime;
 use std::time::{Duration, Instant};
 use std::{iter, thread};
 
@@ -35,7 +39,9 @@ use vulkano::memory::allocator::{AllocationCreateInfo, MemoryUsage, StandardMemo
 use vulkano::sampler::{Sampler, SamplerCreateInfo};
 use vulkano::sync::GpuFuture;
 
-pub use self::image::{Image, ImageData, ImageDims, ImageType};
+pub use self::image::{Image, ImageData, ImageDims, ImageFrame, ImageType};
+#[cfg(feature = "svg")]
+pub use self::svg::SvgImage;
 use crate::image_view::BstImageView;
 
 const ATLAS_IMAGE_COUNT: usize = 4;
@@ -53,6 +59,11 @@ pub enum SubImageCacheID {
     Path(PathBuf),
     Url(String),
     Glyph(CacheKey),
+    /// An svg, as of its modified time, rasterized to the width & height.
+    #[cfg(feature = "svg")]
+    Svg(PathBuf, Option<SystemTime>, u32, u32),
+    /// A gradient ramp keyed by a hash of everything used to rasterize it.
+    Gradient(u64),
     #[default]
     None,
 }
@@ -80,6 +91,13 @@ pub enum AtlasCacheCtrl {
     Seconds(u64),
 }
 
+/// A frame of an animated image stored in the `Atlas`.
+#[derive(Clone)]
+pub struct AtlasFrame {
+    pub coords: AtlasCoords,
+    pub duration: Duration,
+}
+
 /// Coordinates used for referencing an image stored in the `Atlas`.
 ///
 /// # Notes
@@ -333,6 +351,9 @@ pub struct Atlas {
     nearest_sampler: Arc<Sampler>,
     unparker: Unparker,
     image_views: Mutex<Option<(Instant, Arc<HashMap<AtlasImageID, Arc<BstImageView>>>)>>,
+    /// The size defined by an svg along with the modified time of the file it was read from.
+    #[cfg(feature = "svg")]
+    svg_sizes: Mutex<HashMap<PathBuf, (Option<SystemTime>, [f32; 2])>>,
 }
 
 impl Atlas {
@@ -463,6 +484,8 @@ impl Atlas {
             empty_image,
             cmd_send,
             image_views: Mutex::new(None),
+            #[cfg(feature = "svg")]
+            svg_sizes: Mutex::new(HashMap::new()),
         });
 
         let atlas = atlas_ret.clone();
@@ -1055,6 +1078,129 @@ impl Atlas {
 
         self.load_image(cache_id, cache_ctrl, Image::load_from_url(url)?, metadata)
     }
+
+    /// Load an svg from a path rasterized at the provided scale.
+    ///
+    /// Each size the svg is rasterized at is cached separately, so changing the scale will not
+    /// invalidate previous rasterizations until they are removed per `AtlasCacheCtrl`.
+    ///
+    /// # Notes
+    /// - The size defined by the svg is remembered per path, so the svg is only parsed again
+    /// when it needs to be rasterized at a new size or the file has been modified.
+    /// - Rasterizations are cached by the modified time of the file, so a modified svg is
+    /// rasterized again.
+    #[cfg(feature = "svg")]
+    pub fn load_svg<P: AsRef<Path>>(
+        &self,
+        cache_ctrl: AtlasCacheCtrl,
+        path: P,
+        scale: f32,
+        metadata: Vec<u8>,
+    ) -> Result<AtlasCoords, String> {
+        let path = path.as_ref();
+        let modified = std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        let cached_size = self
+            .svg_sizes
+            .lock()
+            .get(path)
+            .filter(|(cached_modified, _)| *cached_modified == modified)
+            .map(|(_, size)| *size);
+
+        let (svg_op, size) = match cached_size {
+            Some(size) => (None, size),
+            None => {
+                let svg = SvgImage::load_from_path(path)?;
+                let size = svg.size();
+
+                self.svg_sizes
+                    .lock()
+                    .insert(path.to_path_buf(), (modified, size));
+
+                (Some(svg), size)
+            },
+        };
+
+        let dims = svg::scaled_dims(size, scale);
+        let cache_id = SubImageCacheID::Svg(path.to_path_buf(), modified, dims.w, dims.h);
+
+        if let Some(coords) = self.cache_coords(cache_id.clone()) {
+            return Ok(coords);
+        }
+
+        let svg = match svg_op {
+            Some(svg) => svg,
+            None => SvgImage::load_from_path(path)?,
+        };
+
+        self.load_image(cache_id, cache_ctrl, svg.rasterize(dims)?, metadata)
+    }
+
+    /// Load the frames of an animated image. Each frame is uploaded as its own sub-image.
+    ///
+    /// # Notes
+    /// - Frames are not cached, each call will upload the frames again.
+    /// - Frames with a duration of 10ms or less are displayed for 100ms, the same as browsers.
+    pub fn load_animation(
+        &self,
+        cache_ctrl: AtlasCacheCtrl,
+        frames: Vec<ImageFrame>,
+        metadata: Vec<u8>,
+    ) -> Result<Vec<AtlasFrame>, String> {
+        if frames.is_empty() {
+            return Err(String::from("Animation contains no frames."));
+        }
+
+        frames
+            .into_iter()
+            .map(|frame| {
+                Ok(AtlasFrame {
+                    coords: self.load_image(
+                        SubImageCacheID::None,
+                        cache_ctrl,
+                        frame.image,
+                        metadata.clone(),
+                    )?,
+                    duration: image::frame_duration(frame.duration),
+                })
+            })
+            .collect()
+    }
+
+    /// Load the frames of an animated image from bytes. This uses the `image` crate.
+    ///
+    /// See `Image::load_frames_from_bytes()` for supported formats.
+    pub fn load_animation_from_bytes(
+        &self,
+        cache_ctrl: AtlasCacheCtrl,
+        bytes: Vec<u8>,
+        metadata: Vec<u8>,
+    ) -> Result<Vec<AtlasFrame>, String> {
+        self.load_animation(cache_ctrl, Image::load_frames_from_bytes(&bytes)?, metadata)
+    }
+
+    /// Load the frames of an animated image from a path. This reads the file and passes it to
+    /// `Image::load_frames_from_bytes()`.
+    pub fn load_animation_from_path<P: AsRef<Path>>(
+        &self,
+        cache_ctrl: AtlasCacheCtrl,
+        path: P,
+        metadata: Vec<u8>,
+    ) -> Result<Vec<AtlasFrame>, String> {
+        self.load_animation(cache_ctrl, Image::load_frames_from_path(path)?, metadata)
+    }
+
+    /// Load the frames of an animated image from a url. This uses `curl` to fetch the data.
+    pub fn load_animation_from_url<U: AsRef<str>>(
+        &self,
+        cache_ctrl: AtlasCacheCtrl,
+        url: U,
+        metadata: Vec<u8>,
+    ) -> Result<Vec<AtlasFrame>, String> {
+        self.load_animation(cache_ctrl, Image::load_frames_from_url(url)?, metadata)
+    }
 }
 
 struct SubImage {
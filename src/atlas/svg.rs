@@ -0,0 +1,87 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{self, TreeParsing};
+
+use super::{Image, ImageData, ImageDims, ImageType};
+
+/// A parsed svg that can be rasterized into an `Image` at any size.
+///
+/// # Notes
+/// - Text elements are not converted to paths and will not be displayed.
+pub struct SvgImage {
+    tree: usvg::Tree,
+}
+
+impl SvgImage {
+    /// Parse an svg from bytes. This uses the `resvg` crate.
+    pub fn load_from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let tree = usvg::Tree::from_data(bytes, &usvg::Options::default())
+            .map_err(|e| format!("Failed to parse svg: {}", e))?;
+
+        Ok(Self {
+            tree,
+        })
+    }
+
+    /// Parse an svg from a path. This reads the file and passes it to `SvgImage::load_from_bytes()`.
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let mut handle = match File::open(path) {
+            Ok(ok) => ok,
+            Err(e) => return Err(format!("Failed to open file: {}", e)),
+        };
+
+        let mut bytes = Vec::new();
+
+        if let Err(e) = handle.read_to_end(&mut bytes) {
+            return Err(format!("Failed to read file: {}", e));
+        }
+
+        Self::load_from_bytes(&bytes)
+    }
+
+    /// The size defined by the svg.
+    pub fn size(&self) -> [f32; 2] {
+        [self.tree.size.width(), self.tree.size.height()]
+    }
+
+    /// The dimensions of the svg when rasterized at the provided scale.
+    pub fn scaled_dims(&self, scale: f32) -> ImageDims {
+        scaled_dims(self.size(), scale)
+    }
+
+    /// Rasterize the svg stretching it to the provided dimensions.
+    pub fn rasterize(&self, dims: ImageDims) -> Result<Image, String> {
+        let mut pixmap = Pixmap::new(dims.w, dims.h)
+            .ok_or_else(|| String::from("Svg raster dimensions are invalid."))?;
+
+        let [w, h] = self.size();
+
+        resvg::Tree::from_usvg(&self.tree).render(
+            Transform::from_scale(dims.w as f32 / w, dims.h as f32 / h),
+            &mut pixmap.as_mut(),
+        );
+
+        // Pixmaps are premultiplied, while the atlas expects straight alpha.
+        let data = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+
+        Image::new(ImageType::LRGBA, dims, ImageData::D8(data))
+            .map_err(|e| format!("Invalid Image: {}", e))
+    }
+}
+
+pub(super) fn scaled_dims([w, h]: [f32; 2], scale: f32) -> ImageDims {
+    ImageDims {
+        w: (w * scale).round().max(1.0) as u32,
+        h: (h * scale).round().max(1.0) as u32,
+    }
+}
@@ -5,9 +5,10 @@ use std::time::Duration;
 
 use crate::input::inner::LoopEvent;
 use crate::input::key::KeyCombo;
-use crate::input::state::{HookState, LocalCursorState, LocalKeyState, WindowState};
+use crate::input::state::{ChordState, HookState, LocalCursorState, LocalKeyState, WindowState};
 use crate::input::{
-    Char, Hook, Input, InputError, InputHookCtrl, InputHookID, InputHookTarget, Key, NO_HOOK_WEIGHT,
+    Char, GamepadAxis, Hook, Input, InputError, InputHookCtrl, InputHookID, InputHookTarget, Key,
+    NO_HOOK_WEIGHT,
 };
 use crate::interface::bin::Bin;
 use crate::interval::IntvlHookCtrl;
@@ -48,6 +49,20 @@ impl<'a> InputHookBuilder<'a> {
         InputPressBuilder::start(self, PressOrRelease::Press)
     }
 
+    /// Attach hook to the press of any key.
+    ///
+    /// Requires a proceeding call to either `window` or `bin`.
+    pub fn on_any_press(self) -> InputAnyPressBuilder<'a> {
+        InputAnyPressBuilder::start(self)
+    }
+
+    /// Attach hook to a sequence of key combinations. (e.g. `Ctrl+K` followed by `Ctrl+C`)
+    ///
+    /// Requires a proceeding call to either `window` or `bin`.
+    pub fn on_chord(self) -> InputChordBuilder<'a> {
+        InputChordBuilder::start(self)
+    }
+
     /// Attach hook to a hold event.
     ///
     /// Requires a proceeding call to either `window` or `bin`.
@@ -116,6 +131,13 @@ impl<'a> InputHookBuilder<'a> {
         InputMotionBuilder::start(self)
     }
 
+    /// Attach hook to a gamepad axis event.
+    ///
+    /// Requires a proceeding call to either `window` or `bin`.
+    pub fn on_axis(self) -> InputAxisBuilder<'a> {
+        InputAxisBuilder::start(self)
+    }
+
     fn submit(self) -> Result<InputHookID, InputError> {
         let state = self.hook.ok_or(InputError::NoTrigger)?;
 
@@ -251,6 +273,149 @@ impl<'a> InputPressBuilder<'a> {
     }
 }
 
+/// Builder returned by `on_any_press`.
+pub struct InputAnyPressBuilder<'a> {
+    parent: InputHookBuilder<'a>,
+    weight: i16,
+    method: Option<
+        Box<dyn FnMut(InputHookTarget, &WindowState, Key) -> InputHookCtrl + Send + 'static>,
+    >,
+}
+
+impl<'a> InputAnyPressBuilder<'a> {
+    fn start(parent: InputHookBuilder<'a>) -> Self {
+        Self {
+            parent,
+            weight: NO_HOOK_WEIGHT,
+            method: None,
+        }
+    }
+
+    /// Assigns a weight.
+    ///
+    /// # Notes
+    /// - Higher weights get called first and may not pass events.
+    pub fn weight(mut self, weight: i16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Assign a function to call. The `Key` provided is the key that was pressed.
+    ///
+    /// # Notes
+    /// - Calling this multiple times will not add additional methods.
+    pub fn call<F: FnMut(InputHookTarget, &WindowState, Key) -> InputHookCtrl + Send + 'static>(
+        mut self,
+        method: F,
+    ) -> Self {
+        self.method = Some(Box::new(method));
+        self
+    }
+
+    /// Finish building, validate, and submit it to `Input`.
+    ///
+    /// # Possible Errors
+    /// - `NoMethod`: No method was added. See `call`.
+    /// - `NoTarget`: No call to `bin()` or `window()` was made.
+    pub fn finish(mut self) -> Result<InputHookID, InputError> {
+        if self.method.is_none() {
+            Err(InputError::NoMethod)
+        } else {
+            self.parent.hook = Some(HookState::AnyPress {
+                weight: self.weight,
+                method: self.method.unwrap(),
+            });
+
+            self.parent.submit()
+        }
+    }
+}
+
+/// Builder returned by `on_chord`.
+pub struct InputChordBuilder<'a> {
+    parent: InputHookBuilder<'a>,
+    steps: Vec<Vec<Key>>,
+    weight: i16,
+    method: Option<
+        Box<
+            dyn FnMut(InputHookTarget, &WindowState, &LocalKeyState) -> InputHookCtrl
+                + Send
+                + 'static,
+        >,
+    >,
+}
+
+impl<'a> InputChordBuilder<'a> {
+    fn start(parent: InputHookBuilder<'a>) -> Self {
+        Self {
+            parent,
+            steps: Vec::new(),
+            weight: NO_HOOK_WEIGHT,
+            method: None,
+        }
+    }
+
+    /// Add a combination to the end of the sequence.
+    ///
+    /// # Notes
+    /// - Each call adds a new step to the sequence.
+    /// - If the next combination isn't pressed within `Input::chord_timeout` the sequence is reset.
+    ///
+    /// ```no_run
+    /// // Ctrl+K followed by Ctrl+C
+    /// .combo((Qwerty::LCtrl, Qwerty::K))
+    /// .combo((Qwerty::LCtrl, Qwerty::C))
+    pub fn combo<C: KeyCombo>(mut self, combo: C) -> Self {
+        self.steps.push(combo.into_vec());
+        self
+    }
+
+    /// Assigns a weight.
+    ///
+    /// # Notes
+    /// - Higher weights get called first and may not pass events.
+    pub fn weight(mut self, weight: i16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Assign a function to call once the last combination of the sequence is pressed.
+    ///
+    /// # Notes
+    /// - Calling this multiple times will not add additional methods.
+    pub fn call<
+        F: FnMut(InputHookTarget, &WindowState, &LocalKeyState) -> InputHookCtrl + Send + 'static,
+    >(
+        mut self,
+        method: F,
+    ) -> Self {
+        self.method = Some(Box::new(method));
+        self
+    }
+
+    /// Finish building, validate, and submit it to `Input`.
+    ///
+    /// # Possible Errors
+    /// - `NoKeys`: No call to `combo` was made or a combination is empty.
+    /// - `NoMethod`: No method was added. See `call`.
+    /// - `NoTarget`: No call to `bin()` or `window()` was made.
+    pub fn finish(mut self) -> Result<InputHookID, InputError> {
+        if self.steps.is_empty() || self.steps.iter().any(|keys| keys.is_empty()) {
+            Err(InputError::NoKeys)
+        } else if self.method.is_none() {
+            Err(InputError::NoMethod)
+        } else {
+            self.parent.hook = Some(HookState::Chord {
+                chord: ChordState::new(self.steps),
+                weight: self.weight,
+                method: self.method.unwrap(),
+            });
+
+            self.parent.submit()
+        }
+    }
+}
+
 /// Builder returned by `on_hold`.
 pub struct InputHoldBuilder<'a> {
     parent: InputHookBuilder<'a>,
@@ -877,3 +1042,83 @@ impl<'a> InputMotionBuilder<'a> {
         }
     }
 }
+
+/// Builder returned by `on_axis`.
+pub struct InputAxisBuilder<'a> {
+    parent: InputHookBuilder<'a>,
+    axes: Vec<GamepadAxis>,
+    weight: i16,
+    method: Option<
+        Box<
+            dyn FnMut(InputHookTarget, &WindowState, GamepadAxis, f32) -> InputHookCtrl
+                + Send
+                + 'static,
+        >,
+    >,
+}
+
+impl<'a> InputAxisBuilder<'a> {
+    fn start(parent: InputHookBuilder<'a>) -> Self {
+        Self {
+            parent,
+            axes: Vec::new(),
+            weight: NO_HOOK_WEIGHT,
+            method: None,
+        }
+    }
+
+    /// Only call the method for the provided axis.
+    ///
+    /// # Notes
+    /// - This adds to any previous `axis` call.
+    /// - If this is never called, the method will be called for all axes.
+    pub fn axis(mut self, axis: GamepadAxis) -> Self {
+        if !self.axes.contains(&axis) {
+            self.axes.push(axis);
+        }
+
+        self
+    }
+
+    /// Assigns a weight.
+    ///
+    /// # Notes
+    /// - Higher weights get called first and may not pass events.
+    pub fn weight(mut self, weight: i16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Assign a function to call.
+    ///
+    /// # Notes
+    /// - Calling this multiple times will not add additional methods.
+    pub fn call<
+        F: FnMut(InputHookTarget, &WindowState, GamepadAxis, f32) -> InputHookCtrl + Send + 'static,
+    >(
+        mut self,
+        method: F,
+    ) -> Self {
+        self.method = Some(Box::new(method));
+        self
+    }
+
+    /// Finish building, validate, and submit it to `Input`.
+    ///
+    /// # Possible Errors
+    /// - `NoMethod`: No method was added. See `call`.
+    /// - `NoTarget`: No call to `bin()` or `window()` was made.
+    pub fn finish(mut self) -> Result<InputHookID, InputError> {
+        if self.method.is_none() {
+            Err(InputError::NoMethod)
+        } else {
+            self.parent.hook = Some(HookState::Axis {
+                axes: self.axes,
+                weight: self.weight,
+                method: self.method.unwrap(),
+            });
+
+            self.parent.submit()
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::thread;
+use std::time::Duration;
+
+use crossbeam::channel::Sender;
+use gilrs::{Axis, Button, EventType, Gilrs};
+
+use crate::input::inner::LoopEvent;
+use crate::input::{GamepadAxis, GamepadButton, InputEvent, Key};
+use crate::window::BstWindowID;
+
+/// A gamepad event, gamepads aren't associated to a window so these are sent to the focused window.
+pub(in crate::input) enum GamepadEvent {
+    Press(GamepadButton),
+    Release(GamepadButton),
+    Axis(GamepadAxis, f32),
+}
+
+impl GamepadEvent {
+    pub(in crate::input) fn into_input_event(self, win: BstWindowID) -> InputEvent {
+        match self {
+            Self::Press(button) => {
+                InputEvent::Press {
+                    win,
+                    key: Key::Gamepad(button),
+                }
+            },
+            Self::Release(button) => {
+                InputEvent::Release {
+                    win,
+                    key: Key::Gamepad(button),
+                }
+            },
+            Self::Axis(axis, value) => {
+                InputEvent::Axis {
+                    win,
+                    axis,
+                    value,
+                }
+            },
+        }
+    }
+}
+
+pub(in crate::input) fn begin_loop(event_send: Sender<LoopEvent>) {
+    thread::spawn(move || {
+        let mut gilrs = match Gilrs::new() {
+            Ok(ok) => ok,
+            Err(e) => {
+                println!("[Basalt]: Unable to initialize gamepad support: {}", e);
+                return;
+            },
+        };
+
+        loop {
+            while let Some(gilrs::Event {
+                event, ..
+            }) = gilrs.next_event()
+            {
+                let event = match event {
+                    EventType::ButtonPressed(button, _) => {
+                        GamepadEvent::Press(button_from_gilrs(button))
+                    },
+                    EventType::ButtonReleased(button, _) => {
+                        GamepadEvent::Release(button_from_gilrs(button))
+                    },
+                    EventType::AxisChanged(axis, value, _) => {
+                        GamepadEvent::Axis(axis_from_gilrs(axis), value)
+                    },
+                    _ => continue,
+                };
+
+                if event_send.send(LoopEvent::Gamepad(event)).is_err() {
+                    return;
+                }
+            }
+
+            thread::sleep(Duration::from_millis(4));
+        }
+    });
+}
+
+fn button_from_gilrs(button: Button) -> GamepadButton {
+    match button {
+        Button::South => GamepadButton::South,
+        Button::East => GamepadButton::East,
+        Button::North => GamepadButton::North,
+        Button::West => GamepadButton::West,
+        Button::LeftTrigger => GamepadButton::LeftBumper,
+        Button::RightTrigger => GamepadButton::RightBumper,
+        Button::LeftTrigger2 => GamepadButton::LeftTrigger,
+        Button::RightTrigger2 => GamepadButton::RightTrigger,
+        Button::Select => GamepadButton::Select,
+        Button::Start => GamepadButton::Start,
+        Button::Mode => GamepadButton::Mode,
+        Button::LeftThumb => GamepadButton::LeftThumb,
+        Button::RightThumb => GamepadButton::RightThumb,
+        Button::DPadUp => GamepadButton::DPadUp,
+        Button::DPadDown => GamepadButton::DPadDown,
+        Button::DPadLeft => GamepadButton::DPadLeft,
+        Button::DPadRight => GamepadButton::DPadRight,
+        Button::C => GamepadButton::Other(0),
+        Button::Z => GamepadButton::Other(1),
+        Button::Unknown => GamepadButton::Other(u8::max_value()),
+    }
+}
+
+fn axis_from_gilrs(axis: Axis) -> GamepadAxis {
+    match axis {
+        Axis::LeftStickX => GamepadAxis::LeftStickX,
+        Axis::LeftStickY => GamepadAxis::LeftStickY,
+        Axis::RightStickX => GamepadAxis::RightStickX,
+        Axis::RightStickY => GamepadAxis::RightStickY,
+        Axis::LeftZ => GamepadAxis::LeftTrigger,
+        Axis::RightZ => GamepadAxis::RightTrigger,
+        Axis::DPadX => GamepadAxis::Other(0),
+        Axis::DPadY => GamepadAxis::Other(1),
+        Axis::Unknown => GamepadAxis::Other(u8::max_value()),
+    }
+}
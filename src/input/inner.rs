@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crossbeam::channel::{self, Receiver, Sender};
+use parking_lot::Mutex;
 
+#[cfg(feature = "gamepad")]
+use crate::input::gamepad::GamepadEvent;
+use crate::input::record::{InputRecording, RecordedEvent};
 use crate::input::state::WindowState;
-use crate::input::{proc, Hook, InputEvent, InputHookID};
+use crate::input::{proc, Hook, InputConfig, InputEvent, InputHookID, Key, LogicalKey, Qwerty};
 use crate::interface::bin::BinID;
 use crate::interface::Interface;
 use crate::interval::Interval;
@@ -14,6 +18,8 @@ use crate::window::BstWindowID;
 
 pub(in crate::input) enum LoopEvent {
     Normal(InputEvent),
+    #[cfg(feature = "gamepad")]
+    Gamepad(GamepadEvent),
     Add {
         id: InputHookID,
         hook: Hook,
@@ -28,11 +34,19 @@ pub(in crate::input) enum LoopEvent {
         h: f32,
     },
     Remove(InputHookID),
+    Layout {
+        physical: Qwerty,
+        logical: LogicalKey,
+    },
+    StartRecording,
+    StopRecording(Sender<InputRecording>),
 }
 
 pub(in crate::input) fn begin_loop(
     interface: Arc<Interface>,
     interval: Arc<Interval>,
+    config: Arc<Mutex<InputConfig>>,
+    layout: Arc<Mutex<HashMap<Qwerty, LogicalKey>>>,
     event_send: Sender<LoopEvent>,
     event_recv: Receiver<LoopEvent>,
 ) {
@@ -49,6 +63,7 @@ pub(in crate::input) fn begin_loop(
         }
 
         let mut ss_state: HashMap<BstWindowID, SmoothScroll> = HashMap::new();
+        let mut recording: Option<(Instant, InputRecording)> = None;
         const SS_CYCLES: u16 = 20;
 
         // TODO: Configure frequency of output?
@@ -124,6 +139,19 @@ pub(in crate::input) fn begin_loop(
         }));
 
         while let Ok(event) = event_recv.recv() {
+            // Gamepads go to the focused window like keyboards do, if no window is focused the
+            // event is dropped.
+            #[cfg(feature = "gamepad")]
+            let event = match event {
+                LoopEvent::Gamepad(gamepad_event) => {
+                    match win_state.iter().find(|(_, state)| state.is_focused()) {
+                        Some((win, _)) => LoopEvent::Normal(gamepad_event.into_input_event(*win)),
+                        None => continue,
+                    }
+                },
+                event => event,
+            };
+
             match event {
                 LoopEvent::Add {
                     id,
@@ -138,6 +166,16 @@ pub(in crate::input) fn begin_loop(
                     win,
                     bin,
                 } => {
+                    if let Some((start, recording)) = recording.as_mut() {
+                        recording.events.push((
+                            start.elapsed(),
+                            RecordedEvent::FocusBin {
+                                win,
+                                bin,
+                            },
+                        ));
+                    }
+
                     let window_state = win_state
                         .entry(win)
                         .or_insert_with(|| WindowState::new(win));
@@ -160,19 +198,95 @@ pub(in crate::input) fn begin_loop(
                 } => {
                     proc::scroll(&interface, &mut hooks, &mut win_state, win, true, v, h);
                 },
+                LoopEvent::Layout {
+                    physical,
+                    logical,
+                } => {
+                    if layout.lock().insert(physical, logical) != Some(logical) {
+                        if let Some((start, recording)) = recording.as_mut() {
+                            recording.events.push((
+                                start.elapsed(),
+                                RecordedEvent::Layout {
+                                    physical,
+                                    logical,
+                                },
+                            ));
+                        }
+                    }
+                },
+                LoopEvent::StartRecording => {
+                    // Start with what is known of the layout, changes are recorded as they occur.
+                    let mut known_layout: Vec<_> = layout
+                        .lock()
+                        .iter()
+                        .map(|(physical, logical)| (*physical, *logical))
+                        .collect();
+
+                    known_layout.sort();
+
+                    recording = Some((
+                        Instant::now(),
+                        InputRecording {
+                            events: known_layout
+                                .into_iter()
+                                .map(|(physical, logical)| {
+                                    (
+                                        Duration::ZERO,
+                                        RecordedEvent::Layout {
+                                            physical,
+                                            logical,
+                                        },
+                                    )
+                                })
+                                .collect(),
+                        },
+                    ));
+                },
+                LoopEvent::StopRecording(send) => {
+                    let _ = send.send(
+                        recording
+                            .take()
+                            .map(|(_, recording)| recording)
+                            .unwrap_or_default(),
+                    );
+                },
                 LoopEvent::Normal(event) => {
+                    if let Some((start, recording)) = recording.as_mut() {
+                        recording
+                            .events
+                            .push((start.elapsed(), RecordedEvent::Input(event.clone())));
+                    }
+
                     match event {
                         InputEvent::Press {
                             win,
                             key,
                         } => {
+                            let config = *config.lock();
+
+                            // A physical key also presses what it means under the current layout.
+                            let logical = match key {
+                                Key::Keyboard(physical) => {
+                                    Some(
+                                        layout
+                                            .lock()
+                                            .get(&physical)
+                                            .copied()
+                                            .unwrap_or_else(|| physical.into()),
+                                    )
+                                },
+                                _ => None,
+                            };
+
                             proc::press(
                                 &interface,
                                 &interval,
+                                &config,
                                 &mut hooks,
                                 &mut win_state,
                                 win,
                                 key,
+                                logical,
                             );
                         },
                         InputEvent::Release {
@@ -228,6 +342,13 @@ pub(in crate::input) fn begin_loop(
                         } => {
                             proc::motion(&mut hooks, x, y);
                         },
+                        InputEvent::Axis {
+                            win,
+                            axis,
+                            value,
+                        } => {
+                            proc::axis(&mut hooks, &mut win_state, win, axis, value);
+                        },
                         InputEvent::CursorCapture {
                             win,
                             captured,
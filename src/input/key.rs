@@ -2,11 +2,15 @@
 
 use std::ops::Deref;
 
-/// A keyboard/mouse agnostic type.
+/// A keyboard/mouse/gamepad agnostic type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Key {
+    /// A physical key, this doesn't change with the keyboard layout.
     Keyboard(Qwerty),
+    /// A logical key, this is what the physical key means under the current keyboard layout.
+    Logical(LogicalKey),
     Mouse(MouseButton),
+    Gamepad(GamepadButton),
 }
 
 impl From<Qwerty> for Key {
@@ -15,12 +19,30 @@ impl From<Qwerty> for Key {
     }
 }
 
+impl From<LogicalKey> for Key {
+    fn from(key: LogicalKey) -> Self {
+        Key::Logical(key)
+    }
+}
+
+impl From<char> for Key {
+    fn from(c: char) -> Self {
+        Key::Logical(c.into())
+    }
+}
+
 impl From<MouseButton> for Key {
     fn from(key: MouseButton) -> Self {
         Key::Mouse(key)
     }
 }
 
+impl From<GamepadButton> for Key {
+    fn from(key: GamepadButton) -> Self {
+        Key::Gamepad(key)
+    }
+}
+
 /// A wrapper around `char` that provides some convenience methods.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Char(pub char);
@@ -77,9 +99,17 @@ impl Deref for Char {
 
 /// Trait used for various methods that can take multiple `Key`s.
 ///
-/// A `Key` can be either `Qwerty` or `MouseButton`.
+/// A `Key` can be either `Qwerty`, `LogicalKey`, `char`, `MouseButton` or `GamepadButton`.
 ///
 /// Supports being a lone key, a `Vec`, an `array` or a `tuple`.
+///
+/// # Notes
+/// - `Qwerty` binds the physical key, where as `LogicalKey` & `char` bind the key that has that
+/// meaning under the current keyboard layout. For example `(Qwerty::LCtrl, 'z')` will be
+/// `Ctrl + W` on a physical QWERTY keyboard using the AZERTY layout.
+/// - The meaning of a physical key is learned as it is pressed, see
+/// `Input::last_observed_logical_key`. This doesn't affect matching, as a physical key is
+/// resolved when pressed, but labels from `Input::key_label` assume US QWERTY until then.
 pub trait KeyCombo {
     fn into_vec(self) -> Vec<Key>;
 }
@@ -127,6 +157,133 @@ macro_rules! impl_tuple_combo {
 
 impl_tuple_combo!(A, B, C, D, E, F, G, H);
 
+/// A key as defined by the current keyboard layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogicalKey {
+    /// A key that produces a character. This is always lowercase.
+    Char(char),
+    /// A key that doesn't produce a character such as `Qwerty::Esc` or `Qwerty::LCtrl`.
+    Named(Qwerty),
+}
+
+impl LogicalKey {
+    /// The user-facing label of this key.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Char(c) => c.to_uppercase().collect(),
+            Self::Named(qwerty) => {
+                match qwerty {
+                    Qwerty::Esc => String::from("Esc"),
+                    Qwerty::F1 => String::from("F1"),
+                    Qwerty::F2 => String::from("F2"),
+                    Qwerty::F3 => String::from("F3"),
+                    Qwerty::F4 => String::from("F4"),
+                    Qwerty::F5 => String::from("F5"),
+                    Qwerty::F6 => String::from("F6"),
+                    Qwerty::F7 => String::from("F7"),
+                    Qwerty::F8 => String::from("F8"),
+                    Qwerty::F9 => String::from("F9"),
+                    Qwerty::F10 => String::from("F10"),
+                    Qwerty::F11 => String::from("F11"),
+                    Qwerty::F12 => String::from("F12"),
+                    Qwerty::Backspace => String::from("Backspace"),
+                    Qwerty::Tab => String::from("Tab"),
+                    Qwerty::Caps => String::from("Caps Lock"),
+                    Qwerty::Enter => String::from("Enter"),
+                    Qwerty::LShift | Qwerty::RShift => String::from("Shift"),
+                    Qwerty::LCtrl | Qwerty::RCtrl => String::from("Ctrl"),
+                    Qwerty::LSuper | Qwerty::RSuper => String::from("Super"),
+                    Qwerty::LAlt | Qwerty::RAlt => String::from("Alt"),
+                    Qwerty::Space => String::from("Space"),
+                    Qwerty::PrintScreen => String::from("Print Screen"),
+                    Qwerty::ScrollLock => String::from("Scroll Lock"),
+                    Qwerty::Pause => String::from("Pause"),
+                    Qwerty::Insert => String::from("Insert"),
+                    Qwerty::Home => String::from("Home"),
+                    Qwerty::PageUp => String::from("Page Up"),
+                    Qwerty::Delete => String::from("Delete"),
+                    Qwerty::End => String::from("End"),
+                    Qwerty::PageDown => String::from("Page Down"),
+                    Qwerty::ArrowUp => String::from("Up"),
+                    Qwerty::ArrowDown => String::from("Down"),
+                    Qwerty::ArrowLeft => String::from("Left"),
+                    Qwerty::ArrowRight => String::from("Right"),
+                    Qwerty::TrackMute => String::from("Mute"),
+                    Qwerty::TrackVolDown => String::from("Volume Down"),
+                    Qwerty::TrackVolUp => String::from("Volume Up"),
+                    Qwerty::TrackPlayPause => String::from("Play/Pause"),
+                    Qwerty::TrackBack => String::from("Previous Track"),
+                    Qwerty::TrackNext => String::from("Next Track"),
+                    Qwerty::Unknown(code) => format!("Unknown ({})", code),
+                    qwerty => LogicalKey::from(*qwerty).label(),
+                }
+            },
+        }
+    }
+}
+
+impl From<char> for LogicalKey {
+    fn from(c: char) -> Self {
+        LogicalKey::Char(c.to_lowercase().next().unwrap_or(c))
+    }
+}
+
+/// Converts using a US QWERTY layout.
+impl From<Qwerty> for LogicalKey {
+    fn from(key: Qwerty) -> Self {
+        match key {
+            Qwerty::Tilda => LogicalKey::Char('`'),
+            Qwerty::One => LogicalKey::Char('1'),
+            Qwerty::Two => LogicalKey::Char('2'),
+            Qwerty::Three => LogicalKey::Char('3'),
+            Qwerty::Four => LogicalKey::Char('4'),
+            Qwerty::Five => LogicalKey::Char('5'),
+            Qwerty::Six => LogicalKey::Char('6'),
+            Qwerty::Seven => LogicalKey::Char('7'),
+            Qwerty::Eight => LogicalKey::Char('8'),
+            Qwerty::Nine => LogicalKey::Char('9'),
+            Qwerty::Zero => LogicalKey::Char('0'),
+            Qwerty::Dash => LogicalKey::Char('-'),
+            Qwerty::Equal => LogicalKey::Char('='),
+            Qwerty::Q => LogicalKey::Char('q'),
+            Qwerty::W => LogicalKey::Char('w'),
+            Qwerty::E => LogicalKey::Char('e'),
+            Qwerty::R => LogicalKey::Char('r'),
+            Qwerty::T => LogicalKey::Char('t'),
+            Qwerty::Y => LogicalKey::Char('y'),
+            Qwerty::U => LogicalKey::Char('u'),
+            Qwerty::I => LogicalKey::Char('i'),
+            Qwerty::O => LogicalKey::Char('o'),
+            Qwerty::P => LogicalKey::Char('p'),
+            Qwerty::LSqBracket => LogicalKey::Char('['),
+            Qwerty::RSqBracket => LogicalKey::Char(']'),
+            Qwerty::Backslash => LogicalKey::Char('\\'),
+            Qwerty::A => LogicalKey::Char('a'),
+            Qwerty::S => LogicalKey::Char('s'),
+            Qwerty::D => LogicalKey::Char('d'),
+            Qwerty::F => LogicalKey::Char('f'),
+            Qwerty::G => LogicalKey::Char('g'),
+            Qwerty::H => LogicalKey::Char('h'),
+            Qwerty::J => LogicalKey::Char('j'),
+            Qwerty::K => LogicalKey::Char('k'),
+            Qwerty::L => LogicalKey::Char('l'),
+            Qwerty::SemiColon => LogicalKey::Char(';'),
+            Qwerty::Parenthesis => LogicalKey::Char('\''),
+            Qwerty::Z => LogicalKey::Char('z'),
+            Qwerty::X => LogicalKey::Char('x'),
+            Qwerty::C => LogicalKey::Char('c'),
+            Qwerty::V => LogicalKey::Char('v'),
+            Qwerty::B => LogicalKey::Char('b'),
+            Qwerty::N => LogicalKey::Char('n'),
+            Qwerty::M => LogicalKey::Char('m'),
+            Qwerty::Comma => LogicalKey::Char(','),
+            Qwerty::Period => LogicalKey::Char('.'),
+            Qwerty::Slash => LogicalKey::Char('/'),
+            key => LogicalKey::Named(key),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
 /// Enum of mouse buttons.
 pub enum MouseButton {
@@ -136,6 +293,81 @@ pub enum MouseButton {
     Other(u8),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+/// Enum of gamepad buttons.
+///
+/// Face buttons are named by their position to be agnostic of controller layout.
+pub enum GamepadButton {
+    /// Bottom face button. (A on Xbox, Cross on PlayStation)
+    South,
+    /// Right face button. (B on Xbox, Circle on PlayStation)
+    East,
+    /// Top face button. (Y on Xbox, Triangle on PlayStation)
+    North,
+    /// Left face button. (X on Xbox, Square on PlayStation)
+    West,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Other(u8),
+}
+
+impl GamepadButton {
+    /// Returns `true` if this button is part of the directional pad.
+    pub fn is_dpad(&self) -> bool {
+        matches!(
+            self,
+            Self::DPadUp | Self::DPadDown | Self::DPadLeft | Self::DPadRight
+        )
+    }
+
+    /// The user-facing label of this button.
+    pub fn label(&self) -> String {
+        match self {
+            Self::South => String::from("South"),
+            Self::East => String::from("East"),
+            Self::North => String::from("North"),
+            Self::West => String::from("West"),
+            Self::LeftBumper => String::from("LB"),
+            Self::RightBumper => String::from("RB"),
+            Self::LeftTrigger => String::from("LT"),
+            Self::RightTrigger => String::from("RT"),
+            Self::Select => String::from("Select"),
+            Self::Start => String::from("Start"),
+            Self::Mode => String::from("Mode"),
+            Self::LeftThumb => String::from("LS"),
+            Self::RightThumb => String::from("RS"),
+            Self::DPadUp => String::from("D-Pad Up"),
+            Self::DPadDown => String::from("D-Pad Down"),
+            Self::DPadLeft => String::from("D-Pad Left"),
+            Self::DPadRight => String::from("D-Pad Right"),
+            Self::Other(i) => format!("Button {}", i),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+/// Enum of gamepad axes.
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+    Other(u8),
+}
+
 /// For use when key location matters. May not always correlate to the actual key.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Qwerty {
@@ -234,6 +466,119 @@ pub enum Qwerty {
     Unknown(u32),
 }
 
+impl Qwerty {
+    /// Returns `true` if this key is shift, ctrl, alt or super.
+    pub fn is_modifier(&self) -> bool {
+        matches!(
+            self,
+            Self::LShift
+                | Self::RShift
+                | Self::LCtrl
+                | Self::RCtrl
+                | Self::LAlt
+                | Self::RAlt
+                | Self::LSuper
+                | Self::RSuper
+        )
+    }
+}
+
+/// Every `Qwerty` key with the exception of `Qwerty::Unknown`.
+pub(crate) const QWERTY_KEYS: [Qwerty; 92] = [
+    Qwerty::Esc,
+    Qwerty::F1,
+    Qwerty::F2,
+    Qwerty::F3,
+    Qwerty::F4,
+    Qwerty::F5,
+    Qwerty::F6,
+    Qwerty::F7,
+    Qwerty::F8,
+    Qwerty::F9,
+    Qwerty::F10,
+    Qwerty::F11,
+    Qwerty::F12,
+    Qwerty::Tilda,
+    Qwerty::One,
+    Qwerty::Two,
+    Qwerty::Three,
+    Qwerty::Four,
+    Qwerty::Five,
+    Qwerty::Six,
+    Qwerty::Seven,
+    Qwerty::Eight,
+    Qwerty::Nine,
+    Qwerty::Zero,
+    Qwerty::Dash,
+    Qwerty::Equal,
+    Qwerty::Backspace,
+    Qwerty::Tab,
+    Qwerty::Q,
+    Qwerty::W,
+    Qwerty::E,
+    Qwerty::R,
+    Qwerty::T,
+    Qwerty::Y,
+    Qwerty::U,
+    Qwerty::I,
+    Qwerty::O,
+    Qwerty::P,
+    Qwerty::LSqBracket,
+    Qwerty::RSqBracket,
+    Qwerty::Backslash,
+    Qwerty::Caps,
+    Qwerty::A,
+    Qwerty::S,
+    Qwerty::D,
+    Qwerty::F,
+    Qwerty::G,
+    Qwerty::H,
+    Qwerty::J,
+    Qwerty::K,
+    Qwerty::L,
+    Qwerty::SemiColon,
+    Qwerty::Parenthesis,
+    Qwerty::Enter,
+    Qwerty::LShift,
+    Qwerty::Z,
+    Qwerty::X,
+    Qwerty::C,
+    Qwerty::V,
+    Qwerty::B,
+    Qwerty::N,
+    Qwerty::M,
+    Qwerty::Comma,
+    Qwerty::Period,
+    Qwerty::Slash,
+    Qwerty::RShift,
+    Qwerty::LCtrl,
+    Qwerty::LSuper,
+    Qwerty::LAlt,
+    Qwerty::Space,
+    Qwerty::RAlt,
+    Qwerty::RSuper,
+    Qwerty::RCtrl,
+    Qwerty::PrintScreen,
+    Qwerty::ScrollLock,
+    Qwerty::Pause,
+    Qwerty::Insert,
+    Qwerty::Home,
+    Qwerty::PageUp,
+    Qwerty::Delete,
+    Qwerty::End,
+    Qwerty::PageDown,
+    Qwerty::ArrowUp,
+    Qwerty::ArrowDown,
+    Qwerty::ArrowLeft,
+    Qwerty::ArrowRight,
+    Qwerty::TrackMute,
+    Qwerty::TrackVolDown,
+    Qwerty::TrackVolUp,
+    Qwerty::TrackPlayPause,
+    Qwerty::TrackBack,
+    Qwerty::TrackNext,
+];
+
 impl From<Qwerty> for u32 {
     fn from(key: Qwerty) -> u32 {
         // Linux X11
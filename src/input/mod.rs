@@ -35,26 +35,46 @@
 //!
 //! ##### Motion
 //! Similar to Character, but there are no targets.
+//!
+//! ##### Axis
+//! Same behavior as Character.
+//!
+//! ### Gamepads
+//! Gamepad buttons are treated like any other `Key` and can be used with press, hold & release
+//! hooks. When the `gamepad` feature is enabled, a backend using `gilrs` will send button & axis
+//! events to the window's `Input`. Presses of the directional pad that are not blocked by a
+//! hook will move focus between `Bin`'s that have been marked with `Bin::set_focusable`.
 
 pub mod builder;
+#[cfg(feature = "gamepad")]
+mod gamepad;
 mod inner;
 pub mod key;
 mod proc;
+pub mod record;
+pub mod shortcut;
 pub mod state;
 
+use std::collections::HashMap;
 use std::sync::atomic::{self, AtomicU64};
 use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crossbeam::channel::{self, Sender};
+use parking_lot::Mutex;
 
 use self::inner::LoopEvent;
-pub use self::key::{Char, Key, MouseButton, Qwerty};
+pub use self::key::{Char, GamepadAxis, GamepadButton, Key, LogicalKey, MouseButton, Qwerty};
+use self::record::{InputRecording, RecordedEvent, ReplayTiming};
+use self::shortcut::{ShortcutState, Shortcuts};
 use self::state::HookState;
 use crate::input::builder::InputHookBuilder;
 use crate::interface::bin::{Bin, BinID};
 use crate::interface::Interface;
 use crate::interval::Interval;
 use crate::window::{BasaltWindow, BstWindowID};
+use crate::Basalt;
 
 const NO_HOOK_WEIGHT: i16 = i16::min_value();
 const BIN_FOCUS_KEY: Key = Key::Mouse(MouseButton::Left);
@@ -152,7 +172,8 @@ pub enum InputHookCtrl {
 ///
 /// # Notes
 /// - This type should only be used externally when using a custom window implementation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[rustfmt::skip]
 pub enum InputEvent {
     Press { win: BstWindowID, key: Key },
     Release { win: BstWindowID, key: Key },
@@ -165,6 +186,7 @@ pub enum InputEvent {
     FocusLost { win: BstWindowID },
     Motion { x: f32, y: f32 },
     CursorCapture { win: BstWindowID, captured: bool },
+    Axis { win: BstWindowID, axis: GamepadAxis, value: f32 },
 }
 
 /// An error that is returned by various `Input` related methods.
@@ -229,6 +251,23 @@ impl Hook {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub(in crate::input) struct InputConfig {
+    multi_click_time: Duration,
+    multi_click_distance: f32,
+    chord_timeout: Duration,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            multi_click_time: Duration::from_millis(500),
+            multi_click_distance: 4.0,
+            chord_timeout: Duration::from_millis(1500),
+        }
+    }
+}
+
 /// The main struct for the input system.
 ///
 /// Accessed via `basalt.input_ref()`.
@@ -236,20 +275,45 @@ pub struct Input {
     event_send: Sender<LoopEvent>,
     current_id: AtomicU64,
     interval: Arc<Interval>,
+    config: Arc<Mutex<InputConfig>>,
+    layout: Arc<Mutex<HashMap<Qwerty, LogicalKey>>>,
+    shortcuts: Mutex<ShortcutState>,
+    basalt: Mutex<Weak<Basalt>>,
 }
 
 impl Input {
     pub(crate) fn new(interface: Arc<Interface>, interval: Arc<Interval>) -> Self {
         let (event_send, event_recv) = channel::unbounded();
-        inner::begin_loop(interface, interval.clone(), event_send.clone(), event_recv);
+        let config = Arc::new(Mutex::new(InputConfig::default()));
+        let layout = Arc::new(Mutex::new(HashMap::new()));
+
+        inner::begin_loop(
+            interface,
+            interval.clone(),
+            config.clone(),
+            layout.clone(),
+            event_send.clone(),
+            event_recv,
+        );
+
+        #[cfg(feature = "gamepad")]
+        gamepad::begin_loop(event_send.clone());
 
         Self {
             event_send,
             interval,
+            config,
             current_id: AtomicU64::new(0),
+            layout,
+            shortcuts: Mutex::new(ShortcutState::default()),
+            basalt: Mutex::new(Weak::new()),
         }
     }
 
+    pub(crate) fn attach_basalt(&self, basalt: &Arc<Basalt>) {
+        *self.basalt.lock() = Arc::downgrade(basalt);
+    }
+
     pub(in crate::input) fn event_send(&self) -> Sender<LoopEvent> {
         self.event_send.clone()
     }
@@ -279,6 +343,13 @@ impl Input {
         InputHookBuilder::start(self)
     }
 
+    /// Returns the registry of named keyboard shortcuts.
+    ///
+    /// See the `shortcut` module for more information.
+    pub fn shortcuts(&self) -> Shortcuts {
+        Shortcuts::new(self)
+    }
+
     /// Remove a hook from `Input`.
     ///
     /// # Notes
@@ -310,6 +381,162 @@ impl Input {
         self.event_send.send(LoopEvent::Normal(event)).unwrap();
     }
 
+    /// Set the maximum time between clicks for them to count as a multi-click.
+    ///
+    /// **Default**: `Duration::from_millis(500)`
+    pub fn set_multi_click_time(&self, time: Duration) {
+        self.config.lock().multi_click_time = time;
+    }
+
+    /// Returns the maximum time between clicks for them to count as a multi-click.
+    pub fn multi_click_time(&self) -> Duration {
+        self.config.lock().multi_click_time
+    }
+
+    /// Set the maximum distance in pixels the cursor can move between clicks for them to count
+    /// as a multi-click.
+    ///
+    /// **Default**: `4.0`
+    pub fn set_multi_click_distance(&self, distance: f32) {
+        self.config.lock().multi_click_distance = distance;
+    }
+
+    /// Returns the maximum distance in pixels the cursor can move between clicks for them to
+    /// count as a multi-click.
+    pub fn multi_click_distance(&self) -> f32 {
+        self.config.lock().multi_click_distance
+    }
+
+    /// Set the maximum time between key combinations of a chord.
+    ///
+    /// **Default**: `Duration::from_millis(1500)`
+    pub fn set_chord_timeout(&self, timeout: Duration) {
+        self.config.lock().chord_timeout = timeout;
+    }
+
+    /// Returns the maximum time between key combinations of a chord.
+    pub fn chord_timeout(&self) -> Duration {
+        self.config.lock().chord_timeout
+    }
+
+    /// Start recording every `InputEvent` that `Input` receives.
+    ///
+    /// Changes to the focused `Bin` made with `set_bin_focused` are recorded as well.
+    ///
+    /// # Notes
+    /// - If a recording is already in progress, it is discarded.
+    pub fn start_recording(&self) {
+        self.event_send.send(LoopEvent::StartRecording).unwrap();
+    }
+
+    /// Stop recording and return the `InputRecording`.
+    ///
+    /// # Notes
+    /// - If a recording was never started, the returned recording will be empty.
+    pub fn stop_recording(&self) -> InputRecording {
+        let (send, recv) = channel::bounded(1);
+        self.event_send
+            .send(LoopEvent::StopRecording(send))
+            .unwrap();
+        recv.recv().unwrap()
+    }
+
+    /// Replay an `InputRecording` by sending its events to `Input`.
+    ///
+    /// # Notes
+    /// - This method blocks until all events have been sent.
+    /// - The keyboard layout of the recording is applied and remains in use until keys are
+    /// pressed again.
+    pub fn replay(&self, recording: &InputRecording, timing: ReplayTiming) {
+        let start = Instant::now();
+
+        for (time, event) in recording.events() {
+            if timing == ReplayTiming::Original {
+                if let Some(wait) = time.checked_sub(start.elapsed()) {
+                    thread::sleep(wait);
+                }
+            }
+
+            match event {
+                RecordedEvent::Input(event) => self.send_event(event.clone()),
+                RecordedEvent::FocusBin {
+                    win,
+                    bin,
+                } => {
+                    self.event_send
+                        .send(LoopEvent::FocusBin {
+                            win: *win,
+                            bin: *bin,
+                        })
+                        .unwrap();
+                },
+                RecordedEvent::Layout {
+                    physical,
+                    logical,
+                } => self.set_key_layout(*physical, *logical),
+            }
+        }
+    }
+
+    /// Inform `Input` of what a physical key means under the current keyboard layout.
+    ///
+    /// When a `Key::Keyboard` is pressed, hooks using the `Key::Logical` it means are pressed as
+    /// well. Only a single press/release should be sent with the physical key.
+    ///
+    /// # Notes
+    /// - This method should only be used externally when using a custom window implementation.
+    /// - This should be called before sending the press event for this key.
+    pub fn set_key_layout(&self, physical: Qwerty, logical: LogicalKey) {
+        self.event_send
+            .send(LoopEvent::Layout {
+                physical,
+                logical,
+            })
+            .unwrap();
+    }
+
+    /// Returns what a physical key meant the last time it was pressed.
+    ///
+    /// # Notes
+    /// - The keyboard layout isn't queried from the platform, it is learned as keys are pressed.
+    /// Until a physical key has been pressed the US QWERTY layout is assumed.
+    /// - If the layout changes, keys will retain their old meaning until pressed again.
+    /// - With the winit window, keys that winit doesn't know the meaning of are resolved from the
+    /// character they produce. If they don't produce one, e.g. while `Ctrl` is held, the previous
+    /// meaning is retained.
+    pub fn last_observed_logical_key(&self, physical: Qwerty) -> LogicalKey {
+        self.layout
+            .lock()
+            .get(&physical)
+            .copied()
+            .unwrap_or_else(|| physical.into())
+    }
+
+    /// Returns the user-facing label of a `Key`.
+    ///
+    /// Physical keys are labeled by what they meant the last time they were pressed.
+    /// See `last_observed_logical_key` for more information.
+    ///
+    /// # Notes
+    /// - Until a physical key has been pressed, it is labeled as it is on a US QWERTY keyboard.
+    /// The platform's layout isn't available through winit, so labels shown before any input
+    /// may not match the keyboard.
+    pub fn key_label<K: Into<Key>>(&self, key: K) -> String {
+        match key.into() {
+            Key::Keyboard(qwerty) => self.last_observed_logical_key(qwerty).label(),
+            Key::Logical(logical) => logical.label(),
+            Key::Mouse(button) => {
+                match button {
+                    MouseButton::Left => String::from("Left Click"),
+                    MouseButton::Right => String::from("Right Click"),
+                    MouseButton::Middle => String::from("Middle Click"),
+                    MouseButton::Other(i) => format!("Mouse {}", i),
+                }
+            },
+            Key::Gamepad(button) => button.label(),
+        }
+    }
+
     fn add_hook(&self, hook: Hook) -> InputHookID {
         let id = InputHookID(self.current_id.fetch_add(1, atomic::Ordering::SeqCst));
         self.event_send
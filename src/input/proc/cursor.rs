@@ -21,7 +21,8 @@ pub(in crate::input) fn cursor(
         .or_insert_with(|| WindowState::new(win));
 
     if window_state.update_cursor_pos(x, y) || force {
-        let inside_bin_ids = interface.get_bin_ids_atop(win, x, y);
+        let mut inside_bin_ids = interface.get_bin_ids_atop(win, x, y);
+        interface.overlays().retain_above_modal(&mut inside_bin_ids);
         let focused_bin_id = window_state.focused_bin_id();
         let mut call_leave_on: Vec<(i16, InputHookID, &mut Hook)> = Vec::new();
         let mut enter: Vec<(i16, InputHookID, &mut Hook)> = Vec::new();
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::input::state::WindowState;
+use crate::input::{proc, GamepadButton, Hook, InputHookID};
+use crate::interface::Interface;
+use crate::interval::Interval;
+
+pub(in crate::input) fn focus_nav(
+    interface: &Arc<Interface>,
+    interval: &Arc<Interval>,
+    hooks: &mut HashMap<InputHookID, Hook>,
+    window_state: &mut WindowState,
+    button: GamepadButton,
+) {
+    let direction: [f32; 2] = match button {
+        GamepadButton::DPadUp => [0.0, -1.0],
+        GamepadButton::DPadDown => [0.0, 1.0],
+        GamepadButton::DPadLeft => [-1.0, 0.0],
+        GamepadButton::DPadRight => [1.0, 0.0],
+        _ => return,
+    };
+
+    let candidates: Vec<_> = interface
+        .focusable_bins()
+        .into_iter()
+        .filter(|bin| bin.visible() && !interface.overlays().below_modal(bin))
+        .map(|bin| {
+            let post = bin.post_update();
+            let center = [
+                (post.tlo[0] + post.tro[0]) / 2.0,
+                (post.tlo[1] + post.blo[1]) / 2.0,
+            ];
+            (bin.id(), center)
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        return;
+    }
+
+    let current_op = window_state.focused_bin_id().and_then(|bin_id| {
+        interface.get_bin(bin_id).map(|bin| {
+            let post = bin.post_update();
+            [
+                (post.tlo[0] + post.tro[0]) / 2.0,
+                (post.tlo[1] + post.blo[1]) / 2.0,
+            ]
+        })
+    });
+
+    let next_bin_id = match current_op {
+        Some(current) => {
+            // Distance along the direction is weighed less than distance perpendicular to it,
+            // so that bins that are inline are preferred over ones that are closer diagonally.
+            let mut best_op = None;
+
+            for (bin_id, center) in candidates {
+                if Some(bin_id) == window_state.focused_bin_id() {
+                    continue;
+                }
+
+                let dx = center[0] - current[0];
+                let dy = center[1] - current[1];
+                let along = (dx * direction[0]) + (dy * direction[1]);
+
+                if along <= 0.0 {
+                    continue;
+                }
+
+                let across = ((dx * direction[1]) - (dy * direction[0])).abs();
+                let score = along + (across * 2.0);
+
+                match best_op {
+                    Some((_, best_score)) if best_score <= score => (),
+                    _ => best_op = Some((bin_id, score)),
+                }
+            }
+
+            match best_op {
+                Some((bin_id, _)) => bin_id,
+                None => return,
+            }
+        },
+        None => {
+            // Nothing focused yet, start at the top-left most bin.
+            candidates
+                .into_iter()
+                .min_by(|(_, a), (_, b)| {
+                    (a[1], a[0])
+                        .partial_cmp(&(b[1], b[0]))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap()
+                .0
+        },
+    };
+
+    if let Some((old_bin_id_op, new_bin_id_op)) = window_state.update_focus_bin(Some(next_bin_id)) {
+        proc::bin_focus(interval, hooks, window_state, old_bin_id_op, new_bin_id_op);
+    }
+}
@@ -1,15 +1,19 @@
+pub mod axis;
 pub mod bin_focus;
 pub mod character;
 pub mod cursor;
+pub mod focus_nav;
 pub mod motion;
 pub mod press;
 pub mod release;
 pub mod scroll;
 pub mod window;
 
+pub(in crate::input) use axis::axis;
 pub(in crate::input) use bin_focus::bin_focus;
 pub(in crate::input) use character::character;
 pub(in crate::input) use cursor::cursor;
+pub(in crate::input) use focus_nav::focus_nav;
 pub(in crate::input) use motion::motion;
 pub(in crate::input) use press::press;
 pub(in crate::input) use release::release;
@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::input::state::{HookState, WindowState};
-use crate::input::{proc, Hook, InputHookCtrl, InputHookID, Key, BIN_FOCUS_KEY, NO_HOOK_WEIGHT};
+use crate::input::{
+    proc, Hook, InputConfig, InputHookCtrl, InputHookID, Key, LogicalKey, BIN_FOCUS_KEY,
+    NO_HOOK_WEIGHT,
+};
 use crate::interface::Interface;
 use crate::interval::Interval;
 use crate::window::BstWindowID;
@@ -11,17 +14,26 @@ use crate::window::BstWindowID;
 pub(in crate::input) fn press(
     interface: &Arc<Interface>,
     interval: &Arc<Interval>,
+    config: &InputConfig,
     hooks: &mut HashMap<InputHookID, Hook>,
     win_state: &mut HashMap<BstWindowID, WindowState>,
     win: BstWindowID,
     key: Key,
+    logical: Option<LogicalKey>,
 ) {
     let window_state = win_state
         .entry(win)
         .or_insert_with(|| WindowState::new(win));
 
-    // Returns true if the state changed
-    if window_state.update_key(key, true) {
+    let keys = window_state.press_keys(key, logical);
+    let mut changed = false;
+
+    for key in keys.iter() {
+        changed |= window_state.update_key(*key, true);
+    }
+
+    if changed {
+        let click_count = window_state.update_click_count(key, config);
         let mut proc_in_order: Vec<_> = hooks
             .iter_mut()
             .filter_map(|(hook_id, hook)| {
@@ -32,7 +44,7 @@ pub(in crate::input) fn press(
                             weight,
                             ..
                         } => {
-                            if state.update(key, true) {
+                            if state.update(&keys, true) {
                                 Some((*weight, (hook_id, hook)))
                             } else {
                                 None
@@ -43,7 +55,7 @@ pub(in crate::input) fn press(
                             weight,
                             ..
                         } => {
-                            if state.is_involved(key) {
+                            if state.involves_any(&keys) {
                                 Some((*weight, (hook_id, hook)))
                             } else {
                                 None
@@ -54,12 +66,26 @@ pub(in crate::input) fn press(
                             weight,
                             ..
                         } => {
-                            if state.is_involved(key) {
+                            if state.involves_any(&keys) {
+                                Some((*weight, (hook_id, hook)))
+                            } else {
+                                None
+                            }
+                        },
+                        HookState::Chord {
+                            chord,
+                            weight,
+                            ..
+                        } => {
+                            if chord.press(&keys, window_state, config.chord_timeout) {
                                 Some((*weight, (hook_id, hook)))
                             } else {
                                 None
                             }
                         },
+                        HookState::AnyPress {
+                            weight, ..
+                        } => Some((*weight, (hook_id, hook))),
                         _ => None,
                     }
                 } else {
@@ -70,18 +96,48 @@ pub(in crate::input) fn press(
 
         proc_in_order.sort_by_key(|(weight, _)| Reverse(*weight));
         let mut pass_bin_event = true;
+        let mut pass_focus_nav = false;
         let mut remove_hooks: Vec<InputHookID> = Vec::new();
 
         for (weight, (hook_id, hook)) in proc_in_order {
             match &mut hook.state {
                 HookState::Press {
-                    state,
-                    method,
+                    ..
+                }
+                | HookState::Chord {
+                    ..
+                }
+                | HookState::AnyPress {
                     ..
                 } => {
                     match hook.target_wk.upgrade() {
                         Some(hook_target) => {
-                            match method(hook_target, window_state, state) {
+                            let hook_ctrl = match &mut hook.state {
+                                HookState::Press {
+                                    state,
+                                    method,
+                                    ..
+                                } => {
+                                    state.set_click_count(click_count);
+                                    method(hook_target, window_state, state)
+                                },
+                                HookState::Chord {
+                                    chord,
+                                    method,
+                                    ..
+                                } => {
+                                    let hook_ctrl =
+                                        method(hook_target, window_state, chord.local());
+                                    chord.reset(window_state, &[]);
+                                    hook_ctrl
+                                },
+                                HookState::AnyPress {
+                                    method, ..
+                                } => method(hook_target, window_state, key),
+                                _ => unreachable!(),
+                            };
+
+                            match hook_ctrl {
                                 InputHookCtrl::Retain => (),
                                 InputHookCtrl::RetainNoPass => {
                                     if weight != NO_HOOK_WEIGHT {
@@ -112,7 +168,7 @@ pub(in crate::input) fn press(
                     pressed,
                     ..
                 } => {
-                    if state.update(key, true) {
+                    if state.update(&keys, true) {
                         *pressed = true;
                     }
                 },
@@ -122,7 +178,7 @@ pub(in crate::input) fn press(
                     intvl_id,
                     ..
                 } => {
-                    if state.update(key, true) {
+                    if state.update(&keys, true) {
                         *pressed = true;
                         interval.start(*intvl_id);
                     }
@@ -132,6 +188,8 @@ pub(in crate::input) fn press(
         }
 
         if pass_bin_event && !window_state.is_cursor_captured() {
+            pass_focus_nav = true;
+
             // Check Bin Focus
             if key == BIN_FOCUS_KEY {
                 if let Some((old_bin_id_op, new_bin_id_op)) =
@@ -152,7 +210,7 @@ pub(in crate::input) fn press(
                                     weight,
                                     ..
                                 } => {
-                                    if state.update(key, true) {
+                                    if state.update(&keys, true) {
                                         Some((*weight, (hook_id, hook)))
                                     } else {
                                         None
@@ -163,7 +221,7 @@ pub(in crate::input) fn press(
                                     weight,
                                     ..
                                 } => {
-                                    if state.is_involved(key) {
+                                    if state.involves_any(&keys) {
                                         Some((*weight, (hook_id, hook)))
                                     } else {
                                         None
@@ -174,12 +232,26 @@ pub(in crate::input) fn press(
                                     weight,
                                     ..
                                 } => {
-                                    if state.is_involved(key) {
+                                    if state.involves_any(&keys) {
+                                        Some((*weight, (hook_id, hook)))
+                                    } else {
+                                        None
+                                    }
+                                },
+                                HookState::Chord {
+                                    chord,
+                                    weight,
+                                    ..
+                                } => {
+                                    if chord.press(&keys, window_state, config.chord_timeout) {
                                         Some((*weight, (hook_id, hook)))
                                     } else {
                                         None
                                     }
                                 },
+                                HookState::AnyPress {
+                                    weight, ..
+                                } => Some((*weight, (hook_id, hook))),
                                 _ => None,
                             }
                         } else {
@@ -193,16 +265,46 @@ pub(in crate::input) fn press(
                 for (weight, (hook_id, hook)) in call_in_order {
                     match &mut hook.state {
                         HookState::Press {
-                            state,
-                            method,
+                            ..
+                        }
+                        | HookState::Chord {
+                            ..
+                        }
+                        | HookState::AnyPress {
                             ..
                         } => {
                             match hook.target_wk.upgrade() {
                                 Some(hook_target) => {
-                                    match method(hook_target, window_state, state) {
+                                    let hook_ctrl = match &mut hook.state {
+                                        HookState::Press {
+                                            state,
+                                            method,
+                                            ..
+                                        } => {
+                                            state.set_click_count(click_count);
+                                            method(hook_target, window_state, state)
+                                        },
+                                        HookState::Chord {
+                                            chord,
+                                            method,
+                                            ..
+                                        } => {
+                                            let hook_ctrl =
+                                                method(hook_target, window_state, chord.local());
+                                            chord.reset(window_state, &[]);
+                                            hook_ctrl
+                                        },
+                                        HookState::AnyPress {
+                                            method, ..
+                                        } => method(hook_target, window_state, key),
+                                        _ => unreachable!(),
+                                    };
+
+                                    match hook_ctrl {
                                         InputHookCtrl::Retain => (),
                                         InputHookCtrl::RetainNoPass => {
                                             if weight != NO_HOOK_WEIGHT {
+                                                pass_focus_nav = false;
                                                 break;
                                             }
                                         },
@@ -213,6 +315,7 @@ pub(in crate::input) fn press(
                                             remove_hooks.push(*hook_id);
 
                                             if weight != NO_HOOK_WEIGHT {
+                                                pass_focus_nav = false;
                                                 break;
                                             }
                                         },
@@ -228,7 +331,7 @@ pub(in crate::input) fn press(
                             pressed,
                             ..
                         } => {
-                            if state.update(key, true) {
+                            if state.update(&keys, true) {
                                 *pressed = true;
                             }
                         },
@@ -238,7 +341,7 @@ pub(in crate::input) fn press(
                             intvl_id,
                             ..
                         } => {
-                            if state.update(key, true) {
+                            if state.update(&keys, true) {
                                 *pressed = true;
                                 interval.start(*intvl_id);
                             }
@@ -252,5 +355,13 @@ pub(in crate::input) fn press(
         for hook_id in remove_hooks {
             hooks.remove(&hook_id);
         }
+
+        if pass_focus_nav {
+            if let Key::Gamepad(button) = key {
+                if button.is_dpad() {
+                    proc::focus_nav(interface, interval, hooks, window_state, button);
+                }
+            }
+        }
     }
 }
@@ -18,7 +18,14 @@ pub(in crate::input) fn release(
         .entry(win)
         .or_insert_with(|| WindowState::new(win));
 
-    if window_state.update_key(key, false) {
+    let keys = window_state.release_keys(key);
+    let mut changed = false;
+
+    for key in keys.iter() {
+        changed |= window_state.update_key(*key, false);
+    }
+
+    if changed {
         let focused_bin_id = window_state.focused_bin_id();
         let mut remove_hooks: Vec<InputHookID> = Vec::new();
 
@@ -35,7 +42,8 @@ pub(in crate::input) fn release(
                             weight,
                             ..
                         } => {
-                            if state.is_involved(key) && !state.update(key, false) && *pressed {
+                            if state.involves_any(&keys) && !state.update(&keys, false) && *pressed
+                            {
                                 *pressed = false;
                                 Some((*weight, (hook_id, hook)))
                             } else {
@@ -45,7 +53,7 @@ pub(in crate::input) fn release(
                         HookState::Press {
                             state, ..
                         } => {
-                            state.update(key, false);
+                            state.update(&keys, false);
                             None
                         },
                         HookState::Hold {
@@ -54,13 +62,20 @@ pub(in crate::input) fn release(
                             intvl_id,
                             ..
                         } => {
-                            if state.is_involved(key) && !state.update(key, false) && *pressed {
+                            if state.involves_any(&keys) && !state.update(&keys, false) && *pressed
+                            {
                                 *pressed = false;
                                 interval.pause(*intvl_id);
                             }
 
                             None
                         },
+                        HookState::Chord {
+                            chord, ..
+                        } => {
+                            chord.release(&keys);
+                            None
+                        },
                         _ => None,
                     }
                 } else {
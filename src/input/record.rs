@@ -0,0 +1,500 @@
+//! Recording and replaying of `InputEvent`'s.
+//!
+//! Recordings are started with `Input::start_recording` and finished with
+//! `Input::stop_recording`. They can be saved to a file and replayed later with `Input::replay`.
+//! This is useful to reproduce bugs or script end-to-end tests.
+//!
+//! # Notes
+//! - `Bin`'s focused with `Input::set_bin_focused` are recorded by their `BinID`. Replaying them
+//! requires the interface to be created in the same order as when recorded.
+//! - The keyboard layout known when the recording starts and any changes to it are recorded, so
+//! physical keys resolve to the same logical keys when replayed on a different layout.
+//! - Smooth scrolling is driven by an `Interval` and will not be deterministic when replaying
+//! with `ReplayTiming::Fast`.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::input::shortcut::{key_from_token, key_to_token};
+use crate::input::{GamepadAxis, InputEvent, Key, LogicalKey, Qwerty};
+use crate::interface::bin::BinID;
+use crate::window::BstWindowID;
+
+/// How a `InputRecording` should be replayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplayTiming {
+    /// Events are sent with the same timing as they were recorded.
+    #[default]
+    Original,
+    /// Events are sent as fast as possible.
+    Fast,
+}
+
+/// An event within an `InputRecording`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedEvent {
+    /// An event received by `Input`.
+    Input(InputEvent),
+    /// A `Bin` was focused with `Input::set_bin_focused`.
+    FocusBin {
+        win: BstWindowID,
+        bin: Option<BinID>,
+    },
+    /// The meaning of a physical key under the keyboard layout. See `Input::set_key_layout`.
+    Layout {
+        physical: Qwerty,
+        logical: LogicalKey,
+    },
+}
+
+/// A recording of `InputEvent`'s with the time they were received relative to the start.
+#[derive(Debug, Clone, Default)]
+pub struct InputRecording {
+    pub(in crate::input) events: Vec<(Duration, RecordedEvent)>,
+}
+
+impl InputRecording {
+    /// Returns the events and the time they were received relative to the start of the recording.
+    pub fn events(&self) -> &[(Duration, RecordedEvent)] {
+        &self.events
+    }
+
+    /// Returns the duration from the start of the recording to the last event.
+    pub fn duration(&self) -> Duration {
+        self.events
+            .last()
+            .map(|(time, _)| *time)
+            .unwrap_or_default()
+    }
+
+    /// Save this recording to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let mut out = String::new();
+
+        for (time, event) in self.events.iter() {
+            out.push_str(&format!("{} {}\n", time.as_micros(), event_to_line(event)));
+        }
+
+        std::fs::write(path, out).map_err(|e| format!("Failed to write file: {}", e))
+    }
+
+    /// Load a recording from a file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let mut events = Vec::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            events.push(
+                event_from_line(line).ok_or_else(|| format!("Line {}: invalid event.", i + 1))?,
+            );
+        }
+
+        Ok(Self {
+            events,
+        })
+    }
+}
+
+fn event_to_line(event: &RecordedEvent) -> String {
+    let event = match event {
+        RecordedEvent::Input(event) => event,
+        RecordedEvent::FocusBin {
+            win,
+            bin,
+        } => {
+            return match bin {
+                Some(bin) => format!("FocusBin {} {}", win.0, bin.0),
+                None => format!("FocusBin {} None", win.0),
+            };
+        },
+        RecordedEvent::Layout {
+            physical,
+            logical,
+        } => {
+            return format!(
+                "Layout {} {}",
+                key_to_token(Key::Keyboard(*physical)),
+                key_to_token(Key::Logical(*logical))
+            );
+        },
+    };
+
+    match event {
+        InputEvent::Press {
+            win,
+            key,
+        } => format!("Press {} {}", win.0, key_to_token(*key)),
+        InputEvent::Release {
+            win,
+            key,
+        } => format!("Release {} {}", win.0, key_to_token(*key)),
+        InputEvent::Character {
+            win,
+            c,
+        } => format!("Character {} {}", win.0, *c as u32),
+        InputEvent::Cursor {
+            win,
+            x,
+            y,
+        } => format!("Cursor {} {} {}", win.0, x, y),
+        InputEvent::Scroll {
+            win,
+            v,
+            h,
+        } => format!("Scroll {} {} {}", win.0, v, h),
+        InputEvent::Enter {
+            win,
+        } => format!("Enter {}", win.0),
+        InputEvent::Leave {
+            win,
+        } => format!("Leave {}", win.0),
+        InputEvent::Focus {
+            win,
+        } => format!("Focus {}", win.0),
+        InputEvent::FocusLost {
+            win,
+        } => format!("FocusLost {}", win.0),
+        InputEvent::Motion {
+            x,
+            y,
+        } => format!("Motion {} {}", x, y),
+        InputEvent::CursorCapture {
+            win,
+            captured,
+        } => format!("CursorCapture {} {}", win.0, captured),
+        InputEvent::Axis {
+            win,
+            axis,
+            value,
+        } => {
+            let axis = match axis {
+                GamepadAxis::Other(i) => format!("#{}", i),
+                axis => format!("{:?}", axis),
+            };
+
+            format!("Axis {} {} {}", win.0, axis, value)
+        },
+    }
+}
+
+fn event_from_line(line: &str) -> Option<(Duration, RecordedEvent)> {
+    let mut parts = line.split_whitespace();
+    let time = Duration::from_micros(parts.next()?.parse().ok()?);
+    let ty = parts.next()?;
+
+    let event = if ty == "Layout" {
+        let physical = match key_from_token(parts.next()?)? {
+            Key::Keyboard(physical) => physical,
+            _ => return None,
+        };
+
+        let logical = match key_from_token(parts.next()?)? {
+            Key::Logical(logical) => logical,
+            _ => return None,
+        };
+
+        return Some((
+            time,
+            RecordedEvent::Layout {
+                physical,
+                logical,
+            },
+        ));
+    } else if ty == "Motion" {
+        InputEvent::Motion {
+            x: parts.next()?.parse().ok()?,
+            y: parts.next()?.parse().ok()?,
+        }
+    } else {
+        let win = BstWindowID(parts.next()?.parse().ok()?);
+
+        if ty == "FocusBin" {
+            let bin = match parts.next()? {
+                "None" => None,
+                id => Some(BinID(id.parse().ok()?)),
+            };
+
+            return Some((
+                time,
+                RecordedEvent::FocusBin {
+                    win,
+                    bin,
+                },
+            ));
+        }
+
+        match ty {
+            "Press" => {
+                InputEvent::Press {
+                    win,
+                    key: key_from_token(parts.next()?)?,
+                }
+            },
+            "Release" => {
+                InputEvent::Release {
+                    win,
+                    key: key_from_token(parts.next()?)?,
+                }
+            },
+            "Character" => {
+                InputEvent::Character {
+                    win,
+                    c: char::from_u32(parts.next()?.parse().ok()?)?,
+                }
+            },
+            "Cursor" => {
+                InputEvent::Cursor {
+                    win,
+                    x: parts.next()?.parse().ok()?,
+                    y: parts.next()?.parse().ok()?,
+                }
+            },
+            "Scroll" => {
+                InputEvent::Scroll {
+                    win,
+                    v: parts.next()?.parse().ok()?,
+                    h: parts.next()?.parse().ok()?,
+                }
+            },
+            "Enter" => {
+                InputEvent::Enter {
+                    win,
+                }
+            },
+            "Leave" => {
+                InputEvent::Leave {
+                    win,
+                }
+            },
+            "Focus" => {
+                InputEvent::Focus {
+                    win,
+                }
+            },
+            "FocusLost" => {
+                InputEvent::FocusLost {
+                    win,
+                }
+            },
+            "CursorCapture" => {
+                InputEvent::CursorCapture {
+                    win,
+                    captured: parts.next()?.parse().ok()?,
+                }
+            },
+            "Axis" => {
+                let axis = match parts.next()? {
+                    "LeftStickX" => GamepadAxis::LeftStickX,
+                    "LeftStickY" => GamepadAxis::LeftStickY,
+                    "RightStickX" => GamepadAxis::RightStickX,
+                    "RightStickY" => GamepadAxis::RightStickY,
+                    "LeftTrigger" => GamepadAxis::LeftTrigger,
+                    "RightTrigger" => GamepadAxis::RightTrigger,
+                    other => GamepadAxis::Other(other.strip_prefix('#')?.parse().ok()?),
+                };
+
+                InputEvent::Axis {
+                    win,
+                    axis,
+                    value: parts.next()?.parse().ok()?,
+                }
+            },
+            _ => return None,
+        }
+    };
+
+    Some((time, RecordedEvent::Input(event)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{event_from_line, event_to_line, RecordedEvent};
+    use crate::input::{
+        GamepadAxis, GamepadButton, InputEvent, Key, LogicalKey, MouseButton, Qwerty,
+    };
+    use crate::interface::bin::BinID;
+    use crate::window::BstWindowID;
+
+    fn assert_round_trip(time: Duration, event: RecordedEvent) {
+        let line = format!("{} {}", time.as_micros(), event_to_line(&event));
+        assert_eq!(event_from_line(&line), Some((time, event)), "{}", line);
+    }
+
+    #[test]
+    fn round_trip_input() {
+        let win = BstWindowID(3);
+
+        let events = [
+            InputEvent::Press {
+                win,
+                key: Key::Keyboard(Qwerty::A),
+            },
+            InputEvent::Press {
+                win,
+                key: Key::Logical(LogicalKey::Char(' ')),
+            },
+            InputEvent::Press {
+                win,
+                key: Key::Gamepad(GamepadButton::DPadUp),
+            },
+            InputEvent::Release {
+                win,
+                key: Key::Mouse(MouseButton::Other(7)),
+            },
+            InputEvent::Release {
+                win,
+                key: Key::Logical(LogicalKey::Named(Qwerty::Esc)),
+            },
+            InputEvent::Character {
+                win,
+                c: 'é',
+            },
+            InputEvent::Character {
+                win,
+                c: ' ',
+            },
+            InputEvent::Cursor {
+                win,
+                x: 12.5,
+                y: -0.1,
+            },
+            InputEvent::Scroll {
+                win,
+                v: -1.0,
+                h: 0.333,
+            },
+            InputEvent::Enter {
+                win,
+            },
+            InputEvent::Leave {
+                win,
+            },
+            InputEvent::Focus {
+                win,
+            },
+            InputEvent::FocusLost {
+                win,
+            },
+            InputEvent::Motion {
+                x: 1.25,
+                y: -3.0,
+            },
+            InputEvent::CursorCapture {
+                win,
+                captured: true,
+            },
+            InputEvent::CursorCapture {
+                win,
+                captured: false,
+            },
+            InputEvent::Axis {
+                win,
+                axis: GamepadAxis::RightTrigger,
+                value: 0.75,
+            },
+            InputEvent::Axis {
+                win,
+                axis: GamepadAxis::Other(9),
+                value: -0.5,
+            },
+        ];
+
+        for (i, event) in events.into_iter().enumerate() {
+            assert_round_trip(
+                Duration::from_micros(i as u64 * 16_667),
+                RecordedEvent::Input(event),
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip_focus_bin() {
+        assert_round_trip(
+            Duration::from_micros(5),
+            RecordedEvent::FocusBin {
+                win: BstWindowID(0),
+                bin: Some(BinID(42)),
+            },
+        );
+
+        assert_round_trip(
+            Duration::from_secs(2),
+            RecordedEvent::FocusBin {
+                win: BstWindowID(1),
+                bin: None,
+            },
+        );
+    }
+
+    #[test]
+    fn round_trip_layout() {
+        assert_round_trip(
+            Duration::ZERO,
+            RecordedEvent::Layout {
+                physical: Qwerty::Q,
+                logical: LogicalKey::Char('a'),
+            },
+        );
+
+        assert_round_trip(
+            Duration::from_millis(10),
+            RecordedEvent::Layout {
+                physical: Qwerty::One,
+                logical: LogicalKey::Char('&'),
+            },
+        );
+
+        assert_round_trip(
+            Duration::from_millis(20),
+            RecordedEvent::Layout {
+                physical: Qwerty::Unknown(300),
+                logical: LogicalKey::Named(Qwerty::Unknown(300)),
+            },
+        );
+    }
+
+    #[test]
+    fn reject_malformed_lines() {
+        let lines = [
+            "",
+            "10",
+            "x Press 0 key:A",
+            "-1 Press 0 key:A",
+            "10 Bogus 0",
+            "10 Press",
+            "10 Press x key:A",
+            "10 Press 0",
+            "10 Press 0 key:Nope",
+            "10 Release 0 bogus:A",
+            "10 Character 0",
+            "10 Character 0 55296",
+            "10 Character 0 a",
+            "10 Cursor 0 1.0",
+            "10 Scroll 0 x 1.0",
+            "10 Enter",
+            "10 Motion 1.0",
+            "10 CursorCapture 0 maybe",
+            "10 Axis 0 Sideways 1.0",
+            "10 Axis 0 #300 1.0",
+            "10 Axis 0 LeftStickX",
+            "10 FocusBin 0",
+            "10 FocusBin 0 x",
+            "10 FocusBin x 1",
+            "10 Layout key:A",
+            "10 Layout char:a key:A",
+            "10 Layout key:A key:B",
+            "10 Layout mouse:Left char:a",
+        ];
+
+        for line in lines {
+            assert_eq!(event_from_line(line), None, "{}", line);
+        }
+    }
+}
@@ -0,0 +1,752 @@
+//! Registry of named keyboard shortcuts.
+//!
+//! Shortcuts are registered with a name, a default `KeyCombo` and a `ShortcutScope`. The combo
+//! used can be changed at runtime and saved/loaded to a file allowing users to rebind them.
+//!
+//! ### Dispatch
+//! Shortcuts are dispatched with press hooks on the window. When a shortcut is activated, it will
+//! not pass the event onto lesser weighed hooks. The weights used from highest to lowest are
+//! `BIN_SCOPE_WEIGHT`, `WINDOW_SCOPE_WEIGHT` & `GLOBAL_SCOPE_WEIGHT`. This allows more specific
+//! shortcuts to take precedence over less specific ones.
+//!
+//! ### Conflicts
+//! Two shortcuts conflict when they use the same keys and their scopes overlap. The global scope
+//! overlaps every scope, a window scope overlaps the scopes of `Bin`'s within it and a `Bin` scope
+//! overlaps the scopes of its ancestors and descendants.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Weak};
+
+use parking_lot::Mutex;
+
+use crate::input::key::{KeyCombo, QWERTY_KEYS};
+use crate::input::state::WindowState;
+use crate::input::{
+    GamepadButton, Input, InputHookCtrl, InputHookID, Key, LogicalKey, MouseButton, Qwerty,
+};
+use crate::interface::bin::Bin;
+use crate::window::BasaltWindow;
+
+/// Weight of the hooks used by shortcuts with `ShortcutScope::Bin`.
+pub const BIN_SCOPE_WEIGHT: i16 = 10_002;
+/// Weight of the hooks used by shortcuts with `ShortcutScope::Window`.
+pub const WINDOW_SCOPE_WEIGHT: i16 = 10_001;
+/// Weight of the hooks used by shortcuts with `ShortcutScope::Global`.
+pub const GLOBAL_SCOPE_WEIGHT: i16 = 10_000;
+
+/// Where a shortcut is active.
+#[derive(Debug, Clone)]
+pub enum ShortcutScope {
+    /// Active regardless of what is focused.
+    Global,
+    /// Active while the window is focused.
+    Window(Arc<dyn BasaltWindow>),
+    /// Active while the `Bin` or one of its descendants is focused.
+    Bin(Arc<Bin>),
+}
+
+/// An error that is returned by various shortcut related methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutError {
+    /// The provided `KeyCombo` contains no keys.
+    NoKeys,
+    /// The name contains `=` or a new line.
+    InvalidName,
+    /// A shortcut with this name is already registered.
+    AlreadyRegistered,
+    /// No shortcut with this name is registered.
+    NotRegistered,
+    /// Another shortcut in an overlapping scope uses the same keys. Contains the other's name.
+    Conflict(String),
+}
+
+enum ScopeWeak {
+    Global,
+    Window(Weak<dyn BasaltWindow>),
+    Bin(Weak<Bin>),
+}
+
+impl ScopeWeak {
+    // Returns true if both scopes can be active at the same time.
+    fn overlaps(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Global, _) | (_, Self::Global) => true,
+            (Self::Window(window_wk), Self::Window(other_wk)) => {
+                match (window_wk.upgrade(), other_wk.upgrade()) {
+                    (Some(window), Some(other)) => window.id() == other.id(),
+                    _ => false,
+                }
+            },
+            (Self::Window(window_wk), Self::Bin(bin_wk))
+            | (Self::Bin(bin_wk), Self::Window(window_wk)) => {
+                match (window_wk.upgrade(), bin_wk.upgrade()) {
+                    (Some(window), Some(bin)) => bin.basalt_ref().window_ref().id() == window.id(),
+                    _ => false,
+                }
+            },
+            (Self::Bin(bin_wk), Self::Bin(other_wk)) => {
+                match (bin_wk.upgrade(), other_wk.upgrade()) {
+                    (Some(bin), Some(other)) => {
+                        bin == other
+                            || bin.ancestors().contains(&other)
+                            || other.ancestors().contains(&bin)
+                    },
+                    _ => false,
+                }
+            },
+        }
+    }
+}
+
+struct Shortcut {
+    scope_wk: ScopeWeak,
+    default: Vec<Key>,
+    keys: Vec<Key>,
+    method: Arc<Mutex<Box<dyn FnMut(&WindowState) + Send + 'static>>>,
+    hook_id: Option<InputHookID>,
+}
+
+#[derive(Default)]
+pub(in crate::input) struct ShortcutState {
+    shortcuts: BTreeMap<String, Shortcut>,
+}
+
+/// Accessed via `basalt.input_ref().shortcuts()`.
+pub struct Shortcuts<'a> {
+    input: &'a Input,
+}
+
+impl<'a> Shortcuts<'a> {
+    pub(in crate::input) fn new(input: &'a Input) -> Self {
+        Self {
+            input,
+        }
+    }
+
+    /// Register a named shortcut.
+    ///
+    /// ```no_run
+    /// basalt
+    ///     .input_ref()
+    ///     .shortcuts()
+    ///     .register("save", (Qwerty::LCtrl, 's'), ShortcutScope::Global, |_| {
+    ///         println!("Save!");
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Possible Errors
+    /// - `NoKeys`: The `KeyCombo` provided is empty.
+    /// - `InvalidName`: The name contains `=` or a new line.
+    /// - `AlreadyRegistered`: A shortcut with this name is already registered.
+    /// - `Conflict`: Another shortcut in an overlapping scope uses the same keys.
+    pub fn register<N, C, F>(
+        &self,
+        name: N,
+        combo: C,
+        scope: ShortcutScope,
+        method: F,
+    ) -> Result<(), ShortcutError>
+    where
+        N: Into<String>,
+        C: KeyCombo,
+        F: FnMut(&WindowState) + Send + 'static,
+    {
+        let name = name.into();
+        let keys = combo.into_vec();
+
+        if keys.is_empty() {
+            return Err(ShortcutError::NoKeys);
+        }
+
+        if name.contains(|c| c == '=' || c == '\n' || c == '\r') {
+            return Err(ShortcutError::InvalidName);
+        }
+
+        let mut state = self.input.shortcuts.lock();
+
+        if state.shortcuts.contains_key(&name) {
+            return Err(ShortcutError::AlreadyRegistered);
+        }
+
+        let scope_wk = match &scope {
+            ShortcutScope::Global => ScopeWeak::Global,
+            ShortcutScope::Window(window) => ScopeWeak::Window(Arc::downgrade(window)),
+            ShortcutScope::Bin(bin) => ScopeWeak::Bin(Arc::downgrade(bin)),
+        };
+
+        if let Some(other) = state.find_conflict(&name, &scope_wk, &keys) {
+            return Err(ShortcutError::Conflict(other));
+        }
+
+        let mut shortcut = Shortcut {
+            scope_wk,
+            default: keys.clone(),
+            keys,
+            method: Arc::new(Mutex::new(Box::new(method))),
+            hook_id: None,
+        };
+
+        shortcut.hook_id = self.add_hook(&shortcut);
+        state.shortcuts.insert(name, shortcut);
+        Ok(())
+    }
+
+    /// Remove a shortcut from the registry.
+    pub fn unregister<N: AsRef<str>>(&self, name: N) {
+        if let Some(shortcut) = self.input.shortcuts.lock().shortcuts.remove(name.as_ref()) {
+            if let Some(hook_id) = shortcut.hook_id {
+                self.input.remove_hook(hook_id);
+            }
+        }
+    }
+
+    /// Change the keys used by a shortcut.
+    ///
+    /// # Possible Errors
+    /// - `NoKeys`: The `KeyCombo` provided is empty.
+    /// - `NotRegistered`: No shortcut with this name is registered.
+    /// - `Conflict`: Another shortcut in an overlapping scope uses the same keys.
+    pub fn rebind<N: AsRef<str>, C: KeyCombo>(
+        &self,
+        name: N,
+        combo: C,
+    ) -> Result<(), ShortcutError> {
+        let keys = combo.into_vec();
+
+        if keys.is_empty() {
+            return Err(ShortcutError::NoKeys);
+        }
+
+        let mut state = self.input.shortcuts.lock();
+
+        let conflict = match state.shortcuts.get(name.as_ref()) {
+            Some(shortcut) => state.find_conflict(name.as_ref(), &shortcut.scope_wk, &keys),
+            None => return Err(ShortcutError::NotRegistered),
+        };
+
+        if let Some(other) = conflict {
+            return Err(ShortcutError::Conflict(other));
+        }
+
+        let shortcut = state.shortcuts.get_mut(name.as_ref()).unwrap();
+        self.set_keys(shortcut, keys);
+        Ok(())
+    }
+
+    /// Change the keys used by a shortcut back to its default.
+    ///
+    /// # Possible Errors
+    /// - `NotRegistered`: No shortcut with this name is registered.
+    /// - `Conflict`: Another shortcut in an overlapping scope uses the same keys.
+    pub fn reset<N: AsRef<str>>(&self, name: N) -> Result<(), ShortcutError> {
+        let default = match self.input.shortcuts.lock().shortcuts.get(name.as_ref()) {
+            Some(shortcut) => shortcut.default.clone(),
+            None => return Err(ShortcutError::NotRegistered),
+        };
+
+        self.rebind(name, default)
+    }
+
+    /// Returns the keys currently used by a shortcut.
+    pub fn keys<N: AsRef<str>>(&self, name: N) -> Option<Vec<Key>> {
+        self.input
+            .shortcuts
+            .lock()
+            .shortcuts
+            .get(name.as_ref())
+            .map(|shortcut| shortcut.keys.clone())
+    }
+
+    /// Returns the names of all the registered shortcuts.
+    pub fn names(&self) -> Vec<String> {
+        self.input
+            .shortcuts
+            .lock()
+            .shortcuts
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a user-facing string of the keys used by a shortcut. e.g. `"Ctrl+S"`
+    ///
+    /// Useful for displaying in menus or tooltips.
+    pub fn display_string<N: AsRef<str>>(&self, name: N) -> Option<String> {
+        self.keys(name).map(|keys| {
+            keys.into_iter()
+                .map(|key| self.input.key_label(key))
+                .collect::<Vec<_>>()
+                .join("+")
+        })
+    }
+
+    /// Returns a list of shortcuts that use the same keys in overlapping scopes.
+    ///
+    /// # Notes
+    /// - Conflicts are prevented by `register`, `rebind` & `load`, but may be introduced by
+    /// changing the parent of a `Bin` used as a scope.
+    pub fn conflicts(&self) -> Vec<(String, String)> {
+        self.input.shortcuts.lock().conflicts()
+    }
+
+    /// Save the keys used by all shortcuts to a file.
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        let mut out = String::new();
+
+        for (name, shortcut) in self.input.shortcuts.lock().shortcuts.iter() {
+            out.push_str(name);
+            out.push('=');
+
+            let tokens: Vec<_> = shortcut.keys.iter().map(|key| key_to_token(*key)).collect();
+            out.push_str(&tokens.join(" "));
+            out.push('\n');
+        }
+
+        std::fs::write(path, out).map_err(|e| format!("Failed to write file: {}", e))
+    }
+
+    /// Load the keys used by shortcuts from a file.
+    ///
+    /// If the loaded keys would cause shortcuts to conflict, no shortcuts are changed and an error
+    /// listing the conflicts is returned.
+    ///
+    /// # Notes
+    /// - Shortcuts that are not registered are ignored.
+    pub fn load<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), String> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let mut bindings = Vec::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (name, tokens) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Line {}: missing '='.", i + 1))?;
+            let mut keys = Vec::new();
+
+            for token in tokens.split_whitespace() {
+                keys.push(
+                    key_from_token(token)
+                        .ok_or_else(|| format!("Line {}: invalid key '{}'.", i + 1, token))?,
+                );
+            }
+
+            if keys.is_empty() {
+                return Err(format!("Line {}: no keys defined.", i + 1));
+            }
+
+            bindings.push((name.to_string(), keys));
+        }
+
+        let mut state = self.input.shortcuts.lock();
+        let mut previous = Vec::new();
+
+        // Swap in the loaded keys to check for conflicts, restoring them if there are any.
+        for (name, keys) in bindings {
+            if let Some(shortcut) = state.shortcuts.get_mut(&name) {
+                previous.push((name, std::mem::replace(&mut shortcut.keys, keys)));
+            }
+        }
+
+        let mut conflicts = state.conflicts();
+        conflicts.retain(|(a, b)| previous.iter().any(|(name, _)| name == a || name == b));
+
+        if !conflicts.is_empty() {
+            for (name, keys) in previous.into_iter().rev() {
+                state.shortcuts.get_mut(&name).unwrap().keys = keys;
+            }
+
+            return Err(format!(
+                "Conflicting shortcuts: {}.",
+                conflicts
+                    .into_iter()
+                    .map(|(a, b)| format!("'{}' & '{}'", a, b))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        for (name, _) in previous {
+            let shortcut = state.shortcuts.get_mut(&name).unwrap();
+            let keys = shortcut.keys.clone();
+            self.set_keys(shortcut, keys);
+        }
+
+        Ok(())
+    }
+
+    fn set_keys(&self, shortcut: &mut Shortcut, keys: Vec<Key>) {
+        if let Some(hook_id) = shortcut.hook_id.take() {
+            self.input.remove_hook(hook_id);
+        }
+
+        shortcut.keys = keys;
+        shortcut.hook_id = self.add_hook(shortcut);
+    }
+
+    fn add_hook(&self, shortcut: &Shortcut) -> Option<InputHookID> {
+        let method = shortcut.method.clone();
+
+        let (window, weight, scope_bin_wk) = match &shortcut.scope_wk {
+            ScopeWeak::Global => {
+                (
+                    self.input.basalt.lock().upgrade()?.window(),
+                    GLOBAL_SCOPE_WEIGHT,
+                    None,
+                )
+            },
+            ScopeWeak::Window(window_wk) => (window_wk.upgrade()?, WINDOW_SCOPE_WEIGHT, None),
+            ScopeWeak::Bin(bin_wk) => {
+                (
+                    bin_wk.upgrade()?.basalt_ref().window(),
+                    BIN_SCOPE_WEIGHT,
+                    Some(bin_wk.clone()),
+                )
+            },
+        };
+
+        self.input
+            .hook()
+            .window(&window)
+            .on_press()
+            .keys(shortcut.keys.clone())
+            .weight(weight)
+            .call(move |_, window_state, _| {
+                if let Some(scope_bin_wk) = scope_bin_wk.as_ref() {
+                    let scope_bin = match scope_bin_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    let focused_bin = match window_state
+                        .focused_bin_id()
+                        .and_then(|bin_id| scope_bin.basalt_ref().interface_ref().get_bin(bin_id))
+                    {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Retain,
+                    };
+
+                    if focused_bin != scope_bin
+                        && !focused_bin
+                            .ancestors()
+                            .into_iter()
+                            .any(|ancestor| ancestor == scope_bin)
+                    {
+                        return InputHookCtrl::Retain;
+                    }
+                }
+
+                (*method.lock())(window_state);
+                InputHookCtrl::RetainNoPass
+            })
+            .finish()
+            .ok()
+    }
+}
+
+impl ShortcutState {
+    fn find_conflict(&self, name: &str, scope_wk: &ScopeWeak, keys: &[Key]) -> Option<String> {
+        let mut keys = keys.to_vec();
+        keys.sort();
+        keys.dedup();
+
+        for (other_name, other) in self.shortcuts.iter() {
+            if other_name == name || !other.scope_wk.overlaps(scope_wk) {
+                continue;
+            }
+
+            let mut other_keys = other.keys.clone();
+            other_keys.sort();
+            other_keys.dedup();
+
+            if other_keys == keys {
+                return Some(other_name.clone());
+            }
+        }
+
+        None
+    }
+
+    fn conflicts(&self) -> Vec<(String, String)> {
+        let mut conflicts = Vec::new();
+
+        for (name, shortcut) in self.shortcuts.iter() {
+            if let Some(other) = self.find_conflict(name, &shortcut.scope_wk, &shortcut.keys) {
+                if *name < other {
+                    conflicts.push((name.clone(), other));
+                }
+            }
+        }
+
+        conflicts
+    }
+}
+
+pub(in crate::input) fn key_to_token(key: Key) -> String {
+    match key {
+        Key::Keyboard(Qwerty::Unknown(code)) => format!("key:#{}", code),
+        Key::Keyboard(qwerty) => format!("key:{:?}", qwerty),
+        // Whitespace would split the token when loading.
+        Key::Logical(LogicalKey::Char(c)) if c.is_whitespace() => format!("char:#{}", c as u32),
+        Key::Logical(LogicalKey::Char(c)) => format!("char:{}", c),
+        Key::Logical(LogicalKey::Named(Qwerty::Unknown(code))) => format!("named:#{}", code),
+        Key::Logical(LogicalKey::Named(qwerty)) => format!("named:{:?}", qwerty),
+        Key::Mouse(MouseButton::Other(i)) => format!("mouse:#{}", i),
+        Key::Mouse(button) => format!("mouse:{:?}", button),
+        Key::Gamepad(GamepadButton::Other(i)) => format!("pad:#{}", i),
+        Key::Gamepad(button) => format!("pad:{:?}", button),
+    }
+}
+
+pub(in crate::input) fn key_from_token(token: &str) -> Option<Key> {
+    let (ty, value) = token.split_once(':')?;
+
+    match ty {
+        "key" => qwerty_from_name(value).map(Key::Keyboard),
+        "named" => qwerty_from_name(value).map(|qwerty| Key::Logical(LogicalKey::Named(qwerty))),
+        "char" => {
+            let mut chars = value.chars();
+            let c = chars.next()?;
+
+            if chars.next().is_none() {
+                return Some(Key::Logical(LogicalKey::Char(c)));
+            }
+
+            char::from_u32(value.strip_prefix('#')?.parse().ok()?)
+                .map(|c| Key::Logical(LogicalKey::Char(c)))
+        },
+        "mouse" => {
+            match value {
+                "Left" => Some(Key::Mouse(MouseButton::Left)),
+                "Right" => Some(Key::Mouse(MouseButton::Right)),
+                "Middle" => Some(Key::Mouse(MouseButton::Middle)),
+                _ => {
+                    value
+                        .strip_prefix('#')?
+                        .parse()
+                        .ok()
+                        .map(|i| Key::Mouse(MouseButton::Other(i)))
+                },
+            }
+        },
+        "pad" => {
+            match value.strip_prefix('#') {
+                Some(i) => {
+                    i.parse()
+                        .ok()
+                        .map(|i| Key::Gamepad(GamepadButton::Other(i)))
+                },
+                None => {
+                    GAMEPAD_BUTTONS
+                        .into_iter()
+                        .find(|button| format!("{:?}", button) == value)
+                        .map(Key::Gamepad)
+                },
+            }
+        },
+        _ => None,
+    }
+}
+
+fn qwerty_from_name(name: &str) -> Option<Qwerty> {
+    match name.strip_prefix('#') {
+        Some(code) => code.parse().ok().map(Qwerty::Unknown),
+        None => {
+            QWERTY_KEYS
+                .into_iter()
+                .find(|qwerty| format!("{:?}", qwerty) == name)
+        },
+    }
+}
+
+const GAMEPAD_BUTTONS: [GamepadButton; 17] = [
+    GamepadButton::South,
+    GamepadButton::East,
+    GamepadButton::North,
+    GamepadButton::West,
+    GamepadButton::LeftBumper,
+    GamepadButton::RightBumper,
+    GamepadButton::LeftTrigger,
+    GamepadButton::RightTrigger,
+    GamepadButton::Select,
+    GamepadButton::Start,
+    GamepadButton::Mode,
+    GamepadButton::LeftThumb,
+    GamepadButton::RightThumb,
+    GamepadButton::DPadUp,
+    GamepadButton::DPadDown,
+    GamepadButton::DPadLeft,
+    GamepadButton::DPadRight,
+];
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Weak};
+
+    use parking_lot::Mutex;
+
+    use super::{
+        key_from_token, key_to_token, ScopeWeak, Shortcut, ShortcutState, GAMEPAD_BUTTONS,
+        QWERTY_KEYS,
+    };
+    use crate::input::state::WindowState;
+    use crate::input::{GamepadButton, Key, LogicalKey, MouseButton, Qwerty};
+    use crate::interface::bin::Bin;
+    use crate::window::winit::WinitWindow;
+    use crate::window::BasaltWindow;
+
+    fn shortcut(scope_wk: ScopeWeak, keys: &[Key]) -> Shortcut {
+        Shortcut {
+            scope_wk,
+            default: keys.to_vec(),
+            keys: keys.to_vec(),
+            method: Arc::new(Mutex::new(Box::new(|_: &WindowState| ()))),
+            hook_id: None,
+        }
+    }
+
+    fn window_scope() -> ScopeWeak {
+        ScopeWeak::Window(Weak::<WinitWindow>::new() as Weak<dyn BasaltWindow>)
+    }
+
+    fn bin_scope() -> ScopeWeak {
+        ScopeWeak::Bin(Weak::<Bin>::new())
+    }
+
+    #[test]
+    fn global_conflicts() {
+        let save = [Key::Keyboard(Qwerty::LCtrl), Key::Keyboard(Qwerty::S)];
+        let open = [Key::Keyboard(Qwerty::LCtrl), Key::Keyboard(Qwerty::O)];
+        let mut state = ShortcutState::default();
+
+        state
+            .shortcuts
+            .insert(String::from("global"), shortcut(ScopeWeak::Global, &save));
+
+        // Keys are compared regardless of order.
+        let reversed = [save[1], save[0]];
+
+        for scope_wk in [ScopeWeak::Global, window_scope(), bin_scope()] {
+            assert_eq!(
+                state.find_conflict("other", &scope_wk, &reversed),
+                Some(String::from("global"))
+            );
+
+            assert_eq!(state.find_conflict("other", &scope_wk, &open), None);
+        }
+
+        // A shortcut doesn't conflict with itself.
+        assert_eq!(
+            state.find_conflict("global", &ScopeWeak::Global, &save),
+            None
+        );
+
+        state
+            .shortcuts
+            .insert(String::from("window"), shortcut(window_scope(), &open));
+        state
+            .shortcuts
+            .insert(String::from("bin"), shortcut(bin_scope(), &save));
+
+        assert_eq!(
+            state.conflicts(),
+            vec![(String::from("bin"), String::from("global"))]
+        );
+
+        state.shortcuts.insert(
+            String::from("global_open"),
+            shortcut(ScopeWeak::Global, &open),
+        );
+
+        assert_eq!(
+            state.conflicts(),
+            vec![
+                (String::from("bin"), String::from("global")),
+                (String::from("global_open"), String::from("window")),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_tokens() {
+        let cases = [
+            ("key:LCtrl", Key::Keyboard(Qwerty::LCtrl)),
+            ("key:#300", Key::Keyboard(Qwerty::Unknown(300))),
+            ("char:s", Key::Logical(LogicalKey::Char('s'))),
+            ("char:=", Key::Logical(LogicalKey::Char('='))),
+            ("char:#", Key::Logical(LogicalKey::Char('#'))),
+            ("char:#32", Key::Logical(LogicalKey::Char(' '))),
+            ("named:Esc", Key::Logical(LogicalKey::Named(Qwerty::Esc))),
+            (
+                "named:#7",
+                Key::Logical(LogicalKey::Named(Qwerty::Unknown(7))),
+            ),
+            ("mouse:Left", Key::Mouse(MouseButton::Left)),
+            ("mouse:#4", Key::Mouse(MouseButton::Other(4))),
+            ("pad:South", Key::Gamepad(GamepadButton::South)),
+            ("pad:#20", Key::Gamepad(GamepadButton::Other(20))),
+        ];
+
+        for (token, key) in cases {
+            assert_eq!(key_from_token(token), Some(key), "{}", token);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_tokens() {
+        let cases = [
+            "",
+            "key",
+            "key:",
+            "key:Nope",
+            "key:#x",
+            "char:",
+            "char:ab",
+            "char:#x",
+            "mouse:Other",
+            "mouse:#-1",
+            "pad:Up",
+            "bogus:A",
+        ];
+
+        for token in cases {
+            assert_eq!(key_from_token(token), None, "{}", token);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let keys = QWERTY_KEYS
+            .into_iter()
+            .flat_map(|qwerty| {
+                [
+                    Key::Keyboard(qwerty),
+                    Key::Logical(LogicalKey::Named(qwerty)),
+                ]
+            })
+            .chain(GAMEPAD_BUTTONS.into_iter().map(Key::Gamepad))
+            .chain([
+                Key::Keyboard(Qwerty::Unknown(0)),
+                Key::Logical(LogicalKey::Named(Qwerty::Unknown(u32::MAX))),
+                Key::Logical(LogicalKey::Char('a')),
+                Key::Logical(LogicalKey::Char('é')),
+                Key::Logical(LogicalKey::Char(' ')),
+                Key::Logical(LogicalKey::Char('\t')),
+                Key::Mouse(MouseButton::Left),
+                Key::Mouse(MouseButton::Right),
+                Key::Mouse(MouseButton::Middle),
+                Key::Mouse(MouseButton::Other(9)),
+                Key::Gamepad(GamepadButton::Other(0)),
+            ]);
+
+        for key in keys {
+            let token = key_to_token(key);
+            assert!(!token.contains(char::is_whitespace), "{}", token);
+            assert_eq!(key_from_token(&token), Some(key), "{}", token);
+        }
+    }
+}
@@ -2,8 +2,11 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::input::{Char, InputHookCtrl, InputHookTarget, Key};
+use crate::input::{
+    Char, GamepadAxis, InputConfig, InputHookCtrl, InputHookTarget, Key, LogicalKey, Qwerty,
+};
 use crate::interface::bin::BinID;
 use crate::interface::Interface;
 use crate::interval::IntvlHookID;
@@ -14,11 +17,22 @@ use crate::window::BstWindowID;
 pub struct WindowState {
     window_id: BstWindowID,
     key_state: HashMap<Key, bool>,
+    logical_pressed: HashMap<Qwerty, LogicalKey>,
+    axis_state: HashMap<GamepadAxis, f32>,
     focus_bin: Option<BinID>,
     cursor_pos: [f32; 2],
     focused: bool,
     cursor_inside: bool,
     cursor_captured: bool,
+    last_click: Option<LastClick>,
+}
+
+#[derive(Debug)]
+struct LastClick {
+    key: Key,
+    time: Instant,
+    pos: [f32; 2],
+    count: u32,
 }
 
 impl WindowState {
@@ -26,11 +40,77 @@ impl WindowState {
         Self {
             window_id,
             key_state: HashMap::new(),
+            logical_pressed: HashMap::new(),
+            axis_state: HashMap::new(),
             focus_bin: None,
             cursor_pos: [0.0; 2],
             focused: true,
             cursor_inside: true,
             cursor_captured: false,
+            last_click: None,
+        }
+    }
+
+    // Returns the click count of this press.
+    pub(in crate::input) fn update_click_count(&mut self, key: Key, config: &InputConfig) -> u32 {
+        if !matches!(key, Key::Mouse(_)) {
+            return 1;
+        }
+
+        let now = Instant::now();
+        let pos = self.cursor_pos;
+
+        let count = match self.last_click.as_ref() {
+            Some(last)
+                if last.key == key
+                    && now.duration_since(last.time) <= config.multi_click_time
+                    && (last.pos[0] - pos[0]).abs() <= config.multi_click_distance
+                    && (last.pos[1] - pos[1]).abs() <= config.multi_click_distance =>
+            {
+                last.count + 1
+            },
+            _ => 1,
+        };
+
+        self.last_click = Some(LastClick {
+            key,
+            time: now,
+            pos,
+            count,
+        });
+
+        count
+    }
+
+    // Returns the keys pressed by this key. A physical key also presses the logical key it means,
+    // which is kept so the same logical key is released if the layout changes while held.
+    pub(in crate::input) fn press_keys(
+        &mut self,
+        key: Key,
+        logical: Option<LogicalKey>,
+    ) -> Vec<Key> {
+        match (key, logical) {
+            (Key::Keyboard(physical), Some(logical)) => {
+                let logical = *self.logical_pressed.entry(physical).or_insert(logical);
+                vec![key, Key::Logical(logical)]
+            },
+            _ => vec![key],
+        }
+    }
+
+    // Returns the keys released by this key.
+    pub(in crate::input) fn release_keys(&mut self, key: Key) -> Vec<Key> {
+        let logical = match key {
+            Key::Keyboard(physical) => self.logical_pressed.remove(&physical),
+            _ => None,
+        };
+
+        match logical {
+            // Another physical key may still be holding the logical key.
+            Some(logical) if !self.logical_pressed.values().any(|held| *held == logical) => {
+                vec![key, Key::Logical(logical)]
+            },
+            _ => vec![key],
         }
     }
 
@@ -51,6 +131,14 @@ impl WindowState {
         changed
     }
 
+    // Returns true if state changed.
+    pub(in crate::input) fn update_axis(&mut self, axis: GamepadAxis, value: f32) -> bool {
+        match self.axis_state.insert(axis, value) {
+            Some(old_value) => old_value != value,
+            None => true,
+        }
+    }
+
     // If changed returns (old, new)
     pub(in crate::input) fn check_focus_bin(
         &mut self,
@@ -148,41 +236,59 @@ impl WindowState {
 
     /// Check if a `Key` is pressed.
     ///
-    /// Supports using `Qwerty` or `MouseButton`.
+    /// Supports using `Qwerty`, `LogicalKey`, `char`, `MouseButton` or `GamepadButton`.
     pub fn is_key_pressed<K: Into<Key>>(&self, key: K) -> bool {
         let key = key.into();
         self.key_state.get(&key).copied().unwrap_or(false)
     }
+
+    /// Returns the last known value of a `GamepadAxis`.
+    ///
+    /// Sticks range from `-1.0` to `1.0` and triggers range from `0.0` to `1.0`.
+    pub fn axis_value(&self, axis: GamepadAxis) -> f32 {
+        self.axis_state.get(&axis).copied().unwrap_or(0.0)
+    }
 }
 
 /// State of `Key`'s specific to the hook.
 #[derive(Debug, Clone)]
 pub struct LocalKeyState {
     state: HashMap<Key, bool>,
+    click_count: u32,
 }
 
 impl LocalKeyState {
     pub(in crate::input) fn from_keys<K: IntoIterator<Item = Key>>(keys: K) -> Self {
         Self {
             state: HashMap::from_iter(keys.into_iter().map(|key| (key, false))),
+            click_count: 1,
+        }
+    }
+
+    pub(in crate::input) fn set_click_count(&mut self, click_count: u32) {
+        self.click_count = click_count;
+    }
+
+    // Set the state of keys to what they are in the window with the exception of some.
+    pub(in crate::input) fn sync(&mut self, window_state: &WindowState, except: &[Key]) {
+        for (key, state) in self.state.iter_mut() {
+            *state = !except.contains(key) && window_state.is_key_pressed(*key);
         }
     }
 
     // Returns true if all keys where not pressed before, but now are.
-    pub(in crate::input) fn update(&mut self, key: Key, key_state: bool) -> bool {
+    pub(in crate::input) fn update(&mut self, keys: &[Key], key_state: bool) -> bool {
         let all_before = self.state.values().all(|state| *state);
+        let mut check_again = false;
 
-        let check_again = match self.state.get_mut(&key) {
-            Some(current) => {
+        for key in keys {
+            if let Some(current) = self.state.get_mut(key) {
                 if *current != key_state {
                     *current = key_state;
-                    true
-                } else {
-                    false
+                    check_again = true;
                 }
-            },
-            None => false,
-        };
+            }
+        }
 
         if check_again {
             let all_after = self.state.values().all(|state| *state);
@@ -197,6 +303,18 @@ impl LocalKeyState {
         }
     }
 
+    // Returns true if any of the keys are involved.
+    pub(in crate::input) fn involves_any(&self, keys: &[Key]) -> bool {
+        keys.iter().any(|key| self.state.contains_key(key))
+    }
+
+    // Returns true if a key of the same kind is involved. e.g. `Key::Logical` for a logical key.
+    pub(in crate::input) fn involves_kind(&self, key: Key) -> bool {
+        self.state
+            .keys()
+            .any(|involved| std::mem::discriminant(involved) == std::mem::discriminant(&key))
+    }
+
     pub(in crate::input) fn release_all(&mut self) {
         self.state.values_mut().for_each(|state| *state = false);
     }
@@ -217,6 +335,101 @@ impl LocalKeyState {
     pub fn is_involved<K: Into<Key>>(&self, key: K) -> bool {
         self.state.contains_key(&key.into())
     }
+
+    /// The amount of times the mouse button that caused this press was clicked in succession.
+    ///
+    /// For example `2` would be a double click and `3` a triple click.
+    ///
+    /// # Notes
+    /// - This is always `1` for keys other than `MouseButton`'s.
+    /// - This is only valid within press hooks.
+    /// - See `Input::set_multi_click_time` & `Input::set_multi_click_distance`.
+    pub fn click_count(&self) -> u32 {
+        self.click_count
+    }
+}
+
+/// State of a sequence of key combinations.
+pub(in crate::input) struct ChordState {
+    steps: Vec<LocalKeyState>,
+    step: usize,
+    last: Option<Instant>,
+}
+
+impl ChordState {
+    pub(in crate::input) fn new(steps: Vec<Vec<Key>>) -> Self {
+        Self {
+            steps: steps.into_iter().map(LocalKeyState::from_keys).collect(),
+            step: 0,
+            last: None,
+        }
+    }
+
+    // Returns true if the last combination of the sequence was completed.
+    pub(in crate::input) fn press(
+        &mut self,
+        keys: &[Key],
+        window_state: &WindowState,
+        timeout: Duration,
+    ) -> bool {
+        if self.step != 0 {
+            let timed_out = match self.last {
+                Some(last) => last.elapsed() > timeout,
+                None => false,
+            };
+
+            if timed_out {
+                self.reset(window_state, keys);
+            } else if !self.steps[self.step].involves_any(keys) {
+                // Modifiers and keys of a kind this combination doesn't use, such as the physical
+                // key when the combination uses logical keys, don't break the sequence.
+                let ignore = keys.iter().all(|key| {
+                    let is_modifier = match key {
+                        Key::Keyboard(qwerty) | Key::Logical(LogicalKey::Named(qwerty)) => {
+                            qwerty.is_modifier()
+                        },
+                        _ => false,
+                    };
+
+                    is_modifier || !self.steps[self.step].involves_kind(*key)
+                });
+
+                if ignore {
+                    return false;
+                }
+
+                self.reset(window_state, keys);
+            }
+        }
+
+        if !self.steps[self.step].update(keys, true) {
+            return false;
+        }
+
+        if self.step + 1 == self.steps.len() {
+            return true;
+        }
+
+        self.step += 1;
+        self.last = Some(Instant::now());
+        self.steps[self.step].sync(window_state, keys);
+        false
+    }
+
+    pub(in crate::input) fn release(&mut self, keys: &[Key]) {
+        self.steps[self.step].update(keys, false);
+    }
+
+    pub(in crate::input) fn reset(&mut self, window_state: &WindowState, except: &[Key]) {
+        self.steps.iter_mut().for_each(|step| step.release_all());
+        self.step = 0;
+        self.last = None;
+        self.steps[0].sync(window_state, except);
+    }
+
+    pub(in crate::input) fn local(&self) -> &LocalKeyState {
+        &self.steps[self.step]
+    }
 }
 
 /// State of cursor specific to the hook.
@@ -275,6 +488,20 @@ pub(in crate::input) enum HookState {
                 + 'static,
         >,
     },
+    AnyPress {
+        weight: i16,
+        method:
+            Box<dyn FnMut(InputHookTarget, &WindowState, Key) -> InputHookCtrl + Send + 'static>,
+    },
+    Chord {
+        chord: ChordState,
+        weight: i16,
+        method: Box<
+            dyn FnMut(InputHookTarget, &WindowState, &LocalKeyState) -> InputHookCtrl
+                + Send
+                + 'static,
+        >,
+    },
     Hold {
         state: LocalKeyState,
         pressed: bool,
@@ -343,6 +570,15 @@ pub(in crate::input) enum HookState {
         weight: i16,
         method: Box<dyn FnMut(f32, f32) -> InputHookCtrl + Send + 'static>,
     },
+    Axis {
+        axes: Vec<GamepadAxis>,
+        weight: i16,
+        method: Box<
+            dyn FnMut(InputHookTarget, &WindowState, GamepadAxis, f32) -> InputHookCtrl
+                + Send
+                + 'static,
+        >,
+    },
 }
 
 impl HookState {
@@ -350,3 +586,172 @@ impl HookState {
         !matches!(self, Self::Motion { .. })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{ChordState, WindowState};
+    use crate::input::{Key, LogicalKey, Qwerty};
+    use crate::window::BstWindowID;
+
+    const TIMEOUT: Duration = Duration::from_millis(1500);
+
+    // Feeds events the same way `proc::press` & `proc::release` do for a winit window.
+    struct Harness {
+        window_state: WindowState,
+        chord: ChordState,
+        timeout: Duration,
+    }
+
+    impl Harness {
+        fn new(steps: Vec<Vec<Key>>) -> Self {
+            Self {
+                window_state: WindowState::new(BstWindowID(0)),
+                chord: ChordState::new(steps),
+                timeout: TIMEOUT,
+            }
+        }
+
+        // Returns true if the chord was completed.
+        fn press(&mut self, physical: Qwerty) -> bool {
+            let keys = self
+                .window_state
+                .press_keys(Key::Keyboard(physical), Some(physical.into()));
+            let mut changed = false;
+
+            for key in keys.iter() {
+                changed |= self.window_state.update_key(*key, true);
+            }
+
+            if changed && self.chord.press(&keys, &self.window_state, self.timeout) {
+                self.chord.reset(&self.window_state, &[]);
+                return true;
+            }
+
+            false
+        }
+
+        fn release(&mut self, physical: Qwerty) {
+            let keys = self.window_state.release_keys(Key::Keyboard(physical));
+            let mut changed = false;
+
+            for key in keys.iter() {
+                changed |= self.window_state.update_key(*key, false);
+            }
+
+            if changed {
+                self.chord.release(&keys);
+            }
+        }
+
+        // Press & release a key, returning true if the chord was completed.
+        fn tap(&mut self, physical: Qwerty) -> bool {
+            let completed = self.press(physical);
+            self.release(physical);
+            completed
+        }
+    }
+
+    fn ctrl_k_ctrl_c_logical() -> Vec<Vec<Key>> {
+        vec![
+            vec![Qwerty::LCtrl.into(), 'k'.into()],
+            vec![Qwerty::LCtrl.into(), 'c'.into()],
+        ]
+    }
+
+    #[test]
+    fn logical_chord_with_held_modifier() {
+        let mut harness = Harness::new(ctrl_k_ctrl_c_logical());
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(!harness.tap(Qwerty::K));
+        assert!(harness.tap(Qwerty::C));
+    }
+
+    #[test]
+    fn physical_chord_with_held_modifier() {
+        let mut harness = Harness::new(vec![
+            vec![Qwerty::LCtrl.into(), Qwerty::K.into()],
+            vec![Qwerty::LCtrl.into(), Qwerty::C.into()],
+        ]);
+
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(!harness.tap(Qwerty::K));
+        assert!(harness.tap(Qwerty::C));
+    }
+
+    #[test]
+    fn modifier_repressed_between_steps() {
+        let mut harness = Harness::new(ctrl_k_ctrl_c_logical());
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(!harness.tap(Qwerty::K));
+        harness.release(Qwerty::LCtrl);
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(harness.tap(Qwerty::C));
+    }
+
+    #[test]
+    fn unrelated_modifier_between_steps() {
+        let mut harness = Harness::new(vec![vec!['g'.into()], vec!['d'.into()]]);
+        assert!(!harness.tap(Qwerty::G));
+        assert!(!harness.tap(Qwerty::LShift));
+        assert!(harness.tap(Qwerty::D));
+    }
+
+    #[test]
+    fn repeated_step() {
+        let mut harness = Harness::new(vec![vec!['g'.into()], vec!['g'.into()]]);
+        assert!(!harness.tap(Qwerty::G));
+        assert!(harness.tap(Qwerty::G));
+        assert!(!harness.tap(Qwerty::G));
+        assert!(harness.tap(Qwerty::G));
+    }
+
+    #[test]
+    fn non_matching_key_resets() {
+        let mut harness = Harness::new(ctrl_k_ctrl_c_logical());
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(!harness.tap(Qwerty::K));
+        assert!(!harness.tap(Qwerty::X));
+        assert!(!harness.tap(Qwerty::C));
+    }
+
+    #[test]
+    fn non_matching_key_restarts_sequence() {
+        let mut harness = Harness::new(ctrl_k_ctrl_c_logical());
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(!harness.tap(Qwerty::K));
+        assert!(!harness.tap(Qwerty::K));
+        assert!(harness.tap(Qwerty::C));
+    }
+
+    #[test]
+    fn timeout_resets() {
+        let mut harness = Harness::new(ctrl_k_ctrl_c_logical());
+        harness.timeout = Duration::from_millis(1);
+        assert!(!harness.press(Qwerty::LCtrl));
+        assert!(!harness.tap(Qwerty::K));
+        thread::sleep(Duration::from_millis(10));
+        assert!(!harness.tap(Qwerty::C));
+    }
+
+    #[test]
+    fn logical_key_follows_layout() {
+        // AZERTY: the physical Q key means 'a'.
+        let mut harness = Harness::new(vec![vec!['a'.into()], vec!['b'.into()]]);
+        let keys = harness
+            .window_state
+            .press_keys(Key::Keyboard(Qwerty::Q), Some(LogicalKey::Char('a')));
+
+        for key in keys.iter() {
+            harness.window_state.update_key(*key, true);
+        }
+
+        assert!(!harness.chord.press(&keys, &harness.window_state, TIMEOUT));
+        harness.release(Qwerty::Q);
+        assert!(!harness.window_state.is_key_pressed(Qwerty::Q));
+        assert!(!harness.window_state.is_key_pressed('a'));
+        assert!(harness.tap(Qwerty::B));
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::atlas::AtlasFrame;
+use crate::interface::bin::{Bin, BinStyle, KeepAlive};
+use crate::interval::{IntvlHookCtrl, IntvlHookID};
+use crate::Basalt;
+
+impl KeepAlive for AnimatedImage {}
+
+/// How many times an `AnimatedImage` plays before stopping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnimationLoop {
+    /// Play indefinitely.
+    #[default]
+    Forever,
+    /// Play this many times, stopping on the last frame.
+    Count(u32),
+}
+
+/// How often playback is checked for a frame change.
+///
+/// Elapsed time is accumulated between checks, so frame durations that aren't a multiple of
+/// this don't drift.
+const TICK_INTERVAL: Duration = Duration::from_millis(4);
+
+/// Plays back the frames of an animated image on a `Bin`.
+///
+/// Frames are displayed by setting `back_image_atlas` on the bin. Other style fields of the bin
+/// are left untouched.
+///
+/// # Notes
+/// - Playback doesn't advance while the bin isn't visible.
+pub struct AnimatedImage {
+    pub basalt: Arc<Basalt>,
+    pub bin: Arc<Bin>,
+    frames: Vec<AtlasFrame>,
+    duration: Duration,
+    state: Mutex<State>,
+    on_finish: Mutex<Vec<Box<dyn FnMut() + Send + 'static>>>,
+    intvl_id: IntvlHookID,
+}
+
+struct State {
+    frame: usize,
+    elapsed: Duration,
+    playing: bool,
+    finished: bool,
+    loops: u32,
+    loop_mode: AnimationLoop,
+}
+
+impl Drop for AnimatedImage {
+    fn drop(&mut self) {
+        self.basalt.interval_ref().remove(self.intvl_id);
+    }
+}
+
+impl AnimatedImage {
+    /// Display the frames on the provided `Bin`.
+    ///
+    /// The first frame is displayed immediately. Use `play` to start playback.
+    ///
+    /// See `Atlas::load_animation` for loading frames.
+    pub fn new(basalt: Arc<Basalt>, bin: Arc<Bin>, frames: Vec<AtlasFrame>) -> Arc<Self> {
+        Arc::new_cyclic(|anim_wk| {
+            let anim_wk = anim_wk.clone();
+
+            let intvl_id = basalt
+                .interval_ref()
+                .do_every(TICK_INTERVAL, None, move |last_call| {
+                    match anim_wk.upgrade() {
+                        Some(anim) => anim.tick(last_call),
+                        None => IntvlHookCtrl::Remove,
+                    }
+                });
+
+            let anim = AnimatedImage {
+                basalt,
+                bin,
+                duration: frames.iter().map(|frame| frame.duration).sum(),
+                frames,
+                state: Mutex::new(State {
+                    frame: 0,
+                    elapsed: Duration::ZERO,
+                    playing: false,
+                    finished: false,
+                    loops: 0,
+                    loop_mode: AnimationLoop::Forever,
+                }),
+                on_finish: Mutex::new(Vec::new()),
+                intvl_id,
+            };
+
+            anim.display(0);
+            anim
+        })
+    }
+
+    /// Start or resume playback.
+    ///
+    /// If playback had finished, it restarts from the first frame.
+    ///
+    /// # Notes
+    /// - Does nothing if there is only one frame or the combined duration of the frames is zero.
+    pub fn play(&self) {
+        if self.frames.len() < 2 || self.duration.is_zero() {
+            return;
+        }
+
+        let restart = {
+            let mut state = self.state.lock();
+
+            if state.playing {
+                return;
+            }
+
+            state.playing = true;
+
+            if state.finished {
+                state.finished = false;
+                state.loops = 0;
+                state.elapsed = Duration::ZERO;
+                state.frame = 0;
+                true
+            } else {
+                false
+            }
+        };
+
+        if restart {
+            self.display(0);
+        }
+
+        self.basalt.interval_ref().start(self.intvl_id);
+    }
+
+    /// Pause playback on the current frame.
+    pub fn pause(&self) {
+        self.state.lock().playing = false;
+        self.basalt.interval_ref().pause(self.intvl_id);
+    }
+
+    /// Pause playback and return to the first frame.
+    pub fn stop(&self) {
+        self.pause();
+        self.set_frame(0);
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.state.lock().playing
+    }
+
+    /// Set how many times the animation plays. This resets the count of completed loops.
+    pub fn set_loop(&self, loop_mode: AnimationLoop) {
+        let mut state = self.state.lock();
+        state.loop_mode = loop_mode;
+        state.loops = 0;
+        state.finished = false;
+    }
+
+    pub fn loop_mode(&self) -> AnimationLoop {
+        self.state.lock().loop_mode
+    }
+
+    /// The index of the frame currently displayed.
+    pub fn frame(&self) -> usize {
+        self.state.lock().frame
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// The combined duration of all frames.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Display a specific frame. The frame is displayed for its full duration.
+    ///
+    /// # Notes
+    /// - Does nothing if `index` is out of bounds.
+    pub fn set_frame(&self, index: usize) {
+        if index >= self.frames.len() {
+            return;
+        }
+
+        {
+            let mut state = self.state.lock();
+            state.frame = index;
+            state.elapsed = Duration::ZERO;
+        }
+
+        self.display(index);
+    }
+
+    /// Called when playback stops after the last loop of `AnimationLoop::Count`.
+    pub fn on_finish<F: FnMut() + Send + 'static>(&self, func: F) {
+        self.on_finish.lock().push(Box::new(func));
+    }
+
+    fn tick(&self, last_call: Option<Duration>) -> IntvlHookCtrl {
+        if !self.bin.visible() {
+            return IntvlHookCtrl::Continue;
+        }
+
+        let mut state = self.state.lock();
+
+        if !state.playing || self.duration.is_zero() {
+            return IntvlHookCtrl::Pause;
+        }
+
+        let start_frame = state.frame;
+        let mut finished = false;
+        state.elapsed += last_call.unwrap_or_default();
+
+        // Skip over whole loops, so at most two loops worth of frames are stepped through below.
+        let whole =
+            (state.elapsed.as_nanos() / self.duration.as_nanos()).min(u32::MAX as u128) as u32;
+
+        let skip = match state.loop_mode {
+            AnimationLoop::Forever => whole,
+            AnimationLoop::Count(count) => {
+                whole.min(count.saturating_sub(state.loops.saturating_add(1)))
+            },
+        };
+
+        if skip > 0 {
+            state.elapsed -= self.duration * skip;
+            state.loops = state.loops.saturating_add(skip);
+        }
+
+        while state.elapsed >= self.frames[state.frame].duration {
+            state.elapsed -= self.frames[state.frame].duration;
+
+            if state.frame + 1 < self.frames.len() {
+                state.frame += 1;
+                continue;
+            }
+
+            state.loops = state.loops.saturating_add(1);
+
+            if let AnimationLoop::Count(count) = state.loop_mode {
+                if state.loops >= count {
+                    state.playing = false;
+                    state.finished = true;
+                    state.elapsed = Duration::ZERO;
+                    finished = true;
+                    break;
+                }
+            }
+
+            state.frame = 0;
+        }
+
+        let frame = state.frame;
+        drop(state);
+
+        if frame != start_frame {
+            self.display(frame);
+        }
+
+        if finished {
+            for func in self.on_finish.lock().iter_mut() {
+                func();
+            }
+
+            return IntvlHookCtrl::Pause;
+        }
+
+        IntvlHookCtrl::Continue
+    }
+
+    fn display(&self, index: usize) {
+        let coords = match self.frames.get(index) {
+            Some(frame) => frame.coords.clone(),
+            None => return,
+        };
+
+        self.bin
+            .style_update(BinStyle {
+                back_image: None,
+                back_image_url: None,
+                back_image_raw: None,
+                back_image_raw_coords: None,
+                back_image_svg: None,
+                back_image_atlas: Some(coords),
+                ..self.bin.style_copy()
+            })
+            .expect_valid();
+    }
+}
@@ -1,14 +1,18 @@
 pub mod style;
 pub use self::style::{
-    BinPosition, BinStyle, BinVert, Color, FontStretch, FontStyle, FontWeight, ImageEffect,
-    TextHoriAlign, TextVertAlign, TextWrap,
+    BinPosition, BinStyle, BinVert, Color, FontStretch, FontStyle, FontWeight, Gradient,
+    GradientKind, GradientSpace, GradientStop, Icon, IconGlyph, ImageAlign, ImageEffect, ImageFit,
+    ImageRepeat, ImageSlice, TextHoriAlign, TextVertAlign, TextWrap,
 };
 
 /// An ID of a `Bin`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct BinID(pub(super) u64);
+pub struct BinID(pub(crate) u64);
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::f32::consts::TAU;
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::{self, AtomicBool};
 use std::sync::{Arc, Barrier, Weak};
 use std::time::{Duration, Instant};
@@ -23,9 +27,10 @@ use crate::atlas::{
 use crate::image_view::BstImageView;
 use crate::input::key::KeyCombo;
 use crate::input::state::{LocalCursorState, LocalKeyState, WindowState};
-use crate::input::{Char, InputHookCtrl, InputHookID, InputHookTarget, MouseButton};
+use crate::input::{Char, GamepadAxis, InputHookCtrl, InputHookID, InputHookTarget, MouseButton};
 pub use crate::interface::bin::style::BinStyleValidation;
 use crate::interface::render::composer::UpdateContext;
+use crate::interface::tooltip::Tooltip;
 use crate::interface::{scale_verts, ItfVertInfo};
 use crate::interval::IntvlHookCtrl;
 use crate::Basalt;
@@ -175,6 +180,7 @@ pub struct Bin {
     verts: Mutex<VertexState>,
     post_update: RwLock<PostUpdate>,
     input_hook_ids: Mutex<Vec<InputHookID>>,
+    focusable: AtomicBool,
     keep_alive: Mutex<Vec<Arc<dyn KeepAlive + Send + Sync>>>,
     last_update: Mutex<Instant>,
     update_stats: Mutex<BinUpdateStats>,
@@ -225,6 +231,7 @@ pub struct PostUpdate {
     /// UI Scale Used
     pub scale: f32,
     text_state: Option<TextState>,
+    back_gradient: Option<(Gradient, AtlasCoords)>,
 }
 
 #[derive(Debug, Clone)]
@@ -314,6 +321,7 @@ impl Bin {
             verts: Mutex::new(VertexState::default()),
             post_update: RwLock::new(PostUpdate::default()),
             input_hook_ids: Mutex::new(Vec::new()),
+            focusable: AtomicBool::new(false),
             keep_alive: Mutex::new(Vec::new()),
             last_update: Mutex::new(Instant::now()),
             update_stats: Mutex::new(BinUpdateStats::default()),
@@ -504,6 +512,20 @@ impl Bin {
             .unwrap()
     }
 
+    pub fn on_axis<F>(self: &Arc<Self>, method: F) -> InputHookID
+    where
+        F: FnMut(InputHookTarget, &WindowState, GamepadAxis, f32) -> InputHookCtrl + Send + 'static,
+    {
+        self.basalt
+            .input_ref()
+            .hook()
+            .bin(self)
+            .on_axis()
+            .call(method)
+            .finish()
+            .unwrap()
+    }
+
     #[inline]
     pub fn on_children_added<F: FnMut(&Arc<Bin>, &Vec<Arc<Bin>>) + Send + 'static>(
         self: &Arc<Self>,
@@ -566,6 +588,35 @@ impl Bin {
         *self.last_update.lock()
     }
 
+    /// Set whether this `Bin` can be focused with directional pad navigation.
+    pub fn set_focusable(self: &Arc<Self>, focusable: bool) {
+        self.focusable.store(focusable, atomic::Ordering::SeqCst);
+        self.basalt.interface_ref().set_focusable(self, focusable);
+    }
+
+    /// Returns `true` if this `Bin` can be focused with directional pad navigation.
+    pub fn is_focusable(&self) -> bool {
+        self.focusable.load(atomic::Ordering::SeqCst)
+    }
+
+    /// Set the tooltip shown when the cursor rests on this `Bin`.
+    pub fn set_tooltip<T: Into<Tooltip>>(self: &Arc<Self>, tooltip: T) {
+        self.basalt
+            .interface_ref()
+            .tooltips()
+            .set(self, Some(tooltip.into()));
+    }
+
+    /// Remove the tooltip of this `Bin`.
+    pub fn remove_tooltip(self: &Arc<Self>) {
+        self.basalt.interface_ref().tooltips().set(self, None);
+    }
+
+    /// The tooltip shown when the cursor rests on this `Bin`.
+    pub fn tooltip(self: &Arc<Self>) -> Option<Tooltip> {
+        self.basalt.interface_ref().tooltips().get(self)
+    }
+
     pub fn keep_alive(&self, thing: Arc<dyn KeepAlive + Send + Sync>) {
         self.keep_alive.lock().push(thing);
     }
@@ -696,6 +747,33 @@ impl Bin {
         children
     }
 
+    /// Remove a child from this `Bin`. Returns `false` if it isn't a child of this `Bin`.
+    pub fn remove_child(self: &Arc<Self>, child: &Arc<Bin>) -> bool {
+        let this_hrchy = self.hrchy.load_full();
+        let mut children = this_hrchy.children.clone();
+        let len = children.len();
+        children.retain(|child_wk| !std::ptr::eq(child_wk.as_ptr(), Arc::as_ptr(child)));
+
+        if children.len() == len {
+            return false;
+        }
+
+        let child_hrchy = child.hrchy.load_full();
+
+        child.hrchy.store(Arc::new(BinHrchy {
+            parent: None,
+            children: child_hrchy.children.clone(),
+        }));
+
+        self.hrchy.store(Arc::new(BinHrchy {
+            children,
+            parent: this_hrchy.parent.clone(),
+        }));
+
+        self.call_children_removed_hooks(vec![Arc::downgrade(child)]);
+        true
+    }
+
     pub fn add_drag_events(self: &Arc<Self>, target_op: Option<Arc<Bin>>) {
         #[derive(Default)]
         struct Data {
@@ -1335,6 +1413,83 @@ impl Bin {
         self.update.load(atomic::Ordering::SeqCst)
     }
 
+    /// Obtain the `AtlasCoords` of glyphs, rasterizing & uploading those not yet in the `Atlas`.
+    ///
+    /// Glyphs without an image, such as spaces, are omitted.
+    fn glyph_atlas_coords(
+        &self,
+        context: &mut UpdateContext,
+        cache_ids: Vec<SubImageCacheID>,
+    ) -> HashMap<SubImageCacheID, AtlasCoords> {
+        let mut atlas_coords = HashMap::new();
+
+        for (atlas_coords_op, atlas_cache_id) in self
+            .basalt
+            .atlas_ref()
+            .batch_cache_coords(cache_ids.clone())
+            .into_iter()
+            .zip(cache_ids.into_iter())
+        {
+            if let Some(coords) = atlas_coords_op {
+                atlas_coords.insert(atlas_cache_id, coords);
+                continue;
+            }
+
+            let swash_cache_id = match atlas_cache_id {
+                SubImageCacheID::Glyph(swash_cache_id) => swash_cache_id,
+                _ => unreachable!(),
+            };
+
+            if let Some(swash_image) = context
+                .swash_cache
+                .get_image_uncached(&mut context.font_system, swash_cache_id)
+            {
+                if swash_image.placement.width == 0
+                    || swash_image.placement.height == 0
+                    || swash_image.data.is_empty()
+                {
+                    continue;
+                }
+
+                let (vertex_ty, image_ty): (i32, _) = match swash_image.content {
+                    text::SwashContent::Mask => (2, ImageType::LMono),
+                    text::SwashContent::SubpixelMask => (2, ImageType::LRGBA),
+                    text::SwashContent::Color => (100, ImageType::LRGBA),
+                };
+
+                let atlas_image = Image::new(
+                    image_ty,
+                    ImageDims {
+                        w: swash_image.placement.width,
+                        h: swash_image.placement.height,
+                    },
+                    ImageData::D8(swash_image.data.into_iter().collect()),
+                )
+                .unwrap();
+
+                let mut metadata = Vec::with_capacity(8);
+                metadata.extend_from_slice(&vertex_ty.to_le_bytes());
+                metadata.extend_from_slice(&swash_image.placement.left.to_le_bytes());
+                metadata.extend_from_slice(&swash_image.placement.top.to_le_bytes());
+
+                let coords = self
+                    .basalt
+                    .atlas_ref()
+                    .load_image(
+                        atlas_cache_id.clone(),
+                        AtlasCacheCtrl::Indefinite,
+                        atlas_image,
+                        metadata,
+                    )
+                    .unwrap();
+
+                atlas_coords.insert(atlas_cache_id, coords);
+            }
+        }
+
+        atlas_coords
+    }
+
     pub(crate) fn do_update(self: &Arc<Self>, context: &mut UpdateContext) {
         // -- Update Check ------------------------------------------------------------------ //
 
@@ -1376,7 +1531,9 @@ impl Bin {
             *self.verts.lock() = VertexState::default();
             *self.last_update.lock() = Instant::now();
             // TODO: should the entire PostUpdate be reset?
-            self.post_update.write().text_state = None;
+            let mut post_update = self.post_update.write();
+            post_update.text_state = None;
+            post_update.back_gradient = None;
             return;
         }
 
@@ -1497,6 +1654,7 @@ impl Bin {
             unbound_mm_y: [top, top + height],
             unbound_mm_x: [left, left + width],
             text_state: None,
+            back_gradient: None,
             extent: [
                 context.extent[0].trunc() as u32,
                 context.extent[1].trunc() as u32,
@@ -1514,6 +1672,37 @@ impl Bin {
 
         let back_image_cache = style.back_image_cache.unwrap_or_default();
 
+        let back_svg_coords = style.back_image_svg.as_ref().map(|path| {
+            #[cfg(feature = "svg")]
+            let result =
+                self.basalt
+                    .atlas_ref()
+                    .load_svg(back_image_cache, path, context.scale, Vec::new());
+
+            // Validation fails for styles with `back_image_svg` without the feature.
+            #[cfg(not(feature = "svg"))]
+            let result: Result<AtlasCoords, String> =
+                Err(String::from("the 'svg' feature is not enabled"));
+
+            match result {
+                Ok(coords) => coords,
+                Err(e) => {
+                    // TODO: Check during validation
+                    println!(
+                        "[Basalt]: Bin ID: {:?} | failed to load svg into atlas {}: {}",
+                        self.id, path, e
+                    );
+                    AtlasCoords::none()
+                },
+            }
+        });
+
+        // Svg's are rasterized at the current scale, while other images are a pixel per unit.
+        let back_image_scale = match back_svg_coords.is_some() {
+            true => context.scale,
+            false => 1.0,
+        };
+
         let (back_img, back_coords) = match style.back_image.as_ref() {
             Some(path) => {
                 match self.basalt.atlas_ref().load_image_from_path(
@@ -1574,7 +1763,9 @@ impl Bin {
 
                                         (Some(image.clone()), coords)
                                     },
-                                    None => (None, AtlasCoords::none()),
+                                    None => {
+                                        (None, back_svg_coords.unwrap_or_else(AtlasCoords::none))
+                                    },
                                 }
                             },
                         }
@@ -1622,6 +1813,16 @@ impl Bin {
         let content_z =
             (-(z_index + 1) as i32 + i16::max_value() as i32) as f32 / i32::max_value() as f32;
         let mut verts = Vec::with_capacity(54);
+        let has_back_image = !back_coords.is_none() || back_img.is_some();
+        let back_image_placed = has_back_image && style.back_image_placed();
+
+        let back_gradient = match style.back_gradient.as_ref() {
+            Some(gradient) if !gradient.stops.is_empty() => Some(gradient),
+            _ => None,
+        };
+
+        // Triangles of the background that a placed image or gradient is clipped to.
+        let mut back_shape = Vec::new();
 
         let border_radius_tl = style.border_radius_tl.unwrap_or(0.0);
         let border_radius_tr = style.border_radius_tr.unwrap_or(0.0);
@@ -1645,7 +1846,7 @@ impl Bin {
                 border_radius_br
             };
 
-            if back_color.a > 0.0 || !back_coords.is_none() || back_img.is_some() {
+            if back_color.a > 0.0 || has_back_image || back_gradient.is_some() {
                 let mut back_verts = Vec::new();
 
                 if border_radius_tl != 0.0 || border_radius_tr != 0.0 {
@@ -1803,7 +2004,7 @@ impl Bin {
                 back_verts.push([bps.bli[0], bps.bli[1] - border_radius_bmax]);
                 back_verts.push([bps.bri[0], bps.bri[1] - border_radius_bmax]);
 
-                let ty = if !back_coords.is_none() || back_img.is_some() {
+                let ty = if has_back_image && !back_image_placed {
                     back_img_vert_ty
                 } else {
                     0
@@ -1811,6 +2012,14 @@ impl Bin {
 
                 let bc_tlwh = back_coords.tlwh();
 
+                if back_image_placed || back_gradient.is_some() {
+                    back_shape = back_verts.clone();
+
+                    if ty == 0 && back_color.a <= 0.0 {
+                        back_verts.clear();
+                    }
+                }
+
                 for [x, y] in back_verts {
                     let coords_x =
                         (((x - bps.tli[0]) / (bps.tri[0] - bps.tli[0])) * bc_tlwh[2]) + bc_tlwh[0];
@@ -2207,8 +2416,12 @@ impl Bin {
                     tex_i: 0,
                 });
             }
-            if back_color.a > 0.0 || !back_coords.is_none() || back_img.is_some() {
-                let ty = if !back_coords.is_none() || back_img.is_some() {
+            if back_image_placed || back_gradient.is_some() {
+                back_shape = vec![bps.tri, bps.tli, bps.bli, bps.tri, bps.bli, bps.bri];
+            }
+
+            if back_color.a > 0.0 || (has_back_image && !back_image_placed) {
+                let ty = if has_back_image && !back_image_placed {
                     back_img_vert_ty
                 } else {
                     0
@@ -2259,6 +2472,88 @@ impl Bin {
             }
         }
 
+        if back_image_placed {
+            // Placed images are above the back color, which is displayed where they don't cover.
+            let image_z =
+                (-(z_index as f32 + 0.5) + i16::max_value() as f32) / i32::max_value() as f32;
+
+            let pieces = back_image_pieces(
+                [bps.tli[0], bps.tli[1], bps.tri[0], bps.bli[1]],
+                back_coords.tlwh(),
+                back_image_scale,
+                &style,
+            );
+
+            for ([px0, py0, px1, py1], [sx, sy, sw, sh]) in pieces {
+                for tri in back_shape.chunks_exact(3) {
+                    let poly = clip_tri_to_rect([tri[0], tri[1], tri[2]], [px0, py0, px1, py1]);
+
+                    if poly.len() < 3 {
+                        continue;
+                    }
+
+                    let to_vert = |[x, y]: [f32; 2]| {
+                        ItfVertInfo {
+                            position: [x, y, image_z],
+                            coords: [
+                                sx + (((x - px0) / (px1 - px0)) * sw),
+                                sy + (((y - py0) / (py1 - py0)) * sh),
+                            ],
+                            color: back_color.as_array(),
+                            ty: back_img_vert_ty,
+                            tex_i: 0,
+                        }
+                    };
+
+                    for i in 1..(poly.len() - 1) {
+                        verts.push(to_vert(poly[0]));
+                        verts.push(to_vert(poly[i]));
+                        verts.push(to_vert(poly[i + 1]));
+                    }
+                }
+            }
+        }
+
+        let gradient_data = back_gradient.map(|gradient| {
+            let ramp_coords = match last_update.back_gradient.as_ref() {
+                Some((last_gradient, coords)) if last_gradient == gradient => coords.clone(),
+                _ => {
+                    let atlas = self.basalt.atlas_ref();
+                    let cache_id = gradient_ramp_cache_id(gradient);
+
+                    match atlas.cache_coords(cache_id.clone()) {
+                        Some(coords) => coords,
+                        None => {
+                            atlas
+                                .load_image(
+                                    cache_id,
+                                    back_image_cache,
+                                    gradient_ramp(gradient),
+                                    Vec::new(),
+                                )
+                                .unwrap()
+                        },
+                    }
+                },
+            };
+
+            // Gradients are between the back color & a placed image.
+            let gradient_z =
+                (-(z_index as f32 + 0.25) + i16::max_value() as f32) / i32::max_value() as f32;
+
+            let gradient_verts = gradient_verts(
+                gradient,
+                [bps.tli[0], bps.tli[1], bps.tri[0], bps.bli[1]],
+                &back_shape,
+                &ramp_coords,
+                gradient_z,
+                opacity,
+            );
+
+            bps.back_gradient = Some((gradient.clone(), ramp_coords.clone()));
+            (gradient_verts, ramp_coords)
+        });
+
         for BinVert {
             position,
             color,
@@ -2287,6 +2582,11 @@ impl Bin {
             atlas_coords_in_use.insert(back_coords);
         }
 
+        if let Some((gradient_verts, ramp_coords)) = gradient_data {
+            vert_data.push((gradient_verts, None, ramp_coords.image_id()));
+            atlas_coords_in_use.insert(ramp_coords);
+        }
+
         if update_stats {
             stats.t_verts = inst.elapsed();
             stats.t_total += inst.elapsed();
@@ -2479,72 +2779,8 @@ impl Bin {
 
             // -- Glyph Fetch/Raster -- //
 
-            let atlas_cache_ids = atlas_cache_ids.into_iter().collect::<Vec<_>>();
-            let mut atlas_coords = HashMap::new();
-
-            for (atlas_coords_op, atlas_cache_id) in self
-                .basalt
-                .atlas_ref()
-                .batch_cache_coords(atlas_cache_ids.clone())
-                .into_iter()
-                .zip(atlas_cache_ids.into_iter())
-            {
-                if let Some(coords) = atlas_coords_op {
-                    atlas_coords.insert(atlas_cache_id, coords);
-                    continue;
-                }
-
-                let swash_cache_id = match atlas_cache_id {
-                    SubImageCacheID::Glyph(swash_cache_id) => swash_cache_id,
-                    _ => unreachable!(),
-                };
-
-                if let Some(swash_image) = context
-                    .swash_cache
-                    .get_image_uncached(&mut context.font_system, swash_cache_id)
-                {
-                    if swash_image.placement.width == 0
-                        || swash_image.placement.height == 0
-                        || swash_image.data.is_empty()
-                    {
-                        continue;
-                    }
-
-                    let (vertex_ty, image_ty): (i32, _) = match swash_image.content {
-                        text::SwashContent::Mask => (2, ImageType::LMono),
-                        text::SwashContent::SubpixelMask => (2, ImageType::LRGBA),
-                        text::SwashContent::Color => (100, ImageType::LRGBA),
-                    };
-
-                    let atlas_image = Image::new(
-                        image_ty,
-                        ImageDims {
-                            w: swash_image.placement.width,
-                            h: swash_image.placement.height,
-                        },
-                        ImageData::D8(swash_image.data.into_iter().collect()),
-                    )
-                    .unwrap();
-
-                    let mut metadata = Vec::with_capacity(8);
-                    metadata.extend_from_slice(&vertex_ty.to_le_bytes());
-                    metadata.extend_from_slice(&swash_image.placement.left.to_le_bytes());
-                    metadata.extend_from_slice(&swash_image.placement.top.to_le_bytes());
-
-                    let coords = self
-                        .basalt
-                        .atlas_ref()
-                        .load_image(
-                            atlas_cache_id.clone(),
-                            AtlasCacheCtrl::Indefinite,
-                            atlas_image,
-                            metadata,
-                        )
-                        .unwrap();
-
-                    atlas_coords.insert(atlas_cache_id, coords);
-                }
-            }
+            let atlas_coords =
+                self.glyph_atlas_coords(context, atlas_cache_ids.into_iter().collect());
 
             // -- Finalize Placement -- //
 
@@ -2709,6 +2945,170 @@ impl Bin {
             });
         }
 
+        // -- Icon -------------------------------------------------------------------------- //
+
+        'icon_done: {
+            let icon = match style.icon.as_ref() {
+                Some(some) => some,
+                None => break 'icon_done,
+            };
+
+            let icon_size = style.icon_size.or(style.text_height).unwrap_or(12.0) * context.scale;
+
+            let metrics = text::Metrics {
+                font_size: icon_size,
+                line_height: icon_size,
+            };
+
+            let mut buffer = text::Buffer::new(&mut context.font_system, metrics);
+            buffer.set_size(&mut context.font_system, f32::MAX, f32::MAX);
+            let mut attrs = text::Attrs::new().family(text::Family::Name(&icon.family));
+
+            if let Some(weight) = icon.weight {
+                attrs = attrs.weight(weight.into());
+            }
+
+            let icon_text = match &icon.glyph {
+                IconGlyph::Codepoint(codepoint) => codepoint.to_string(),
+                IconGlyph::Name(name) => name.clone(),
+            };
+
+            buffer.set_text(&mut context.font_system, &icon_text, attrs);
+            buffer.shape_until(&mut context.font_system, 1);
+            let mut glyph_info = Vec::new();
+
+            for run in buffer.layout_runs() {
+                for glyph in run.glyphs.iter() {
+                    glyph_info.push((
+                        SubImageCacheID::Glyph(glyph.cache_key),
+                        glyph.x_int as f32,
+                        run.line_y,
+                    ));
+                }
+            }
+
+            let atlas_coords = self.glyph_atlas_coords(
+                context,
+                glyph_info
+                    .iter()
+                    .map(|(atlas_cache_id, ..)| atlas_cache_id.clone())
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut glyphs = Vec::with_capacity(glyph_info.len());
+            let mut bounds = [f32::MAX, f32::MAX, f32::MIN, f32::MIN];
+
+            for (atlas_cache_id, glyph_x, glyph_y) in glyph_info {
+                let coords = match atlas_coords.get(&atlas_cache_id) {
+                    Some(coords) => coords.clone(),
+                    None => continue,
+                };
+
+                let placement_left =
+                    i32::from_le_bytes(coords.metadata()[4..8].try_into().unwrap());
+                let placement_top =
+                    i32::from_le_bytes(coords.metadata()[8..12].try_into().unwrap());
+                let min_x = glyph_x + placement_left as f32;
+                let min_y = glyph_y - placement_top as f32;
+                let [glyph_w, glyph_h] = coords.width_height();
+                bounds[0] = bounds[0].min(min_x);
+                bounds[1] = bounds[1].min(min_y);
+                bounds[2] = bounds[2].max(min_x + glyph_w);
+                bounds[3] = bounds[3].max(min_y + glyph_h);
+                glyphs.push((coords, min_x, min_y));
+            }
+
+            if glyphs.is_empty() {
+                break 'icon_done;
+            }
+
+            // Centered using the bounds of the glyph images rather than the font's metrics, as
+            // icons are rarely aligned to the baseline.
+            let center_x = (bps.tli[0] + style.pad_l.unwrap_or(0.0) + bps.tri[0]
+                - style.pad_r.unwrap_or(0.0))
+                / 2.0;
+            let center_y = (bps.tli[1] + style.pad_t.unwrap_or(0.0) + bps.bli[1]
+                - style.pad_b.unwrap_or(0.0))
+                / 2.0;
+
+            // Note: Round not to interfere with hinting
+            let offset_x = ((center_x * context.scale) - ((bounds[0] + bounds[2]) / 2.0)).round();
+            let offset_y = ((center_y * context.scale) - ((bounds[1] + bounds[3]) / 2.0)).round();
+
+            let mut color = style
+                .text_color
+                .clone()
+                .unwrap_or_else(|| Color::srgb_hex("000000"));
+
+            color.a *= opacity;
+            let mut icon_vertex_data: HashMap<u32, Vec<ItfVertInfo>> = HashMap::new();
+
+            for (coords, glyph_x, glyph_y) in glyphs {
+                let vertex_ty = i32::from_le_bytes(coords.metadata()[0..4].try_into().unwrap());
+                let [glyph_w, glyph_h] = coords.width_height();
+                let min_x = (glyph_x + offset_x) / context.scale;
+                let min_y = (glyph_y + offset_y) / context.scale;
+                let max_x = min_x + (glyph_w / context.scale);
+                let max_y = min_y + (glyph_h / context.scale);
+                let [c_min_x, c_min_y] = coords.top_left();
+                let [c_max_x, c_max_y] = coords.bottom_right();
+                let tex_i = coords.image_id() as u32;
+
+                icon_vertex_data.entry(tex_i).or_default().extend([
+                    ItfVertInfo {
+                        position: [max_x, min_y, content_z],
+                        coords: [c_max_x, c_min_y],
+                        color: color.as_array(),
+                        ty: vertex_ty,
+                        tex_i,
+                    },
+                    ItfVertInfo {
+                        position: [min_x, min_y, content_z],
+                        coords: [c_min_x, c_min_y],
+                        color: color.as_array(),
+                        ty: vertex_ty,
+                        tex_i,
+                    },
+                    ItfVertInfo {
+                        position: [min_x, max_y, content_z],
+                        coords: [c_min_x, c_max_y],
+                        color: color.as_array(),
+                        ty: vertex_ty,
+                        tex_i,
+                    },
+                    ItfVertInfo {
+                        position: [max_x, min_y, content_z],
+                        coords: [c_max_x, c_min_y],
+                        color: color.as_array(),
+                        ty: vertex_ty,
+                        tex_i,
+                    },
+                    ItfVertInfo {
+                        position: [min_x, max_y, content_z],
+                        coords: [c_min_x, c_max_y],
+                        color: color.as_array(),
+                        ty: vertex_ty,
+                        tex_i,
+                    },
+                    ItfVertInfo {
+                        position: [max_x, max_y, content_z],
+                        coords: [c_max_x, c_max_y],
+                        color: color.as_array(),
+                        ty: vertex_ty,
+                        tex_i,
+                    },
+                ]);
+
+                atlas_coords_in_use.insert(coords);
+            }
+
+            for (tex_i, vertexes) in icon_vertex_data {
+                vert_data.push((vertexes, None, tex_i as u64));
+            }
+        }
+
         if update_stats {
             stats.t_text = inst.elapsed();
             stats.t_total += inst.elapsed();
@@ -3006,13 +3406,24 @@ impl Bin {
 
     #[track_caller]
     pub fn style_update(&self, copy: BinStyle) -> BinStyleValidation {
+        let validation = self.style_store(copy);
+
+        if !validation.errors_present() {
+            self.basalt.interface_ref().composer_ref().unpark();
+        }
+
+        validation
+    }
+
+    /// Same as `style_update`, but without waking the composer. This allows many `Bin`'s to be
+    /// updated before the composer is woken once.
+    pub(crate) fn style_store(&self, copy: BinStyle) -> BinStyleValidation {
         let validation = copy.validate(self.hrchy.load().parent.is_some());
 
         if !validation.errors_present() {
             self.style.store(Arc::new(copy));
             *self.initial.lock() = false;
             self.update.store(true, atomic::Ordering::SeqCst);
-            self.basalt.interface_ref().composer_ref().unpark();
         }
 
         validation
@@ -3026,7 +3437,495 @@ impl Bin {
     }
 }
 
-fn curve_line_segments(
+/// The maximum amount of tiles of a repeated background image, beyond this tiles are omitted.
+const MAX_BACK_IMAGE_TILES: usize = 4096;
+
+/// The pieces of a placed background image as the rect they're displayed in & the rect of the
+/// image they display. Rects are `[min_x, min_y, max_x, max_y]` and image rects are `tlwh`.
+///
+/// `src_scale` is the amount of image pixels per unit of the interface.
+fn back_image_pieces(
+    [x0, y0, x1, y1]: [f32; 4],
+    [sx, sy, sw, sh]: [f32; 4],
+    src_scale: f32,
+    style: &BinStyle,
+) -> Vec<([f32; 4], [f32; 4])> {
+    let (w, h) = (x1 - x0, y1 - y0);
+    // The size of the image in units of the interface.
+    let (iw, ih) = (sw / src_scale, sh / src_scale);
+
+    if w <= 0.0 || h <= 0.0 || iw <= 0.0 || ih <= 0.0 {
+        return Vec::new();
+    }
+
+    if let Some(slice) = style.back_image_slice {
+        let l = slice.left.clamp(0.0, iw);
+        let r = slice.right.clamp(0.0, iw - l);
+        let t = slice.top.clamp(0.0, ih);
+        let b = slice.bottom.clamp(0.0, ih - t);
+        // Corners are scaled down when the bin is smaller than the insets.
+        let scale_x = (w / (l + r)).min(1.0);
+        let scale_y = (h / (t + b)).min(1.0);
+        let dst_x = [x0, x0 + (l * scale_x), x1 - (r * scale_x), x1];
+        let dst_y = [y0, y0 + (t * scale_y), y1 - (b * scale_y), y1];
+        let src_x = [sx, sx + (l * src_scale), sx + sw - (r * src_scale), sx + sw];
+        let src_y = [sy, sy + (t * src_scale), sy + sh - (b * src_scale), sy + sh];
+        let mut pieces = Vec::with_capacity(9);
+
+        for row in 0..3 {
+            for col in 0..3 {
+                if dst_x[col + 1] - dst_x[col] <= 0.0
+                    || dst_y[row + 1] - dst_y[row] <= 0.0
+                    || src_x[col + 1] - src_x[col] <= 0.0
+                    || src_y[row + 1] - src_y[row] <= 0.0
+                {
+                    continue;
+                }
+
+                pieces.push((
+                    [dst_x[col], dst_y[row], dst_x[col + 1], dst_y[row + 1]],
+                    [
+                        src_x[col],
+                        src_y[row],
+                        src_x[col + 1] - src_x[col],
+                        src_y[row + 1] - src_y[row],
+                    ],
+                ));
+            }
+        }
+
+        return pieces;
+    }
+
+    let fit = style.back_image_fit.unwrap_or_default();
+    let repeat = style.back_image_repeat.unwrap_or_default();
+    let [ax, ay] = style.back_image_align.unwrap_or_default().factors();
+
+    let scale = match fit {
+        ImageFit::Fill => None,
+        ImageFit::Contain => Some((w / iw).min(h / ih)),
+        ImageFit::Cover => Some((w / iw).max(h / ih)),
+        ImageFit::None => Some(1.0),
+        ImageFit::ScaleDown => Some((w / iw).min(h / ih).min(1.0)),
+    };
+
+    let (dw, dh) = match (scale, repeat) {
+        (Some(scale), _) => (iw * scale, ih * scale),
+        (None, ImageRepeat::NoRepeat) => (w, h),
+        // Filling the bin with tiles of the image's size.
+        (None, _) => (iw, ih),
+    };
+
+    if dw < 1.0 || dh < 1.0 {
+        return Vec::new();
+    }
+
+    let dx = x0 + ((w - dw) * ax);
+    let dy = y0 + ((h - dh) * ay);
+
+    let (repeat_x, repeat_y) = match repeat {
+        ImageRepeat::NoRepeat => (false, false),
+        ImageRepeat::Repeat => (true, true),
+        ImageRepeat::RepeatX => (true, false),
+        ImageRepeat::RepeatY => (false, true),
+    };
+
+    let starts = |start: f32, size: f32, min: f32, max: f32, repeat: bool| -> Vec<f32> {
+        if !repeat {
+            return vec![start];
+        }
+
+        let mut pos = start - (((start - min) / size).ceil() * size);
+        let mut out = Vec::new();
+
+        while pos < max {
+            out.push(pos);
+            pos += size;
+        }
+
+        out
+    };
+
+    let mut pieces = Vec::new();
+
+    'rows: for ty in starts(dy, dh, y0, y1, repeat_y) {
+        for tx in starts(dx, dw, x0, x1, repeat_x) {
+            if pieces.len() >= MAX_BACK_IMAGE_TILES {
+                break 'rows;
+            }
+
+            pieces.push(([tx, ty, tx + dw, ty + dh], [sx, sy, sw, sh]));
+        }
+    }
+
+    pieces
+}
+
+/// Clip a triangle to a rect of `[min_x, min_y, max_x, max_y]` returning a convex polygon.
+fn clip_tri_to_rect(tri: [[f32; 2]; 3], [x0, y0, x1, y1]: [f32; 4]) -> Vec<[f32; 2]> {
+    let mut poly = tri.to_vec();
+
+    // Each edge of the rect as the axis, the bound and if points must be greater than it.
+    for (axis, bound, greater) in [(0, x0, true), (0, x1, false), (1, y0, true), (1, y1, false)] {
+        if poly.is_empty() {
+            break;
+        }
+
+        let inside = |p: &[f32; 2]| {
+            match greater {
+                true => p[axis] >= bound,
+                false => p[axis] <= bound,
+            }
+        };
+
+        let mut clipped = Vec::with_capacity(poly.len() + 2);
+
+        for i in 0..poly.len() {
+            let a = poly[i];
+            let b = poly[(i + 1) % poly.len()];
+
+            if inside(&a) {
+                clipped.push(a);
+            }
+
+            if inside(&a) != inside(&b) {
+                let t = (bound - a[axis]) / (b[axis] - a[axis]);
+                clipped.push([a[0] + ((b[0] - a[0]) * t), a[1] + ((b[1] - a[1]) * t)]);
+            }
+        }
+
+        poly = clipped;
+    }
+
+    poly
+}
+
+/// The amount of colors in the ramp of a gradient.
+const GRADIENT_RAMP_LEN: usize = 256;
+/// Texels of the ramp repeated on each side, so sampling never reaches outside of it.
+const GRADIENT_RAMP_PAD: usize = 2;
+/// The amount of wedges radial & conic gradients are divided into.
+const GRADIENT_SEGMENTS: usize = 64;
+
+/// The cache id of the ramp of a gradient. Gradients that only differ in kind share a ramp.
+fn gradient_ramp_cache_id(gradient: &Gradient) -> SubImageCacheID {
+    let mut hasher = DefaultHasher::new();
+    GRADIENT_RAMP_LEN.hash(&mut hasher);
+    GRADIENT_RAMP_PAD.hash(&mut hasher);
+    gradient.space.hash(&mut hasher);
+
+    for stop in gradient.stops.iter() {
+        stop.position.to_bits().hash(&mut hasher);
+
+        for c in stop.color.as_array() {
+            c.to_bits().hash(&mut hasher);
+        }
+    }
+
+    SubImageCacheID::Gradient(hasher.finish())
+}
+
+/// Rasterize the color stops of a gradient into a ramp image.
+///
+/// Colors are interpolated premultiplied in the gradient's color space and stored linear.
+fn gradient_ramp(gradient: &Gradient) -> Image {
+    let to_space = |c: f32| {
+        match gradient.space {
+            GradientSpace::Srgb => {
+                match c <= 0.0031308 {
+                    true => c.max(0.0) * 12.92,
+                    false => (c.min(1.0).powf(1.0 / 2.4) * 1.055) - 0.055,
+                }
+            },
+            GradientSpace::Linear => c,
+        }
+    };
+
+    let from_space = |c: f32| {
+        match gradient.space {
+            GradientSpace::Srgb => {
+                match c <= 0.04045 {
+                    true => c.max(0.0) / 12.92,
+                    false => ((c.min(1.0) + 0.055) / 1.055).powf(2.4),
+                }
+            },
+            GradientSpace::Linear => c,
+        }
+    };
+
+    let mut stops = gradient
+        .stops
+        .iter()
+        .map(|stop| {
+            let a = stop.color.a.clamp(0.0, 1.0);
+
+            (
+                stop.position.clamp(0.0, 1.0),
+                [
+                    to_space(stop.color.r) * a,
+                    to_space(stop.color.g) * a,
+                    to_space(stop.color.b) * a,
+                    a,
+                ],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Stable, so stops sharing a position remain in the order they're defined.
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let ramp = (0..GRADIENT_RAMP_LEN)
+        .map(|i| {
+            let t = i as f32 / (GRADIENT_RAMP_LEN - 1) as f32;
+            let after = stops.partition_point(|(position, _)| *position <= t);
+
+            let [r, g, b, a] = if after == 0 {
+                stops[0].1
+            } else if after == stops.len() {
+                stops[after - 1].1
+            } else {
+                let (a_pos, a_color) = stops[after - 1];
+                let (b_pos, b_color) = stops[after];
+                let f = (t - a_pos) / (b_pos - a_pos);
+                [0, 1, 2, 3].map(|c| a_color[c] + ((b_color[c] - a_color[c]) * f))
+            };
+
+            match a > 0.0 {
+                true => [from_space(r / a), from_space(g / a), from_space(b / a), a],
+                false => [0.0; 4],
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let width = GRADIENT_RAMP_LEN + (GRADIENT_RAMP_PAD * 2);
+    let height = (GRADIENT_RAMP_PAD * 2) + 1;
+    let mut data = Vec::with_capacity(width * height * 4);
+
+    for _ in 0..height {
+        for x in 0..width {
+            let color = ramp[x
+                .saturating_sub(GRADIENT_RAMP_PAD)
+                .min(GRADIENT_RAMP_LEN - 1)];
+
+            data.extend(
+                color
+                    .into_iter()
+                    .map(|c| (c.clamp(0.0, 1.0) * u16::max_value() as f32).round() as u16),
+            );
+        }
+    }
+
+    Image::new(
+        ImageType::LRGBA,
+        ImageDims {
+            w: width as u32,
+            h: height as u32,
+        },
+        ImageData::D16(data),
+    )
+    .unwrap()
+}
+
+/// Generate the vertexes of a gradient within the rect of `[min_x, min_y, max_x, max_y]`
+/// clipped to the triangles of `shape`.
+///
+/// The gradient is split into convex cells where the position within the ramp is linear, or
+/// close to it, so that it can be interpolated between vertexes.
+fn gradient_verts(
+    gradient: &Gradient,
+    [x0, y0, x1, y1]: [f32; 4],
+    shape: &[[f32; 2]],
+    ramp_coords: &AtlasCoords,
+    z: f32,
+    opacity: f32,
+) -> Vec<ItfVertInfo> {
+    let (w, h) = (x1 - x0, y1 - y0);
+
+    if w <= 0.0 || h <= 0.0 {
+        return Vec::new();
+    }
+
+    // Cells are defined by half-planes of `(point, normal)` & the range of the ramp within them.
+    let mut cells: Vec<(Vec<([f32; 2], [f32; 2])>, [f32; 2])> = Vec::new();
+    let segment = |i: usize| i as f32 / GRADIENT_SEGMENTS as f32;
+
+    // The half-planes bounding a wedge from `center` between two directions.
+    let wedge = |center: [f32; 2], d0: [f32; 2], d1: [f32; 2]| {
+        vec![(center, [-d0[1], d0[0]]), (center, [d1[1], -d1[0]])]
+    };
+
+    let ramp_pos: Box<dyn Fn([f32; 2], [f32; 2]) -> f32> = match gradient.kind {
+        GradientKind::Linear {
+            angle,
+        } => {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let dir = [sin, -cos];
+            let back = [-sin, cos];
+            let len = (w * sin).abs() + (h * cos).abs();
+            let start = [
+                x0 + (w / 2.0) - (dir[0] * len / 2.0),
+                y0 + (h / 2.0) - (dir[1] * len / 2.0),
+            ];
+            let end = [start[0] + (dir[0] * len), start[1] + (dir[1] * len)];
+            cells.push((vec![(start, back)], [0.0, 0.0]));
+            cells.push((vec![(start, dir), (end, back)], [0.0, 1.0]));
+            cells.push((vec![(end, dir)], [1.0, 1.0]));
+
+            Box::new(move |[x, y]: [f32; 2], [min, max]: [f32; 2]| {
+                ((((x - start[0]) * dir[0]) + ((y - start[1]) * dir[1])) / len).clamp(min, max)
+            })
+        },
+        GradientKind::Radial {
+            center,
+            radius,
+        } => {
+            let center = [x0 + (center[0] * w), y0 + (center[1] * h)];
+            let (rx, ry) = (radius[0] * w, radius[1] * h);
+
+            if rx <= 0.0 || ry <= 0.0 {
+                cells.push((Vec::new(), [1.0, 1.0]));
+            } else {
+                for i in 0..GRADIENT_SEGMENTS {
+                    let (sin0, cos0) = (segment(i) * TAU).sin_cos();
+                    let (sin1, cos1) = (segment(i + 1) * TAU).sin_cos();
+                    let d0 = [cos0 * rx, sin0 * ry];
+                    let d1 = [cos1 * rx, sin1 * ry];
+                    // The chord between where the wedge reaches the radius.
+                    let p0 = [center[0] + d0[0], center[1] + d0[1]];
+                    let inward = [-(d0[0] + d1[0]) / 2.0, -(d0[1] + d1[1]) / 2.0];
+                    let outward = [-inward[0], -inward[1]];
+                    let mut inner = wedge(center, d0, d1);
+                    let mut outer = inner.clone();
+                    inner.push((p0, inward));
+                    outer.push((p0, outward));
+                    cells.push((inner, [0.0, 1.0]));
+                    cells.push((outer, [1.0, 1.0]));
+                }
+            }
+
+            Box::new(move |[x, y]: [f32; 2], [min, max]: [f32; 2]| {
+                match rx <= 0.0 || ry <= 0.0 {
+                    true => max,
+                    false => {
+                        (((x - center[0]) / rx).powi(2) + ((y - center[1]) / ry).powi(2))
+                            .sqrt()
+                            .clamp(min, max)
+                    },
+                }
+            })
+        },
+        GradientKind::Conic {
+            center,
+            angle,
+        } => {
+            let center = [x0 + (center[0] * w), y0 + (center[1] * h)];
+            let start = angle.to_radians();
+
+            for i in 0..GRADIENT_SEGMENTS {
+                let (sin0, cos0) = (start + (segment(i) * TAU)).sin_cos();
+                let (sin1, cos1) = (start + (segment(i + 1) * TAU)).sin_cos();
+                let d0 = [sin0, -cos0];
+                let d1 = [sin1, -cos1];
+                cells.push((wedge(center, d0, d1), [segment(i), segment(i + 1)]));
+            }
+
+            Box::new(move |[x, y]: [f32; 2], [min, max]: [f32; 2]| {
+                let (dx, dy) = (x - center[0], y - center[1]);
+
+                // The angle is undefined at the center.
+                if dx.abs() < f32::EPSILON && dy.abs() < f32::EPSILON {
+                    return (min + max) / 2.0;
+                }
+
+                let mut t = (dx.atan2(-dy) - start).rem_euclid(TAU) / TAU;
+
+                // Points on the starting edge may wrap around to the other end.
+                if t > max + 0.5 {
+                    t -= 1.0;
+                } else if t < min - 0.5 {
+                    t += 1.0;
+                }
+
+                t.clamp(min, max)
+            })
+        },
+    };
+
+    let [rx, ry, ..] = ramp_coords.tlwh();
+    let ramp_x = rx + GRADIENT_RAMP_PAD as f32 + 0.5;
+    let ramp_y = ry + GRADIENT_RAMP_PAD as f32 + 0.5;
+    let tex_i = ramp_coords.image_id() as u32;
+    let mut verts = Vec::new();
+
+    for tri in shape.chunks_exact(3) {
+        for (planes, range) in cells.iter() {
+            let mut poly = tri.to_vec();
+
+            for (point, normal) in planes.iter() {
+                if poly.len() < 3 {
+                    break;
+                }
+
+                poly = clip_poly_to_half_plane(poly, *point, *normal);
+            }
+
+            if poly.len() < 3 {
+                continue;
+            }
+
+            let to_vert = |position: [f32; 2]| {
+                ItfVertInfo {
+                    position: [position[0], position[1], z],
+                    coords: [
+                        ramp_x + (ramp_pos(position, *range) * (GRADIENT_RAMP_LEN - 1) as f32),
+                        ramp_y,
+                    ],
+                    color: [1.0, 1.0, 1.0, opacity],
+                    // BackColorMultiply, so that opacity is applied.
+                    ty: 105,
+                    tex_i,
+                }
+            };
+
+            for i in 1..(poly.len() - 1) {
+                verts.push(to_vert(poly[0]));
+                verts.push(to_vert(poly[i]));
+                verts.push(to_vert(poly[i + 1]));
+            }
+        }
+    }
+
+    verts
+}
+
+/// Clip a convex polygon to the half-plane where points are on the side `normal` faces.
+fn clip_poly_to_half_plane(
+    poly: Vec<[f32; 2]>,
+    point: [f32; 2],
+    normal: [f32; 2],
+) -> Vec<[f32; 2]> {
+    let dist = |p: &[f32; 2]| ((p[0] - point[0]) * normal[0]) + ((p[1] - point[1]) * normal[1]);
+    let mut clipped = Vec::with_capacity(poly.len() + 1);
+
+    for i in 0..poly.len() {
+        let a = poly[i];
+        let b = poly[(i + 1) % poly.len()];
+        let (da, db) = (dist(&a), dist(&b));
+
+        if da >= 0.0 {
+            clipped.push(a);
+        }
+
+        if (da >= 0.0) != (db >= 0.0) {
+            let t = da / (da - db);
+            clipped.push([a[0] + ((b[0] - a[0]) * t), a[1] + ((b[1] - a[1]) * t)]);
+        }
+    }
+
+    clipped
+}
+
+pub(crate) fn curve_line_segments(
     a: (f32, f32),
     b: (f32, f32),
     c: (f32, f32),
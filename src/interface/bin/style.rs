@@ -183,6 +183,32 @@ pub struct BinStyle {
     pub back_image_raw_coords: Option<AtlasCoords>,
     pub back_image_cache: Option<AtlasCacheCtrl>,
     pub back_image_effect: Option<ImageEffect>,
+    /// Path of an svg to use as the background image.
+    ///
+    /// The svg is rasterized at the current effective scale and rasterized again when the scale
+    /// changes. Sizes of the image used by `back_image_fit` & `back_image_slice` are that of the
+    /// svg before scaling.
+    ///
+    /// # Notes
+    /// - Requires the `svg` feature, without it validation will fail.
+    pub back_image_svg: Option<String>,
+    /// How the background image is sized within the bin.
+    ///
+    /// **Default**: `ImageFit::Fill`
+    pub back_image_fit: Option<ImageFit>,
+    /// Where the background image is placed when it doesn't fill the bin.
+    ///
+    /// **Default**: `ImageAlign::Center`
+    pub back_image_align: Option<ImageAlign>,
+    /// Repeat the background image to fill the bin.
+    ///
+    /// **Default**: `ImageRepeat::NoRepeat`
+    pub back_image_repeat: Option<ImageRepeat>,
+    /// Scale the background image as a nine-slice. When set, the fit, alignment & repeat of the
+    /// background image are ignored.
+    pub back_image_slice: Option<ImageSlice>,
+    /// A gradient displayed above `back_color` and below the background image.
+    pub back_gradient: Option<Gradient>,
     // Text
     pub text: String,
     pub text_color: Option<Color>,
@@ -197,6 +223,15 @@ pub struct BinStyle {
     pub font_weight: Option<FontWeight>,
     pub font_stretch: Option<FontStretch>,
     pub font_style: Option<FontStyle>,
+    // Icon
+    /// An icon from an icon font displayed centered within the padding of the bin.
+    ///
+    /// The icon is colored by `text_color` and is displayed independently of `text`.
+    pub icon: Option<Icon>,
+    /// Size of the icon.
+    ///
+    /// **Default**: `text_height` or `12.0`
+    pub icon_size: Option<f32>,
     // Misc
     pub custom_verts: Vec<BinVert>,
 }
@@ -223,6 +258,8 @@ pub enum BinStyleErrorType {
     NotEnoughConstraints,
     /// Requested font family & weight are not available.
     MissingFont,
+    /// A field requires a feature that isn't enabled.
+    MissingFeature,
 }
 
 impl std::fmt::Display for BinStyleErrorType {
@@ -232,6 +269,7 @@ impl std::fmt::Display for BinStyleErrorType {
             Self::TooManyConstraints => write!(f, "Too Many Constraints"),
             Self::NotEnoughConstraints => write!(f, "Not Enough Constraints"),
             Self::MissingFont => write!(f, "Missing Font"),
+            Self::MissingFeature => write!(f, "Missing Feature"),
         }
     }
 }
@@ -723,6 +761,16 @@ impl BinStyle {
             back_image_defined.push("back_image_raw");
         }
 
+        if self.back_image_svg.is_some() {
+            back_image_defined.push("back_image_svg");
+
+            #[cfg(not(feature = "svg"))]
+            validation.error(
+                BinStyleErrorType::MissingFeature,
+                "'back_image_svg' is defined, but the 'svg' feature is not enabled.",
+            );
+        }
+
         match back_image_defined.len() {
             0 => {
                 useless_field!(
@@ -734,6 +782,10 @@ impl BinStyle {
 
                 useless_field!(self, back_image_cache, "back_image_cache", validation);
                 useless_field!(self, back_image_effect, "back_image_effect", validation);
+                useless_field!(self, back_image_fit, "back_image_fit", validation);
+                useless_field!(self, back_image_align, "back_image_align", validation);
+                useless_field!(self, back_image_repeat, "back_image_repeat", validation);
+                useless_field!(self, back_image_slice, "back_image_slice", validation);
             },
             1 => {
                 let back_color_has_effect = match self.back_image_effect {
@@ -741,10 +793,21 @@ impl BinStyle {
                     Some(_) => true,
                 };
 
-                if !back_color_has_effect {
+                // The back color is displayed behind images that may not cover the bin.
+                if !back_color_has_effect && !self.back_image_placed() {
                     useless_field!(self, back_color, "back_color", validation);
                 }
 
+                if self.back_image_slice.is_some() {
+                    useless_field!(self, back_image_fit, "back_image_fit", validation);
+                    useless_field!(self, back_image_align, "back_image_align", validation);
+                    useless_field!(self, back_image_repeat, "back_image_repeat", validation);
+                } else if self.back_image_fit.unwrap_or_default() == ImageFit::Fill
+                    && self.back_image_repeat.unwrap_or_default() == ImageRepeat::NoRepeat
+                {
+                    useless_field!(self, back_image_align, "back_image_align", validation);
+                }
+
                 if self.back_image_raw.is_none() {
                     useless_field!(
                         self,
@@ -779,8 +842,28 @@ impl BinStyle {
             },
         }
 
+        if let Some(gradient) = self.back_gradient.as_ref() {
+            if gradient.stops.is_empty() {
+                validation.warning(
+                    BinStyleWarnType::UselessField,
+                    "'back_gradient' is defined, but has no color stops.",
+                );
+            }
+        }
+
+        if self.icon.is_none() {
+            useless_field!(self, icon_size, "icon_size", validation);
+        }
+
         validation
     }
+
+    /// Returns `true` if the background image isn't simply stretched over the bin.
+    pub(crate) fn back_image_placed(&self) -> bool {
+        self.back_image_slice.is_some()
+            || self.back_image_fit.unwrap_or_default() != ImageFit::Fill
+            || self.back_image_repeat.unwrap_or_default() != ImageRepeat::NoRepeat
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -808,6 +891,266 @@ impl ImageEffect {
     }
 }
 
+/// How an image is sized within a bin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImageFit {
+    /// Stretch the image to the size of the bin.
+    #[default]
+    Fill,
+    /// Scale the image to fit within the bin keeping its aspect ratio.
+    Contain,
+    /// Scale the image to cover the bin keeping its aspect ratio. Parts outside are clipped.
+    Cover,
+    /// Display the image at its size.
+    None,
+    /// Same as `None` unless the image is larger than the bin, then the same as `Contain`.
+    ScaleDown,
+}
+
+/// Where an image is placed within a bin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImageAlign {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl ImageAlign {
+    /// The fraction of the free space placed before the image horizontally & vertically.
+    pub(crate) fn factors(self) -> [f32; 2] {
+        match self {
+            Self::TopLeft => [0.0, 0.0],
+            Self::Top => [0.5, 0.0],
+            Self::TopRight => [1.0, 0.0],
+            Self::Left => [0.0, 0.5],
+            Self::Center => [0.5, 0.5],
+            Self::Right => [1.0, 0.5],
+            Self::BottomLeft => [0.0, 1.0],
+            Self::Bottom => [0.5, 1.0],
+            Self::BottomRight => [1.0, 1.0],
+        }
+    }
+}
+
+/// How an image is repeated to fill a bin.
+///
+/// Each tile is the size given by `ImageFit`, except with `ImageFit::Fill` where tiles are the
+/// size of the image. Tiles are placed outward from the position given by `ImageAlign`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImageRepeat {
+    #[default]
+    NoRepeat,
+    Repeat,
+    RepeatX,
+    RepeatY,
+}
+
+/// Insets of a nine-slice image in pixels of the image.
+///
+/// The corners are displayed at their size, the edges are stretched along one axis and the
+/// center is stretched to fill the remaining space. If the bin is smaller than the insets, the
+/// corners are scaled down to fit.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct ImageSlice {
+    pub top: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub right: f32,
+}
+
+impl ImageSlice {
+    /// Use the same inset on each side.
+    pub fn uniform(inset: f32) -> Self {
+        Self {
+            top: inset,
+            bottom: inset,
+            left: inset,
+            right: inset,
+        }
+    }
+}
+
+/// A gradient used with `BinStyle::back_gradient`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// Color stops of the gradient. Stops don't need to be sorted, stops that share a position
+    /// create a hard transition in the order they're defined.
+    pub stops: Vec<GradientStop>,
+    /// The color space colors are interpolated in.
+    pub space: GradientSpace,
+}
+
+/// The shape of a `Gradient`.
+///
+/// Centers & radii are relative to the size of the bin, where `[0.5, 0.5]` is the center.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// Colors change along a line at the angle in degrees. An angle of `0.0` goes upward and
+    /// increases clockwise. The line is sized so the corners of the bin have the first & last
+    /// stops.
+    Linear { angle: f32 },
+    /// Colors change outward from the center, reaching the last stop at the radius.
+    Radial { center: [f32; 2], radius: [f32; 2] },
+    /// Colors change around the center, starting at the angle in degrees. An angle of `0.0`
+    /// starts upward and increases clockwise.
+    Conic { center: [f32; 2], angle: f32 },
+}
+
+/// A color stop of a `Gradient`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientStop {
+    /// Position of the stop from `0.0` to `1.0`.
+    pub position: f32,
+    pub color: Color,
+}
+
+/// The color space a `Gradient` is interpolated in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GradientSpace {
+    /// Interpolate gamma encoded colors, this matches css & most design tools.
+    #[default]
+    Srgb,
+    /// Interpolate linear colors, transitions appear brighter but are physically correct.
+    Linear,
+}
+
+impl GradientStop {
+    pub fn new(position: f32, color: Color) -> Self {
+        Self {
+            position,
+            color,
+        }
+    }
+}
+
+impl Gradient {
+    /// Create a linear gradient at the angle in degrees.
+    pub fn linear(angle: f32, stops: Vec<GradientStop>) -> Self {
+        Self {
+            kind: GradientKind::Linear {
+                angle,
+            },
+            stops,
+            space: GradientSpace::default(),
+        }
+    }
+
+    /// Create a radial gradient centered within the bin that reaches the last stop at the
+    /// corners of the bin.
+    pub fn radial(stops: Vec<GradientStop>) -> Self {
+        Self {
+            kind: GradientKind::Radial {
+                center: [0.5, 0.5],
+                radius: [std::f32::consts::FRAC_1_SQRT_2; 2],
+            },
+            stops,
+            space: GradientSpace::default(),
+        }
+    }
+
+    /// Create a conic gradient centered within the bin starting at the angle in degrees.
+    pub fn conic(angle: f32, stops: Vec<GradientStop>) -> Self {
+        Self {
+            kind: GradientKind::Conic {
+                center: [0.5, 0.5],
+                angle,
+            },
+            stops,
+            space: GradientSpace::default(),
+        }
+    }
+
+    /// Set the center of a radial or conic gradient.
+    pub fn center(mut self, x: f32, y: f32) -> Self {
+        match &mut self.kind {
+            GradientKind::Linear {
+                ..
+            } => (),
+            GradientKind::Radial {
+                center, ..
+            }
+            | GradientKind::Conic {
+                center, ..
+            } => *center = [x, y],
+        }
+
+        self
+    }
+
+    /// Set the radius of a radial gradient.
+    pub fn radius(mut self, x: f32, y: f32) -> Self {
+        if let GradientKind::Radial {
+            radius, ..
+        } = &mut self.kind
+        {
+            *radius = [x, y];
+        }
+
+        self
+    }
+
+    /// Set the color space colors are interpolated in.
+    pub fn space(mut self, space: GradientSpace) -> Self {
+        self.space = space;
+        self
+    }
+}
+
+/// An icon from an icon font used with `BinStyle::icon`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Icon {
+    /// Family name of the icon font.
+    pub family: String,
+    pub glyph: IconGlyph,
+    /// Weight of the icon font, some fonts provide different icon sets per weight.
+    pub weight: Option<FontWeight>,
+}
+
+/// How an icon is selected from an icon font.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IconGlyph {
+    /// The codepoint of the icon, e.g. `'\u{e88a}'`.
+    Codepoint(char),
+    /// The name of the icon, e.g. `"home"`.
+    ///
+    /// This relies on the font providing a ligature for the name.
+    Name(String),
+}
+
+impl Icon {
+    /// Select an icon by its codepoint.
+    pub fn codepoint<F: Into<String>>(family: F, codepoint: char) -> Self {
+        Self {
+            family: family.into(),
+            glyph: IconGlyph::Codepoint(codepoint),
+            weight: None,
+        }
+    }
+
+    /// Select an icon by its name.
+    pub fn name<F: Into<String>, N: Into<String>>(family: F, name: N) -> Self {
+        Self {
+            family: family.into(),
+            glyph: IconGlyph::Name(name.into()),
+            weight: None,
+        }
+    }
+
+    /// Use the provided font weight.
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct BinVert {
     pub position: (f32, f32, i16),
@@ -0,0 +1,1211 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, InputHookID, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interface::overlay::DISMISS_WEIGHT;
+use crate::interface::slider::Method;
+use crate::interface::spin_box::{SpinBox, SpinBoxTheme};
+use crate::Basalt;
+
+impl KeepAlive for ColorPicker {}
+
+/// Space between the parts of the picker.
+const PAD: f32 = 8.0;
+/// Height of a row within the field column.
+const ROW_HEIGHT: f32 = 22.0;
+/// Width of the labels in front of the fields.
+const LABEL_WIDTH: f32 = 16.0;
+/// Width of the fields.
+const FIELD_WIDTH: f32 = 76.0;
+/// Amount of cells along each side of the saturation/value square's gradient.
+const SQUARE_CELLS: usize = 12;
+/// Amount of segments of the hue strip's gradient.
+const HUE_SEGMENTS: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorPickerTheme {
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    pub text_color: bin::Color,
+    /// Color of the markers displaying the current position on the square & strips.
+    pub marker_color: bin::Color,
+    /// Color displayed behind the alpha strip & swatches.
+    pub checker_color: bin::Color,
+    pub text_height: f32,
+    /// Size of the saturation/value square.
+    pub square_size: f32,
+    /// Width of the hue & alpha strips.
+    pub strip_width: f32,
+    /// Size of the swatches.
+    pub swatch_size: f32,
+    /// Maximum amount of recent swatches.
+    pub max_swatches: usize,
+    /// Theme of the RGB, HSV & alpha fields.
+    pub field: SpinBoxTheme,
+}
+
+impl Default for ColorPickerTheme {
+    fn default() -> Self {
+        ColorPickerTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            border_color: bin::Color::srgb_hex("505058"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            marker_color: bin::Color::srgb_hex("ffffff"),
+            checker_color: bin::Color::srgb_hex("808080"),
+            text_height: 12.0,
+            square_size: 180.0,
+            strip_width: 16.0,
+            swatch_size: 18.0,
+            max_swatches: 8,
+            field: SpinBoxTheme::default(),
+        }
+    }
+}
+
+/// A color picker with a saturation/value square, hue & alpha strips and text fields.
+///
+/// The color is displayed & edited as sRGB, while `color` returns a linear `bin::Color` the
+/// same as `bin::Color::srgb_hex` would with the value of `hex`.
+pub struct ColorPicker {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub square: Arc<Bin>,
+    pub hue_strip: Arc<Bin>,
+    pub alpha_strip: Arc<Bin>,
+    pub preview: Arc<Bin>,
+    pub bin_color_pick: Arc<Bin>,
+    pub hex_field: Arc<Bin>,
+    theme: ColorPickerTheme,
+    labels: Vec<Arc<Bin>>,
+    fields: Vec<Arc<SpinBox>>,
+    swatches: Vec<Arc<Bin>>,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(bin::Color) + Send + 'static>>>,
+    hooks: Mutex<Vec<InputHookID>>,
+}
+
+struct State {
+    /// Hue from `0.0` to `360.0`, saturation & value from `0.0` to `1.0`.
+    hsv: [f32; 3],
+    alpha: f32,
+    drag: Option<DragTarget>,
+    hex_edit: Option<String>,
+    recent: Vec<[f32; 4]>,
+    /// The press & `Esc` hooks while picking a color from a `Bin`.
+    picking: Option<[InputHookID; 2]>,
+    /// Set while the fields are updated to avoid them updating the color.
+    syncing: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DragTarget {
+    Square,
+    Hue,
+    Alpha,
+}
+
+/// Order of the fields within `ColorPicker::fields`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Red,
+    Green,
+    Blue,
+    Hue,
+    Saturation,
+    Value,
+    Alpha,
+}
+
+const FIELDS: [Field; 7] = [
+    Field::Red,
+    Field::Green,
+    Field::Blue,
+    Field::Hue,
+    Field::Saturation,
+    Field::Value,
+    Field::Alpha,
+];
+
+impl Field {
+    fn label(self) -> &'static str {
+        match self {
+            Field::Red => "R",
+            Field::Green => "G",
+            Field::Blue => "B",
+            Field::Hue => "H",
+            Field::Saturation => "S",
+            Field::Value => "V",
+            Field::Alpha => "A",
+        }
+    }
+}
+
+impl Drop for ColorPicker {
+    fn drop(&mut self) {
+        let mut hooks = self.hooks.lock();
+
+        for id in hooks.split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+
+        if let Some(ids) = self.state.lock().picking.take() {
+            for id in ids {
+                self.basalt.input_ref().remove_hook(id);
+            }
+        }
+    }
+}
+
+impl ColorPicker {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: ColorPickerTheme,
+        parent: Option<Arc<Bin>>,
+    ) -> Arc<Self> {
+        let mut bins = basalt
+            .interface_ref()
+            .new_bins(7 + FIELDS.len() + 1 + theme.max_swatches);
+        let container = bins.pop().unwrap();
+        let square = bins.pop().unwrap();
+        let hue_strip = bins.pop().unwrap();
+        let alpha_strip = bins.pop().unwrap();
+        let preview = bins.pop().unwrap();
+        let bin_color_pick = bins.pop().unwrap();
+        let hex_field = bins.pop().unwrap();
+        let labels: Vec<_> = bins.drain(..(FIELDS.len() + 1)).collect();
+        let swatches = bins;
+
+        container.add_children(vec![
+            square.clone(),
+            hue_strip.clone(),
+            alpha_strip.clone(),
+            preview.clone(),
+            bin_color_pick.clone(),
+            hex_field.clone(),
+        ]);
+
+        container.add_children(labels.clone());
+        container.add_children(swatches.clone());
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        let fields: Vec<_> = FIELDS
+            .iter()
+            .map(|field| {
+                let spin_box =
+                    SpinBox::new(basalt.clone(), theme.field.clone(), Some(container.clone()));
+
+                match field {
+                    Field::Red | Field::Green | Field::Blue => spin_box.set_min_max(0.0, 255.0),
+                    Field::Hue => {
+                        spin_box.set_min_max(0.0, 360.0);
+                        spin_box.set_unit("°");
+                    },
+                    Field::Saturation | Field::Value | Field::Alpha => {
+                        spin_box.set_min_max(0.0, 100.0);
+                        spin_box.set_unit("%");
+                    },
+                }
+
+                spin_box.set_method(Method::RoundToStep);
+                spin_box
+            })
+            .collect();
+
+        let picker = Arc::new(ColorPicker {
+            basalt,
+            container,
+            square,
+            hue_strip,
+            alpha_strip,
+            preview,
+            bin_color_pick,
+            hex_field,
+            theme,
+            labels,
+            fields,
+            swatches,
+            state: Mutex::new(State {
+                hsv: [0.0, 0.0, 1.0],
+                alpha: 1.0,
+                drag: None,
+                hex_edit: None,
+                recent: Vec::new(),
+                picking: None,
+                syncing: false,
+            }),
+            on_change: Mutex::new(Vec::new()),
+            hooks: Mutex::new(Vec::new()),
+        });
+
+        picker.layout();
+
+        for (target, bin) in [
+            (DragTarget::Square, &picker.square),
+            (DragTarget::Hue, &picker.hue_strip),
+            (DragTarget::Alpha, &picker.alpha_strip),
+        ] {
+            let picker_wk = Arc::downgrade(&picker);
+
+            bin.on_press(MouseButton::Left, move |_, window, _| {
+                let picker = match picker_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                picker.state.lock().drag = Some(target);
+                picker.drag_to(window.cursor_pos());
+                Default::default()
+            });
+        }
+
+        let window = picker.basalt.window();
+        let picker_wk = Arc::downgrade(&picker);
+
+        let cursor_hook = picker
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_cursor()
+            .call(move |_, window, _| {
+                let picker = match picker_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                picker.drag_to(window.cursor_pos());
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        let release_hook = picker
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_release()
+            .keys(MouseButton::Left)
+            .call(move |_, _, _| {
+                let picker = match picker_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                if picker.state.lock().drag.take().is_some() {
+                    picker.push_recent();
+                }
+
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        picker.hooks.lock().extend([cursor_hook, release_hook]);
+
+        for (i, field) in FIELDS.iter().enumerate() {
+            let picker_wk = Arc::downgrade(&picker);
+            let field = *field;
+
+            picker.fields[i].on_change(move |value| {
+                if let Some(picker) = picker_wk.upgrade() {
+                    picker.field_changed(field, value);
+                }
+            });
+        }
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        picker.hex_field.on_character(move |_, _, c| {
+            let picker = match picker_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if c.is_new_line() {
+                picker.commit_hex();
+                return Default::default();
+            }
+
+            if c.0.is_control() && !c.is_backspace() {
+                return Default::default();
+            }
+
+            {
+                let mut state = picker.state.lock();
+
+                let mut text = match state.hex_edit.take() {
+                    Some(some) => some,
+                    None => hex_string(&state.hsv, state.alpha),
+                };
+
+                c.modify_string(&mut text);
+                state.hex_edit = Some(text);
+            }
+
+            picker.update();
+            Default::default()
+        });
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        picker.hex_field.on_focus_lost(move |_, _| {
+            let picker = match picker_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if !picker.commit_hex() {
+                picker.state.lock().hex_edit = None;
+                picker.update();
+            }
+
+            Default::default()
+        });
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        picker
+            .bin_color_pick
+            .on_press(MouseButton::Left, move |_, _, _| {
+                match picker_wk.upgrade() {
+                    Some(picker) => {
+                        match picker.is_picking() {
+                            true => picker.stop_bin_color_pick(),
+                            false => picker.start_bin_color_pick(),
+                        }
+
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+
+        for (i, swatch) in picker.swatches.iter().enumerate() {
+            let picker_wk = Arc::downgrade(&picker);
+
+            swatch.on_press(MouseButton::Left, move |_, _, _| {
+                let picker = match picker_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let recent = picker.state.lock().recent.get(i).copied();
+
+                if let Some([r, g, b, a]) = recent {
+                    picker.set_srgba([r, g, b], a);
+                }
+
+                Default::default()
+            });
+        }
+
+        picker.update();
+        picker
+    }
+
+    /// The current color.
+    pub fn color(&self) -> bin::Color {
+        let state = self.state.lock();
+        let [r, g, b] = hsv_to_rgb(state.hsv);
+        srgb_color([r, g, b], state.alpha)
+    }
+
+    /// Set the current color.
+    pub fn set_color(&self, color: bin::Color) {
+        let mut color = color;
+        color.to_nonlinear();
+        color.clamp();
+        self.set_srgba([color.r, color.g, color.b], color.a);
+    }
+
+    /// The current color as sRGB hex code including alpha, e.g. `ff8000ff`.
+    pub fn hex(&self) -> String {
+        let state = self.state.lock();
+        hex_string(&state.hsv, state.alpha)
+    }
+
+    /// Set the current color from a sRGB hex code with an optional alpha and `#`.
+    ///
+    /// Returns `false` if the code isn't valid.
+    pub fn set_hex(&self, code: &str) -> bool {
+        match parse_hex(code) {
+            Some([r, g, b, a]) => {
+                self.set_srgba([r, g, b], a);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// The recently picked colors, most recent first.
+    pub fn recent(&self) -> Vec<bin::Color> {
+        self.state
+            .lock()
+            .recent
+            .iter()
+            .map(|[r, g, b, a]| srgb_color([*r, *g, *b], *a))
+            .collect()
+    }
+
+    /// Add the current color to the recent swatches.
+    ///
+    /// # Notes
+    /// - This is done automatically once a color is picked with the square, strips, fields or
+    /// from a `Bin`.
+    pub fn push_recent(&self) {
+        {
+            let mut state = self.state.lock();
+            let [r, g, b] = hsv_to_rgb(state.hsv);
+            let color = [r, g, b, state.alpha];
+            let key = hex_rgba(color);
+            state.recent.retain(|recent| hex_rgba(*recent) != key);
+            state.recent.insert(0, color);
+            state.recent.truncate(self.theme.max_swatches);
+        }
+
+        self.update();
+    }
+
+    /// Start picking a color from a `Bin`.
+    ///
+    /// The next left click within the window picks the `back_color` of the top-most `Bin` under
+    /// the cursor, or of its closest ancestor with one, instead of being passed on. `Esc` cancels
+    /// picking.
+    ///
+    /// # Notes
+    /// - This does not sample the rendered frame. Images, text, gradients & `custom_verts` are
+    /// not taken into account.
+    pub fn start_bin_color_pick(self: &Arc<Self>) {
+        if self.is_picking() {
+            return;
+        }
+
+        let picker_wk = Arc::downgrade(self);
+
+        let hook = self
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&self.basalt.window())
+            .on_press()
+            .keys(MouseButton::Left)
+            .weight(DISMISS_WEIGHT)
+            .call(move |_, window, _| {
+                let picker = match picker_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let [x, y] = window.cursor_pos();
+
+                let atop = picker
+                    .basalt
+                    .interface_ref()
+                    .get_bin_atop(window.window_id(), x, y);
+
+                picker.stop_bin_color_pick();
+
+                // Clicking the pick button again cancels picking.
+                if let Some(atop) = atop.filter(|atop| atop.id() != picker.bin_color_pick.id()) {
+                    let color = std::iter::once(atop.clone())
+                        .chain(atop.ancestors())
+                        .find_map(|bin| bin.style_copy().back_color.filter(|color| color.a > 0.0));
+
+                    if let Some(color) = color {
+                        picker.set_color(color);
+                        picker.push_recent();
+                    }
+                }
+
+                InputHookCtrl::RetainNoPass
+            })
+            .finish()
+            .unwrap();
+
+        let picker_wk = Arc::downgrade(self);
+
+        let esc_hook = self
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&self.basalt.window())
+            .on_press()
+            .keys(Qwerty::Esc)
+            .weight(DISMISS_WEIGHT)
+            .call(move |_, _, _| {
+                match picker_wk.upgrade() {
+                    Some(picker) => {
+                        picker.stop_bin_color_pick();
+                        InputHookCtrl::RetainNoPass
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            })
+            .finish()
+            .unwrap();
+
+        self.state.lock().picking = Some([hook, esc_hook]);
+        self.update();
+    }
+
+    /// Stop picking a color from a `Bin`.
+    pub fn stop_bin_color_pick(&self) {
+        let ids = match self.state.lock().picking.take() {
+            Some(some) => some,
+            None => return,
+        };
+
+        for id in ids {
+            self.basalt.input_ref().remove_hook(id);
+        }
+
+        self.update();
+    }
+
+    /// Returns `true` if a color is being picked from a `Bin`.
+    pub fn is_picking(&self) -> bool {
+        self.state.lock().picking.is_some()
+    }
+
+    /// Add a function to be called when the color changes.
+    pub fn on_change<F: FnMut(bin::Color) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    fn set_srgba(&self, rgb: [f32; 3], alpha: f32) {
+        let hsv = {
+            let state = self.state.lock();
+            rgb_to_hsv(rgb, state.hsv[0])
+        };
+
+        self.set_hsva(hsv, alpha);
+    }
+
+    fn set_hsva(&self, hsv: [f32; 3], alpha: f32) {
+        {
+            let mut state = self.state.lock();
+            state.hsv = hsv;
+            state.alpha = alpha.clamp(0.0, 1.0);
+            state.hex_edit = None;
+        }
+
+        self.update();
+        let color = self.color();
+
+        for func in self.on_change.lock().iter_mut() {
+            func(color.clone());
+        }
+    }
+
+    fn field_changed(&self, field: Field, value: f32) {
+        let (mut hsv, mut alpha) = {
+            let state = self.state.lock();
+
+            if state.syncing {
+                return;
+            }
+
+            (state.hsv, state.alpha)
+        };
+
+        match field {
+            Field::Red | Field::Green | Field::Blue => {
+                let mut rgb = hsv_to_rgb(hsv);
+
+                let channel = match field {
+                    Field::Red => 0,
+                    Field::Green => 1,
+                    _ => 2,
+                };
+
+                rgb[channel] = value / 255.0;
+                hsv = rgb_to_hsv(rgb, hsv[0]);
+            },
+            Field::Hue => hsv[0] = value,
+            Field::Saturation => hsv[1] = value / 100.0,
+            Field::Value => hsv[2] = value / 100.0,
+            Field::Alpha => alpha = value / 100.0,
+        }
+
+        self.set_hsva(hsv, alpha);
+        self.push_recent();
+    }
+
+    fn commit_hex(&self) -> bool {
+        let parsed = match self.state.lock().hex_edit.as_ref() {
+            Some(text) => parse_hex(text),
+            None => return true,
+        };
+
+        match parsed {
+            Some([r, g, b, a]) => {
+                self.set_srgba([r, g, b], a);
+                self.push_recent();
+                true
+            },
+            None => {
+                self.update();
+                false
+            },
+        }
+    }
+
+    fn drag_to(&self, cursor: [f32; 2]) {
+        let (target, mut hsv, mut alpha) = {
+            let state = self.state.lock();
+
+            match state.drag {
+                Some(target) => (target, state.hsv, state.alpha),
+                None => return,
+            }
+        };
+
+        let scale = self.basalt.interface_ref().current_effective_scale();
+        let cursor = [cursor[0] / scale, cursor[1] / scale];
+
+        let bin = match target {
+            DragTarget::Square => &self.square,
+            DragTarget::Hue => &self.hue_strip,
+            DragTarget::Alpha => &self.alpha_strip,
+        };
+
+        let post = bin.post_update();
+        let w = (post.tri[0] - post.tli[0]).max(1.0);
+        let h = (post.bli[1] - post.tli[1]).max(1.0);
+        let x = ((cursor[0] - post.tli[0]) / w).clamp(0.0, 1.0);
+        let y = ((cursor[1] - post.tli[1]) / h).clamp(0.0, 1.0);
+
+        match target {
+            DragTarget::Square => {
+                hsv[1] = x;
+                hsv[2] = 1.0 - y;
+            },
+            DragTarget::Hue => hsv[0] = y * 360.0,
+            DragTarget::Alpha => alpha = 1.0 - y,
+        }
+
+        self.set_hsva(hsv, alpha);
+    }
+
+    fn layout(&self) {
+        let size = self.theme.square_size;
+        let strip_w = self.theme.strip_width;
+        let hue_x = PAD + size + PAD;
+        let alpha_x = hue_x + strip_w + PAD;
+        let fields_x = alpha_x + strip_w + PAD;
+        let rows = FIELDS.len() + 1;
+        let fields_h = (rows as f32 * (ROW_HEIGHT + 4.0)) - 4.0;
+        let preview_y = PAD + size + PAD;
+        let swatch_y = preview_y + ROW_HEIGHT + PAD;
+        let width = fields_x + LABEL_WIDTH + FIELD_WIDTH + PAD;
+        let height = (swatch_y + self.theme.swatch_size).max(PAD + fields_h) + PAD;
+        let border_color = self.theme.border_color.clone();
+
+        self.container
+            .style_update(BinStyle {
+                position: Some(match self.container.parent().is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(width),
+                height: Some(height),
+                back_color: Some(self.theme.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(border_color.clone()),
+                border_color_b: Some(border_color.clone()),
+                border_color_l: Some(border_color.clone()),
+                border_color_r: Some(border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        for (bin, left, width, back_color) in [
+            (&self.square, PAD, size, None),
+            (&self.hue_strip, hue_x, strip_w, None),
+            (
+                &self.alpha_strip,
+                alpha_x,
+                strip_w,
+                Some(self.theme.checker_color.clone()),
+            ),
+        ] {
+            bin.style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(PAD),
+                pos_from_l: Some(left),
+                width: Some(width),
+                height: Some(size),
+                back_color,
+                ..BinStyle::default()
+            })
+            .expect_valid();
+        }
+
+        for (i, label) in self.labels.iter().enumerate() {
+            let text = match FIELDS.get(i) {
+                Some(field) => field.label(),
+                None => "#",
+            };
+
+            label
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(PAD + (i as f32 * (ROW_HEIGHT + 4.0))),
+                    pos_from_l: Some(fields_x),
+                    width: Some(LABEL_WIDTH),
+                    height: Some(ROW_HEIGHT),
+                    text: String::from(text),
+                    text_color: Some(self.theme.text_color.clone()),
+                    text_height: Some(self.theme.text_height),
+                    text_vert_align: Some(TextVertAlign::Center),
+                    text_hori_align: Some(TextHoriAlign::Left),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+        }
+
+        for (i, field) in self.fields.iter().enumerate() {
+            field
+                .container
+                .style_update(BinStyle {
+                    pos_from_t: Some(PAD + (i as f32 * (ROW_HEIGHT + 4.0))),
+                    pos_from_l: Some(fields_x + LABEL_WIDTH),
+                    width: Some(FIELD_WIDTH),
+                    height: Some(ROW_HEIGHT),
+                    ..field.container.style_copy()
+                })
+                .expect_valid();
+        }
+
+        for (i, swatch) in self.swatches.iter().enumerate() {
+            swatch
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(swatch_y),
+                    pos_from_l: Some(PAD + (i as f32 * (self.theme.swatch_size + 4.0))),
+                    width: Some(self.theme.swatch_size),
+                    height: Some(self.theme.swatch_size),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+        }
+    }
+
+    fn update(&self) {
+        let (hsv, alpha, hex_edit, recent, picking) = {
+            let state = self.state.lock();
+
+            (
+                state.hsv,
+                state.alpha,
+                state.hex_edit.clone(),
+                state.recent.clone(),
+                state.picking.is_some(),
+            )
+        };
+
+        let size = self.theme.square_size;
+        let strip_w = self.theme.strip_width;
+        let rgb = hsv_to_rgb(hsv);
+        let marker = &self.theme.marker_color;
+
+        // Saturation/value square
+        let mut verts = Vec::with_capacity(SQUARE_CELLS * SQUARE_CELLS * 6 + 24);
+        let cell = size / SQUARE_CELLS as f32;
+        let at = |i: usize, j: usize| {
+            let s = i as f32 / SQUARE_CELLS as f32;
+            let v = 1.0 - (j as f32 / SQUARE_CELLS as f32);
+            srgb_color(hsv_to_rgb([hsv[0], s, v]), 1.0)
+        };
+
+        for j in 0..SQUARE_CELLS {
+            for i in 0..SQUARE_CELLS {
+                push_quad(
+                    &mut verts,
+                    [
+                        i as f32 * cell,
+                        j as f32 * cell,
+                        (i + 1) as f32 * cell,
+                        (j + 1) as f32 * cell,
+                    ],
+                    [at(i, j), at(i + 1, j), at(i, j + 1), at(i + 1, j + 1)],
+                    0,
+                );
+            }
+        }
+
+        let (mx, my) = (hsv[1] * size, (1.0 - hsv[2]) * size);
+        push_outline(&mut verts, [mx - 4.0, my - 4.0, mx + 4.0, my + 4.0], marker);
+
+        self.square
+            .style_update(BinStyle {
+                custom_verts: verts,
+                ..self.square.style_copy()
+            })
+            .expect_valid();
+
+        // Hue strip
+        let mut verts = Vec::with_capacity(HUE_SEGMENTS * 6 + 6);
+        let segment = size / HUE_SEGMENTS as f32;
+
+        for i in 0..HUE_SEGMENTS {
+            let top = srgb_color(
+                hsv_to_rgb([i as f32 * 360.0 / HUE_SEGMENTS as f32, 1.0, 1.0]),
+                1.0,
+            );
+            let bottom = srgb_color(
+                hsv_to_rgb([(i + 1) as f32 * 360.0 / HUE_SEGMENTS as f32, 1.0, 1.0]),
+                1.0,
+            );
+
+            push_quad(
+                &mut verts,
+                [0.0, i as f32 * segment, strip_w, (i + 1) as f32 * segment],
+                [top.clone(), top, bottom.clone(), bottom],
+                0,
+            );
+        }
+
+        let hy = hsv[0] / 360.0 * size;
+        push_quad(
+            &mut verts,
+            [-2.0, hy - 1.0, strip_w + 2.0, hy + 1.0],
+            [
+                marker.clone(),
+                marker.clone(),
+                marker.clone(),
+                marker.clone(),
+            ],
+            2,
+        );
+
+        self.hue_strip
+            .style_update(BinStyle {
+                custom_verts: verts,
+                ..self.hue_strip.style_copy()
+            })
+            .expect_valid();
+
+        // Alpha strip
+        let mut verts = Vec::with_capacity(12);
+        let opaque = srgb_color(rgb, 1.0);
+        let clear = srgb_color(rgb, 0.0);
+
+        push_quad(
+            &mut verts,
+            [0.0, 0.0, strip_w, size],
+            [opaque.clone(), opaque, clear.clone(), clear],
+            0,
+        );
+
+        let ay = (1.0 - alpha) * size;
+        push_quad(
+            &mut verts,
+            [-2.0, ay - 1.0, strip_w + 2.0, ay + 1.0],
+            [
+                marker.clone(),
+                marker.clone(),
+                marker.clone(),
+                marker.clone(),
+            ],
+            2,
+        );
+
+        self.alpha_strip
+            .style_update(BinStyle {
+                custom_verts: verts,
+                ..self.alpha_strip.style_copy()
+            })
+            .expect_valid();
+
+        // Preview, bin color pick & hex field
+        let preview_y = PAD + size + PAD;
+        let fields_x = PAD + size + PAD + strip_w + PAD + strip_w + PAD;
+        let border_color = self.theme.border_color.clone();
+
+        self.preview
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(preview_y),
+                pos_from_l: Some(PAD),
+                width: Some(size - ROW_HEIGHT - 4.0),
+                height: Some(ROW_HEIGHT),
+                back_color: Some(srgb_color(rgb, alpha)),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(border_color.clone()),
+                border_color_b: Some(border_color.clone()),
+                border_color_l: Some(border_color.clone()),
+                border_color_r: Some(border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let pick_color = match picking {
+            true => marker.clone(),
+            false => self.theme.field.button_color.clone(),
+        };
+
+        self.bin_color_pick
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(preview_y),
+                pos_from_l: Some(PAD + size - ROW_HEIGHT),
+                width: Some(ROW_HEIGHT),
+                height: Some(ROW_HEIGHT),
+                back_color: Some(pick_color),
+                custom_verts: pipette_verts(&self.theme.text_color),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let (hex_text, hex_valid) = match hex_edit {
+            Some(text) => {
+                let valid = parse_hex(&text).is_some();
+                (text, valid)
+            },
+            None => (hex_string(&hsv, alpha), true),
+        };
+
+        let (hex_border, hex_text_color) = match hex_valid {
+            true => (border_color, self.theme.text_color.clone()),
+            false => {
+                (
+                    self.theme.field.error_color.clone(),
+                    self.theme.field.error_color.clone(),
+                )
+            },
+        };
+
+        self.hex_field
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(PAD + (FIELDS.len() as f32 * (ROW_HEIGHT + 4.0))),
+                pos_from_l: Some(fields_x + LABEL_WIDTH),
+                width: Some(FIELD_WIDTH),
+                height: Some(ROW_HEIGHT),
+                pad_l: Some(4.0),
+                back_color: Some(self.theme.field.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(hex_border.clone()),
+                border_color_b: Some(hex_border.clone()),
+                border_color_l: Some(hex_border.clone()),
+                border_color_r: Some(hex_border),
+                text: hex_text,
+                text_color: Some(hex_text_color),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Left),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        // Swatches
+        for (i, swatch) in self.swatches.iter().enumerate() {
+            let (hidden, custom_verts) = match recent.get(i) {
+                Some([r, g, b, a]) => {
+                    let color = srgb_color([*r, *g, *b], *a);
+                    let mut verts = Vec::with_capacity(6);
+                    let s = self.theme.swatch_size;
+                    push_quad(
+                        &mut verts,
+                        [0.0, 0.0, s, s],
+                        [color.clone(), color.clone(), color.clone(), color],
+                        0,
+                    );
+                    (None, verts)
+                },
+                None => (Some(true), Vec::new()),
+            };
+
+            swatch
+                .style_update(BinStyle {
+                    hidden,
+                    back_color: Some(self.theme.checker_color.clone()),
+                    custom_verts,
+                    ..swatch.style_copy()
+                })
+                .expect_valid();
+        }
+
+        // Fields
+        self.state.lock().syncing = true;
+
+        for (field, spin_box) in FIELDS.iter().zip(self.fields.iter()) {
+            spin_box.set(match field {
+                Field::Red => rgb[0] * 255.0,
+                Field::Green => rgb[1] * 255.0,
+                Field::Blue => rgb[2] * 255.0,
+                Field::Hue => hsv[0],
+                Field::Saturation => hsv[1] * 100.0,
+                Field::Value => hsv[2] * 100.0,
+                Field::Alpha => alpha * 100.0,
+            });
+        }
+
+        self.state.lock().syncing = false;
+        self.container.update_children();
+    }
+}
+
+/// Convert hue (`0.0` to `360.0`), saturation & value into RGB.
+fn hsv_to_rgb([h, s, v]: [f32; 3]) -> [f32; 3] {
+    let h = (h.rem_euclid(360.0)) / 60.0;
+    let c = v * s;
+    let x = c * (1.0 - ((h % 2.0) - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    [r + m, g + m, b + m]
+}
+
+/// Convert RGB into hue, saturation & value. The hue is kept when it is undefined.
+fn rgb_to_hsv([r, g, b]: [f32; 3], hue: f32) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let h = if delta <= f32::EPSILON {
+        hue
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * (((b - r) / delta) + 2.0)
+    } else {
+        60.0 * (((r - g) / delta) + 4.0)
+    };
+
+    let s = match max <= f32::EPSILON {
+        true => 0.0,
+        false => delta / max,
+    };
+
+    [h, s, max]
+}
+
+fn srgb_color([r, g, b]: [f32; 3], a: f32) -> bin::Color {
+    let mut color = bin::Color {
+        r,
+        g,
+        b,
+        a,
+    };
+
+    color.to_linear();
+    color
+}
+
+fn hex_rgba(rgba: [f32; 4]) -> String {
+    rgba.iter()
+        .map(|c| format!("{:02x}", (c.clamp(0.0, 1.0) * 255.0).round() as u8))
+        .collect()
+}
+
+fn hex_string(hsv: &[f32; 3], alpha: f32) -> String {
+    let [r, g, b] = hsv_to_rgb(*hsv);
+    hex_rgba([r, g, b, alpha])
+}
+
+fn parse_hex(code: &str) -> Option<[f32; 4]> {
+    let code = code.trim();
+    let code = code.strip_prefix('#').unwrap_or(code);
+
+    if (code.len() != 6 && code.len() != 8) || !code.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |i: usize| -> f32 {
+        match code.get(i..(i + 2)) {
+            Some(hex) => u8::from_str_radix(hex, 16).unwrap() as f32 / 255.0,
+            None => 1.0,
+        }
+    };
+
+    Some([channel(0), channel(2), channel(4), channel(6)])
+}
+
+/// Push a quad with the colors of its top left, top right, bottom left & bottom right corners.
+fn push_quad(verts: &mut Vec<BinVert>, [l, t, r, b]: [f32; 4], colors: [bin::Color; 4], z: i16) {
+    let [tl, tr, bl, br] = colors;
+
+    for (x, y, color) in [
+        (l, t, tl.clone()),
+        (r, t, tr.clone()),
+        (l, b, bl.clone()),
+        (l, b, bl),
+        (r, t, tr),
+        (r, b, br),
+    ] {
+        verts.push(BinVert {
+            position: (x, y, z),
+            color,
+        });
+    }
+}
+
+fn push_outline(verts: &mut Vec<BinVert>, [l, t, r, b]: [f32; 4], color: &bin::Color) {
+    let colors = || [color.clone(), color.clone(), color.clone(), color.clone()];
+    push_quad(verts, [l, t, r, t + 1.0], colors(), 2);
+    push_quad(verts, [l, b - 1.0, r, b], colors(), 2);
+    push_quad(verts, [l, t, l + 1.0, b], colors(), 2);
+    push_quad(verts, [r - 1.0, t, r, b], colors(), 2);
+}
+
+/// A pipette drawn diagonally within the bin color pick button.
+fn pipette_verts(color: &bin::Color) -> Vec<BinVert> {
+    let (a, b) = ((6.0, ROW_HEIGHT - 6.0), (ROW_HEIGHT - 7.0, 7.0));
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = (dx * dx + dy * dy).sqrt();
+    let mut verts = Vec::with_capacity(12);
+
+    for (start, end, width) in [(0.0, 0.7, 2.0), (0.7, 1.0, 5.0)] {
+        let (nx, ny) = (-dy / len * width / 2.0, dx / len * width / 2.0);
+        let p = (a.0 + dx * start, a.1 + dy * start);
+        let q = (a.0 + dx * end, a.1 + dy * end);
+
+        for (x, y) in [
+            (p.0 + nx, p.1 + ny),
+            (p.0 - nx, p.1 - ny),
+            (q.0 + nx, q.1 + ny),
+            (q.0 + nx, q.1 + ny),
+            (p.0 - nx, p.1 - ny),
+            (q.0 - nx, q.1 - ny),
+        ] {
+            verts.push(BinVert {
+                position: (x, y, 0),
+                color: color.clone(),
+            });
+        }
+    }
+
+    verts
+}
@@ -0,0 +1,995 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Weak};
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, InputHookID, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinID, BinPosition, BinStyle, BinVert, KeepAlive, TextVertAlign, TextWrap,
+};
+use crate::interface::scroll_bar::ScrollBarStyle;
+use crate::interface::virtual_list::{VirtualList, VirtualListSource, VirtualListTheme};
+use crate::Basalt;
+
+impl KeepAlive for DataTable {}
+
+/// Provides the rows of a `DataTable`.
+pub trait DataTableSource: Send + Sync {
+    /// The total amount of rows.
+    fn row_count(&self) -> usize;
+
+    /// The content of a cell.
+    ///
+    /// # Notes
+    /// - `column` is the index of the column as provided to `DataTable::new`, regardless of the
+    /// order it is displayed in.
+    fn cell(&self, row: usize, column: usize) -> DataTableCell;
+
+    /// Sort the rows by the column.
+    ///
+    /// Returns `false` if the column can't be sorted.
+    ///
+    /// # Notes
+    /// - The source is responsible for reordering its rows.
+    /// - The table will be reloaded after the rows have been sorted.
+    fn sort(&self, _column: usize, _order: SortOrder) -> bool {
+        false
+    }
+}
+
+/// Content of a cell.
+pub enum DataTableCell {
+    /// Text displayed with the table's theme.
+    Text(String),
+    /// A custom `Bin` added as a child of the cell.
+    ///
+    /// # Notes
+    /// - The `Bin` should use `BinPosition::Parent`.
+    /// - The `Bin` is kept alive until the cell is bound to another row.
+    Bin(Arc<Bin>),
+}
+
+impl From<String> for DataTableCell {
+    fn from(text: String) -> Self {
+        DataTableCell::Text(text)
+    }
+}
+
+impl From<&str> for DataTableCell {
+    fn from(text: &str) -> Self {
+        DataTableCell::Text(text.to_string())
+    }
+}
+
+impl From<Arc<Bin>> for DataTableCell {
+    fn from(bin: Arc<Bin>) -> Self {
+        DataTableCell::Bin(bin)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Rows can't be selected.
+    None,
+    /// Only one row can be selected at a time.
+    #[default]
+    Single,
+    /// Multiple rows can be selected by holding ctrl or shift.
+    Multi,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTableColumn {
+    pub title: String,
+    pub width: f32,
+    pub min_width: f32,
+    /// Clicking the header will sort the rows by this column.
+    pub sortable: bool,
+    /// Dragging the right edge of the header will resize this column.
+    pub resizable: bool,
+}
+
+impl DataTableColumn {
+    pub fn new<T: Into<String>>(title: T) -> Self {
+        DataTableColumn {
+            title: title.into(),
+            width: 100.0,
+            min_width: 20.0,
+            sortable: true,
+            resizable: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTableTheme {
+    pub back_color: bin::Color,
+    pub header_color: bin::Color,
+    pub header_text_color: bin::Color,
+    pub border_color: bin::Color,
+    /// Color of the sort indicator.
+    pub indicator_color: bin::Color,
+    pub row_color: bin::Color,
+    /// Color of every other row.
+    pub row_alt_color: bin::Color,
+    pub selected_color: bin::Color,
+    pub text_color: bin::Color,
+    pub text_height: f32,
+    pub header_height: f32,
+    pub row_height: f32,
+    pub scroll_bar: ScrollBarStyle,
+}
+
+impl Default for DataTableTheme {
+    fn default() -> Self {
+        DataTableTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            header_color: bin::Color::srgb_hex("35353c"),
+            header_text_color: bin::Color::srgb_hex("f0f0f0"),
+            border_color: bin::Color::srgb_hex("222227"),
+            indicator_color: bin::Color::srgb_hex("f0f0f0"),
+            row_color: bin::Color::srgb_hex("2a2a30"),
+            row_alt_color: bin::Color::srgb_hex("303036"),
+            selected_color: bin::Color::srgb_hex("3a5a8c"),
+            text_color: bin::Color::srgb_hex("e0e0e0"),
+            text_height: 12.0,
+            header_height: 24.0,
+            row_height: 22.0,
+            scroll_bar: ScrollBarStyle::default(),
+        }
+    }
+}
+
+/// A table with a header of resizable, reorderable & sortable columns.
+///
+/// Rows are displayed with a `VirtualList`, so only visible rows have `Bin`'s.
+pub struct DataTable {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub header: Arc<Bin>,
+    pub list: Arc<VirtualList>,
+    theme: DataTableTheme,
+    source: Mutex<Arc<dyn DataTableSource>>,
+    state: Mutex<State>,
+    on_selection_change: Mutex<Vec<Box<dyn FnMut(&[usize]) + Send + 'static>>>,
+    hooks: Mutex<Vec<InputHookID>>,
+}
+
+struct State {
+    columns: Vec<DataTableColumn>,
+    order: Vec<usize>,
+    header_cells: Vec<(Arc<Bin>, Arc<Bin>)>,
+    sort: Option<(usize, SortOrder)>,
+    mode: SelectionMode,
+    selected: BTreeSet<usize>,
+    anchor: Option<usize>,
+    rows: HashMap<BinID, BoundRow>,
+    drag: Option<Drag>,
+}
+
+struct BoundRow {
+    index: usize,
+    row: Arc<Bin>,
+    cells: Vec<Arc<Bin>>,
+    content: Vec<Option<Arc<Bin>>>,
+}
+
+enum Drag {
+    Resize {
+        column: usize,
+        start_x: f32,
+        start_width: f32,
+    },
+    Move {
+        column: usize,
+        start_x: f32,
+        moved: bool,
+    },
+}
+
+impl State {
+    fn column_x(&self, column: usize) -> f32 {
+        let mut x = 0.0;
+
+        for i in self.order.iter() {
+            if *i == column {
+                break;
+            }
+
+            x += self.columns[*i].width;
+        }
+
+        x
+    }
+}
+
+struct Rows {
+    table: Weak<DataTable>,
+}
+
+impl VirtualListSource for Rows {
+    fn row_count(&self) -> usize {
+        match self.table.upgrade() {
+            Some(table) => table.source.lock().row_count(),
+            None => 0,
+        }
+    }
+
+    fn row_height(&self) -> Option<f32> {
+        self.table.upgrade().map(|table| table.theme.row_height)
+    }
+
+    fn bind_row(&self, index: usize, row: &Arc<Bin>) {
+        if let Some(table) = self.table.upgrade() {
+            table.bind_row(index, row);
+        }
+    }
+}
+
+impl Drop for DataTable {
+    fn drop(&mut self) {
+        let mut hooks = self.hooks.lock();
+
+        for id in hooks.split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+    }
+}
+
+impl DataTable {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: DataTableTheme,
+        parent: Option<Arc<Bin>>,
+        columns: Vec<DataTableColumn>,
+        source: Arc<dyn DataTableSource>,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(2 + (columns.len() * 2));
+        let container = bins.pop().unwrap();
+        let header = bins.pop().unwrap();
+        container.add_child(header.clone());
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                back_color: Some(theme.back_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        header
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(theme.header_height),
+                back_color: Some(theme.header_color.clone()),
+                border_size_b: Some(1.0),
+                border_color_b: Some(theme.border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let header_cells: Vec<_> = (0..columns.len())
+            .map(|_| {
+                let cell = bins.pop().unwrap();
+                let handle = bins.pop().unwrap();
+                header.add_child(cell.clone());
+                cell.add_child(handle.clone());
+                (cell, handle)
+            })
+            .collect();
+
+        let table = Arc::new_cyclic(|table_wk: &Weak<DataTable>| {
+            let list = VirtualList::new(
+                basalt.clone(),
+                VirtualListTheme {
+                    back_color: theme.back_color.clone(),
+                    scroll_bar: theme.scroll_bar.clone(),
+                    ..VirtualListTheme::default()
+                },
+                Some(container.clone()),
+                Arc::new(Rows {
+                    table: table_wk.clone(),
+                }),
+            );
+
+            list.container
+                .style_update(BinStyle {
+                    pos_from_t: Some(theme.header_height),
+                    ..list.container.style_copy()
+                })
+                .expect_valid();
+
+            DataTable {
+                basalt,
+                container,
+                header,
+                list,
+                theme,
+                source: Mutex::new(source),
+                state: Mutex::new(State {
+                    order: (0..columns.len()).collect(),
+                    columns,
+                    header_cells,
+                    sort: None,
+                    mode: SelectionMode::default(),
+                    selected: BTreeSet::new(),
+                    anchor: None,
+                    rows: HashMap::new(),
+                    drag: None,
+                }),
+                on_selection_change: Mutex::new(Vec::new()),
+                hooks: Mutex::new(Vec::new()),
+            }
+        });
+
+        let header_cells = table.state.lock().header_cells.clone();
+
+        for (column, (cell, handle)) in header_cells.into_iter().enumerate() {
+            let table_wk = Arc::downgrade(&table);
+
+            cell.on_press(MouseButton::Left, move |_, window, _| {
+                let table = match table_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                table.state.lock().drag = Some(Drag::Move {
+                    column,
+                    start_x: window.cursor_pos()[0],
+                    moved: false,
+                });
+
+                Default::default()
+            });
+
+            let table_wk = Arc::downgrade(&table);
+
+            handle.on_press(MouseButton::Left, move |_, window, _| {
+                let table = match table_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let mut state = table.state.lock();
+
+                if state.columns[column].resizable {
+                    state.drag = Some(Drag::Resize {
+                        column,
+                        start_x: window.cursor_pos()[0],
+                        start_width: state.columns[column].width,
+                    });
+                }
+
+                Default::default()
+            });
+        }
+
+        let window = table.basalt.window();
+        let table_wk = Arc::downgrade(&table);
+
+        let cursor_hook = table
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_cursor()
+            .call(move |_, window, _| {
+                let table = match table_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let x = window.cursor_pos()[0];
+                let mut state = table.state.lock();
+
+                let resize = match state.drag.as_mut() {
+                    Some(Drag::Resize {
+                        column,
+                        start_x,
+                        start_width,
+                    }) => Some((*column, *start_width + (x - *start_x))),
+                    Some(Drag::Move {
+                        start_x,
+                        moved,
+                        ..
+                    }) => {
+                        if (x - *start_x).abs() > 4.0 {
+                            *moved = true;
+                        }
+
+                        None
+                    },
+                    None => None,
+                };
+
+                if let Some((column, width)) = resize {
+                    state.columns[column].width = width.max(state.columns[column].min_width);
+                    drop(state);
+                    table.layout();
+                }
+
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        let table_wk = Arc::downgrade(&table);
+
+        let release_hook = table
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_release()
+            .keys(MouseButton::Left)
+            .call(move |_, window, _| {
+                let table = match table_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let drag = table.state.lock().drag.take();
+
+                if let Some(Drag::Move {
+                    column,
+                    moved,
+                    ..
+                }) = drag
+                {
+                    if moved {
+                        table.move_column_to_cursor(column, window.cursor_pos()[0]);
+                    } else {
+                        table.toggle_sort(column);
+                    }
+                }
+
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        let table_wk = Arc::downgrade(&table);
+
+        let select_hook = table
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_press()
+            .keys(MouseButton::Left)
+            .call(move |_, window, _| {
+                let table = match table_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let [x, y] = window.cursor_pos();
+
+                if !table.list.view.mouse_inside(x, y) {
+                    return Default::default();
+                }
+
+                let atop = match table
+                    .basalt
+                    .interface_ref()
+                    .get_bin_atop(window.window_id(), x, y)
+                {
+                    Some(some) => some,
+                    None => return Default::default(),
+                };
+
+                let index = {
+                    let state = table.state.lock();
+
+                    match state
+                        .rows
+                        .get(&atop.id())
+                        .map(|bound| bound.index)
+                        .or_else(|| {
+                            atop.ancestors()
+                                .into_iter()
+                                .find_map(|bin| state.rows.get(&bin.id()).map(|bound| bound.index))
+                        }) {
+                        Some(some) => some,
+                        None => return Default::default(),
+                    }
+                };
+
+                let shift =
+                    window.is_key_pressed(Qwerty::LShift) || window.is_key_pressed(Qwerty::RShift);
+                let ctrl =
+                    window.is_key_pressed(Qwerty::LCtrl) || window.is_key_pressed(Qwerty::RCtrl);
+                table.select_with(index, shift, ctrl);
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        table
+            .hooks
+            .lock()
+            .extend([cursor_hook, release_hook, select_hook]);
+        table.layout_header();
+        table.list.reload();
+        table
+    }
+
+    /// Replace the source of the rows. This clears the selection & sort.
+    pub fn set_source(&self, source: Arc<dyn DataTableSource>) {
+        *self.source.lock() = source;
+
+        {
+            let mut state = self.state.lock();
+            state.sort = None;
+            state.selected.clear();
+            state.anchor = None;
+        }
+
+        self.layout_header();
+        self.list.reload();
+        self.call_on_selection_change();
+    }
+
+    /// Rebind every row.
+    ///
+    /// Should be called when the rows of the source change.
+    ///
+    /// # Notes
+    /// - Selected rows beyond the new row count will be deselected.
+    pub fn reload(&self) {
+        let count = self.source.lock().row_count();
+
+        let changed = {
+            let mut state = self.state.lock();
+            let before = state.selected.len();
+            state.selected.retain(|index| *index < count);
+            before != state.selected.len()
+        };
+
+        self.list.reload();
+
+        if changed {
+            self.call_on_selection_change();
+        }
+    }
+
+    /// Rebind a row if it is currently bound.
+    pub fn reload_row(&self, index: usize) {
+        self.list.reload_row(index);
+    }
+
+    pub fn set_selection_mode(&self, mode: SelectionMode) {
+        let changed = {
+            let mut state = self.state.lock();
+            state.mode = mode;
+
+            match mode {
+                SelectionMode::None if !state.selected.is_empty() => {
+                    state.selected.clear();
+                    true
+                },
+                SelectionMode::Single if state.selected.len() > 1 => {
+                    let first = *state.selected.iter().next().unwrap();
+                    state.selected.retain(|index| *index == first);
+                    true
+                },
+                _ => false,
+            }
+        };
+
+        if changed {
+            self.update_row_colors();
+            self.call_on_selection_change();
+        }
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.state.lock().mode
+    }
+
+    /// The indexes of the selected rows in ascending order.
+    pub fn selected(&self) -> Vec<usize> {
+        self.state.lock().selected.iter().cloned().collect()
+    }
+
+    /// Set the selected rows.
+    ///
+    /// # Notes
+    /// - This ignores the selection mode.
+    pub fn set_selected<I: IntoIterator<Item = usize>>(&self, rows: I) {
+        {
+            let mut state = self.state.lock();
+            state.selected = rows.into_iter().collect();
+            state.anchor = state.selected.iter().next().cloned();
+        }
+
+        self.update_row_colors();
+        self.call_on_selection_change();
+    }
+
+    pub fn clear_selection(&self) {
+        self.set_selected([]);
+    }
+
+    /// Add a function to be called when the selection changes.
+    pub fn on_selection_change<F: FnMut(&[usize]) + Send + 'static>(&self, func: F) {
+        self.on_selection_change.lock().push(Box::new(func));
+    }
+
+    /// The column & order the rows are currently sorted by.
+    pub fn sorted_by(&self) -> Option<(usize, SortOrder)> {
+        self.state.lock().sort
+    }
+
+    /// Sort the rows by a column.
+    ///
+    /// # Notes
+    /// - This clears the selection.
+    /// - Does nothing if the source is unable to sort by the column.
+    pub fn sort_by(&self, column: usize, order: SortOrder) {
+        if !self.source.lock().sort(column, order) {
+            return;
+        }
+
+        {
+            let mut state = self.state.lock();
+            state.sort = Some((column, order));
+            state.selected.clear();
+            state.anchor = None;
+        }
+
+        self.layout_header();
+        self.list.reload();
+        self.call_on_selection_change();
+    }
+
+    /// The order the columns are displayed in.
+    pub fn column_order(&self) -> Vec<usize> {
+        self.state.lock().order.clone()
+    }
+
+    /// Set the order the columns are displayed in.
+    ///
+    /// # Notes
+    /// - Does nothing if `order` isn't a permutation of the columns.
+    pub fn set_column_order(&self, order: Vec<usize>) {
+        {
+            let mut state = self.state.lock();
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+
+            if sorted.into_iter().ne(0..state.columns.len()) {
+                return;
+            }
+
+            state.order = order;
+        }
+
+        self.layout();
+    }
+
+    pub fn column_width(&self, column: usize) -> Option<f32> {
+        self.state
+            .lock()
+            .columns
+            .get(column)
+            .map(|column| column.width)
+    }
+
+    pub fn set_column_width(&self, column: usize, width: f32) {
+        {
+            let mut state = self.state.lock();
+
+            match state.columns.get_mut(column) {
+                Some(column) => column.width = width.max(column.min_width),
+                None => return,
+            }
+        }
+
+        self.layout();
+    }
+
+    fn toggle_sort(&self, column: usize) {
+        let order = {
+            let state = self.state.lock();
+
+            if !state.columns[column].sortable {
+                return;
+            }
+
+            match state.sort {
+                Some((sort_column, SortOrder::Ascending)) if sort_column == column => {
+                    SortOrder::Descending
+                },
+                _ => SortOrder::Ascending,
+            }
+        };
+
+        self.sort_by(column, order);
+    }
+
+    fn move_column_to_cursor(&self, column: usize, x: f32) {
+        let header_x = self.header.post_update().tli[0];
+
+        {
+            let mut state = self.state.lock();
+            let from = state.order.iter().position(|i| *i == column).unwrap();
+            let mut to = state.order.len() - 1;
+            let mut edge = header_x;
+
+            for (i, order_column) in state.order.iter().enumerate() {
+                edge += state.columns[*order_column].width;
+
+                if x < edge {
+                    to = i;
+                    break;
+                }
+            }
+
+            if from == to {
+                return;
+            }
+
+            let column = state.order.remove(from);
+            state.order.insert(to, column);
+        }
+
+        self.layout();
+    }
+
+    fn select_with(&self, index: usize, shift: bool, ctrl: bool) {
+        {
+            let mut state = self.state.lock();
+
+            match state.mode {
+                SelectionMode::None => return,
+                SelectionMode::Single => {
+                    if state.selected.len() == 1 && state.selected.contains(&index) {
+                        return;
+                    }
+
+                    state.selected.clear();
+                    state.selected.insert(index);
+                },
+                SelectionMode::Multi => {
+                    match (shift, state.anchor) {
+                        (true, Some(anchor)) => {
+                            if !ctrl {
+                                state.selected.clear();
+                            }
+
+                            state.selected.extend(anchor.min(index)..=anchor.max(index));
+                        },
+                        _ => {
+                            if ctrl {
+                                if !state.selected.remove(&index) {
+                                    state.selected.insert(index);
+                                }
+                            } else {
+                                state.selected.clear();
+                                state.selected.insert(index);
+                            }
+
+                            state.anchor = Some(index);
+                        },
+                    }
+                },
+            }
+
+            if state.mode == SelectionMode::Single {
+                state.anchor = Some(index);
+            }
+        }
+
+        self.update_row_colors();
+        self.call_on_selection_change();
+    }
+
+    fn call_on_selection_change(&self) {
+        let selected = self.selected();
+
+        for func in self.on_selection_change.lock().iter_mut() {
+            func(&selected);
+        }
+    }
+
+    fn row_color(&self, state: &State, index: usize) -> bin::Color {
+        if state.selected.contains(&index) {
+            self.theme.selected_color.clone()
+        } else if index % 2 == 1 {
+            self.theme.row_alt_color.clone()
+        } else {
+            self.theme.row_color.clone()
+        }
+    }
+
+    fn update_row_colors(&self) {
+        let state = self.state.lock();
+
+        for bound in state.rows.values() {
+            bound
+                .row
+                .style_update(BinStyle {
+                    back_color: Some(self.row_color(&state, bound.index)),
+                    ..bound.row.style_copy()
+                })
+                .expect_valid();
+        }
+    }
+
+    fn layout(&self) {
+        self.layout_header();
+        let state = self.state.lock();
+
+        for bound in state.rows.values() {
+            for (column, cell) in bound.cells.iter().enumerate() {
+                cell.style_update(BinStyle {
+                    pos_from_l: Some(state.column_x(column)),
+                    width: Some(state.columns[column].width),
+                    ..cell.style_copy()
+                })
+                .expect_valid();
+            }
+        }
+    }
+
+    fn layout_header(&self) {
+        let state = self.state.lock();
+
+        for (column, (cell, handle)) in state.header_cells.iter().enumerate() {
+            let width = state.columns[column].width;
+            let mid = self.theme.header_height / 2.0;
+
+            let custom_verts = match state.sort {
+                Some((sort_column, order)) if sort_column == column => {
+                    let (tip, base) = match order {
+                        SortOrder::Ascending => (mid - 3.0, mid + 3.0),
+                        SortOrder::Descending => (mid + 3.0, mid - 3.0),
+                    };
+
+                    vec![
+                        BinVert {
+                            position: (width - 12.0, tip, 0),
+                            color: self.theme.indicator_color.clone(),
+                        },
+                        BinVert {
+                            position: (width - 16.0, base, 0),
+                            color: self.theme.indicator_color.clone(),
+                        },
+                        BinVert {
+                            position: (width - 8.0, base, 0),
+                            color: self.theme.indicator_color.clone(),
+                        },
+                    ]
+                },
+                _ => Vec::new(),
+            };
+
+            cell.style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(state.column_x(column)),
+                width: Some(width),
+                pad_l: Some(6.0),
+                border_size_r: Some(1.0),
+                border_color_r: Some(self.theme.border_color.clone()),
+                text: state.columns[column].title.clone(),
+                text_color: Some(self.theme.header_text_color.clone()),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                custom_verts,
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+            handle
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(0.0),
+                    pos_from_b: Some(0.0),
+                    pos_from_r: Some(0.0),
+                    width: Some(5.0),
+                    hidden: Some(!state.columns[column].resizable),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+        }
+    }
+
+    fn bind_row(&self, index: usize, row: &Arc<Bin>) {
+        let column_count = {
+            let mut state = self.state.lock();
+            let column_count = state.columns.len();
+
+            if !state.rows.contains_key(&row.id()) {
+                let cells = self.basalt.interface_ref().new_bins(column_count);
+                row.add_children(cells.clone());
+
+                state.rows.insert(
+                    row.id(),
+                    BoundRow {
+                        index,
+                        row: row.clone(),
+                        cells,
+                        content: vec![None; column_count],
+                    },
+                );
+            }
+
+            state.rows.get_mut(&row.id()).unwrap().index = index;
+            column_count
+        };
+
+        let source = self.source.lock().clone();
+        let content: Vec<_> = (0..column_count)
+            .map(|column| source.cell(index, column))
+            .collect();
+        let mut state = self.state.lock();
+
+        row.style_update(BinStyle {
+            back_color: Some(self.row_color(&state, index)),
+            ..row.style_copy()
+        })
+        .expect_valid();
+
+        let mut cell_styles = Vec::with_capacity(column_count);
+
+        for column in 0..column_count {
+            cell_styles.push(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(state.column_x(column)),
+                width: Some(state.columns[column].width),
+                pad_l: Some(6.0),
+                text_color: Some(self.theme.text_color.clone()),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            });
+        }
+
+        let bound = state.rows.get_mut(&row.id()).unwrap();
+
+        for (column, (content, mut style)) in content.into_iter().zip(cell_styles).enumerate() {
+            let cell = &bound.cells[column];
+            cell.take_children();
+
+            bound.content[column] = match content {
+                DataTableCell::Text(text) => {
+                    style.text = text;
+                    None
+                },
+                DataTableCell::Bin(bin) => {
+                    cell.add_child(bin.clone());
+                    Some(bin)
+                },
+            };
+
+            cell.style_update(style).expect_valid();
+        }
+    }
+}
@@ -0,0 +1,1037 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::Basalt;
+
+impl KeepAlive for DatePicker {}
+
+/// Amount of weeks displayed in the grid.
+const GRID_WEEKS: usize = 6;
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// The index of the day starting with `0` for Monday.
+    pub fn index(self) -> usize {
+        match self {
+            Self::Monday => 0,
+            Self::Tuesday => 1,
+            Self::Wednesday => 2,
+            Self::Thursday => 3,
+            Self::Friday => 4,
+            Self::Saturday => 5,
+            Self::Sunday => 6,
+        }
+    }
+
+    /// The day from an index starting with `0` for Monday. Wraps around every 7 days.
+    pub fn from_index(index: usize) -> Self {
+        match index % 7 {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+}
+
+/// A date in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Create a date. Returns `None` if the month or day is out of range.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Date {
+            year,
+            month,
+            day,
+        })
+    }
+
+    /// The current date in UTC.
+    pub fn today_utc() -> Self {
+        let secs = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(since) => since.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64) - 1,
+        };
+
+        Self::from_days(secs.div_euclid(86400))
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month from `1` to `12`.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month starting at `1`.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::from_index((self.to_days() + 3).rem_euclid(7) as usize)
+    }
+
+    /// Add an amount of days which may be negative.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Add an amount of months which may be negative.
+    ///
+    /// The day is limited to the last day of the resulting month.
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = (self.year * 12) + (self.month as i32 - 1) + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u8 + 1;
+
+        Date {
+            year,
+            month,
+            day: self.day.min(Self::days_in_month(year, month)),
+        }
+    }
+
+    /// The first day of the month of this date.
+    pub fn first_of_month(&self) -> Self {
+        Date {
+            day: 1,
+            ..*self
+        }
+    }
+
+    /// Days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let yoe = year - (era * 400);
+        let mp = (self.month as i64 + 9) % 12;
+        let doy = ((153 * mp) + 2) / 5 + self.day as i64 - 1;
+        let doe = (yoe * 365) + (yoe / 4) - (yoe / 100) + doy;
+        (era * 146097) + doe - 719468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let doe = days - (era * 146097);
+        let yoe = (doe - (doe / 1460) + (doe / 36524) - (doe / 146096)) / 365;
+        let doy = doe - ((365 * yoe) + (yoe / 4) - (yoe / 100));
+        let mp = ((5 * doy) + 2) / 153;
+        let day = (doy - (((153 * mp) + 2) / 5) + 1) as u8;
+        let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+
+        Date {
+            year: ((era * 400) + yoe + (month <= 2) as i64) as i32,
+            month,
+            day,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parse a date in the `YYYY-MM-DD` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().rsplitn(3, '-');
+        let (day, month, year) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(day), Some(month), Some(year)) => (day, month, year),
+            _ => return Err(format!("'{}' isn't in the YYYY-MM-DD format.", s)),
+        };
+
+        let year = year
+            .parse::<i32>()
+            .map_err(|e| format!("Invalid year '{}': {}", year, e))?;
+        let month = month
+            .parse::<u8>()
+            .map_err(|e| format!("Invalid month '{}': {}", month, e))?;
+        let day = day
+            .parse::<u8>()
+            .map_err(|e| format!("Invalid day '{}': {}", day, e))?;
+
+        Date::new(year, month, day).ok_or_else(|| format!("'{}' isn't a valid date.", s))
+    }
+}
+
+/// How dates are selected in a `DatePicker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateSelectMode {
+    /// A single date is selected.
+    Single,
+    /// A range is selected with two clicks, the first being the start and second the end.
+    Range,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatePickerTheme {
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    pub header_color: bin::Color,
+    pub text_color: bin::Color,
+    pub weekday_color: bin::Color,
+    /// Text color of days outside of the displayed month.
+    pub outside_color: bin::Color,
+    /// Text color of days before the min or after the max date.
+    pub disabled_color: bin::Color,
+    pub today_color: bin::Color,
+    pub selected_color: bin::Color,
+    pub selected_text_color: bin::Color,
+    /// Background color of days between the start & end of a range.
+    pub range_color: bin::Color,
+    /// Border color of the day focused with the keyboard.
+    pub focus_color: bin::Color,
+    pub arrow_color: bin::Color,
+    pub text_height: f32,
+    pub cell_size: f32,
+    pub pad: f32,
+    /// The day displayed in the first column.
+    pub first_day_of_week: Weekday,
+    /// Names of the months starting with January.
+    pub month_names: [String; 12],
+    /// Names of the weekdays starting with Monday.
+    pub weekday_names: [String; 7],
+}
+
+impl Default for DatePickerTheme {
+    fn default() -> Self {
+        DatePickerTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            border_color: bin::Color::srgb_hex("505058"),
+            header_color: bin::Color::srgb_hex("ffffff"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            weekday_color: bin::Color::srgb_hex("9090a0"),
+            outside_color: bin::Color::srgb_hex("60606c"),
+            disabled_color: bin::Color::srgb_hex("44444c"),
+            today_color: bin::Color::srgb_hex("5aa0ff"),
+            selected_color: bin::Color::srgb_hex("3a7bd5"),
+            selected_text_color: bin::Color::srgb_hex("ffffff"),
+            range_color: bin::Color::srgb_hex("3a7bd550"),
+            focus_color: bin::Color::srgb_hex("c0c0c8"),
+            arrow_color: bin::Color::srgb_hex("c0c0c8"),
+            text_height: 12.0,
+            cell_size: 28.0,
+            pad: 6.0,
+            first_day_of_week: Weekday::Monday,
+            month_names: [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]
+            .map(String::from),
+            weekday_names: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"].map(String::from),
+        }
+    }
+}
+
+/// A calendar displaying a month as a grid of days.
+///
+/// ### Keyboard
+/// - Arrow keys move the focused day by a day or a week.
+/// - `PageUp` & `PageDown` move the focused day by a month.
+/// - `Home` & `End` move the focused day to the start or end of the week.
+/// - `Enter` & `Space` select the focused day.
+pub struct DatePicker {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub previous_button: Arc<Bin>,
+    pub next_button: Arc<Bin>,
+    pub title: Arc<Bin>,
+    weekdays: Vec<Arc<Bin>>,
+    days: Vec<Arc<Bin>>,
+    theme: DatePickerTheme,
+    mode: DateSelectMode,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(Option<(Date, Date)>) + Send + 'static>>>,
+}
+
+struct State {
+    /// The first day of the displayed month.
+    view: Date,
+    /// The day focused with the keyboard.
+    cursor: Date,
+    selection: Option<(Date, Date)>,
+    /// The start of a range waiting for its end to be clicked.
+    anchor: Option<Date>,
+    min: Option<Date>,
+    max: Option<Date>,
+    today: Date,
+}
+
+impl State {
+    fn grid_start(&self, first_day: Weekday) -> Date {
+        let offset = (self.view.weekday().index() + 7 - first_day.index()) % 7;
+        self.view.add_days(-(offset as i64))
+    }
+
+    fn in_bounds(&self, date: Date) -> bool {
+        self.min.map(|min| date >= min).unwrap_or(true)
+            && self.max.map(|max| date <= max).unwrap_or(true)
+    }
+
+    fn constrain(&self, mut date: Date) -> Date {
+        if let Some(min) = self.min {
+            date = date.max(min);
+        }
+
+        if let Some(max) = self.max {
+            date = date.min(max);
+        }
+
+        date
+    }
+}
+
+impl DatePicker {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: DatePickerTheme,
+        mode: DateSelectMode,
+        parent: Option<Arc<Bin>>,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(4 + 7 + (GRID_WEEKS * 7));
+        let container = bins.pop().unwrap();
+        let previous_button = bins.pop().unwrap();
+        let next_button = bins.pop().unwrap();
+        let title = bins.pop().unwrap();
+        let days = bins.split_off(7);
+        let weekdays = bins;
+
+        container.add_children(vec![
+            previous_button.clone(),
+            next_button.clone(),
+            title.clone(),
+        ]);
+
+        container.add_children(weekdays.clone());
+        container.add_children(days.clone());
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        let cell = theme.cell_size;
+        let weekday_h = theme.text_height * 1.8;
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some((cell * 7.0) + (theme.pad * 2.0)),
+                height: Some((cell * (GRID_WEEKS + 1) as f32) + weekday_h + (theme.pad * 2.0)),
+                back_color: Some(theme.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(theme.border_color.clone()),
+                border_color_b: Some(theme.border_color.clone()),
+                border_color_l: Some(theme.border_color.clone()),
+                border_color_r: Some(theme.border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        title
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(theme.pad),
+                pos_from_l: Some(theme.pad + cell),
+                pos_from_r: Some(theme.pad + cell),
+                height: Some(cell),
+                text_color: Some(theme.header_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        for (button, left) in [(&previous_button, true), (&next_button, false)] {
+            let (cx, cy) = (cell / 2.0, cell / 2.0);
+            let size = theme.text_height / 3.0;
+
+            let points = match left {
+                true => {
+                    [
+                        (cx - size, cy),
+                        (cx + size, cy - size),
+                        (cx + size, cy + size),
+                    ]
+                },
+                false => {
+                    [
+                        (cx + size, cy),
+                        (cx - size, cy + size),
+                        (cx - size, cy - size),
+                    ]
+                },
+            };
+
+            button
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(theme.pad),
+                    pos_from_l: match left {
+                        true => Some(theme.pad),
+                        false => None,
+                    },
+                    pos_from_r: match left {
+                        true => None,
+                        false => Some(theme.pad),
+                    },
+                    width: Some(cell),
+                    height: Some(cell),
+                    custom_verts: points
+                        .into_iter()
+                        .map(|(x, y)| {
+                            BinVert {
+                                position: (x, y, 0),
+                                color: theme.arrow_color.clone(),
+                            }
+                        })
+                        .collect(),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+        }
+
+        for (i, weekday) in weekdays.iter().enumerate() {
+            let day = Weekday::from_index(theme.first_day_of_week.index() + i);
+
+            weekday
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(theme.pad + cell),
+                    pos_from_l: Some(theme.pad + (i as f32 * cell)),
+                    width: Some(cell),
+                    height: Some(weekday_h),
+                    text: theme.weekday_names[day.index()].clone(),
+                    text_color: Some(theme.weekday_color.clone()),
+                    text_height: Some(theme.text_height * 0.9),
+                    text_vert_align: Some(TextVertAlign::Center),
+                    text_hori_align: Some(TextHoriAlign::Center),
+                    text_wrap: Some(TextWrap::None),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+        }
+
+        let today = Date::today_utc();
+
+        let picker = Arc::new(DatePicker {
+            basalt,
+            container,
+            previous_button,
+            next_button,
+            title,
+            weekdays,
+            days,
+            theme,
+            mode,
+            state: Mutex::new(State {
+                view: today.first_of_month(),
+                cursor: today,
+                selection: None,
+                anchor: None,
+                min: None,
+                max: None,
+                today,
+            }),
+            on_change: Mutex::new(Vec::new()),
+        });
+
+        for (button, months) in [(&picker.previous_button, -1), (&picker.next_button, 1)] {
+            let picker_wk = Arc::downgrade(&picker);
+
+            button.on_press(MouseButton::Left, move |_, _, _| {
+                match picker_wk.upgrade() {
+                    Some(picker) => {
+                        picker.move_cursor_months(months);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+        }
+
+        for (i, day) in picker.days.iter().enumerate() {
+            let picker_wk = Arc::downgrade(&picker);
+
+            day.on_press(MouseButton::Left, move |_, _, _| {
+                let picker = match picker_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let date = {
+                    let state = picker.state.lock();
+
+                    state
+                        .grid_start(picker.theme.first_day_of_week)
+                        .add_days(i as i64)
+                };
+
+                picker.select_date(date);
+                Default::default()
+            });
+        }
+
+        // A click focuses the top-most bin, so keys are hooked on every bin of the picker.
+        let key_bins: Vec<_> = [
+            &picker.container,
+            &picker.previous_button,
+            &picker.next_button,
+            &picker.title,
+        ]
+        .into_iter()
+        .chain(picker.weekdays.iter())
+        .chain(picker.days.iter())
+        .cloned()
+        .collect();
+
+        for bin in key_bins {
+            for key in [
+                Qwerty::ArrowLeft,
+                Qwerty::ArrowRight,
+                Qwerty::ArrowUp,
+                Qwerty::ArrowDown,
+                Qwerty::PageUp,
+                Qwerty::PageDown,
+                Qwerty::Home,
+                Qwerty::End,
+                Qwerty::Enter,
+                Qwerty::Space,
+            ] {
+                let picker_wk = Arc::downgrade(&picker);
+
+                bin.on_press(key, move |_, _, _| {
+                    let picker = match picker_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    let first_day = picker.theme.first_day_of_week;
+
+                    match key {
+                        Qwerty::ArrowLeft => picker.move_cursor_days(-1),
+                        Qwerty::ArrowRight => picker.move_cursor_days(1),
+                        Qwerty::ArrowUp => picker.move_cursor_days(-7),
+                        Qwerty::ArrowDown => picker.move_cursor_days(7),
+                        Qwerty::PageUp => picker.move_cursor_months(-1),
+                        Qwerty::PageDown => picker.move_cursor_months(1),
+                        Qwerty::Home | Qwerty::End => {
+                            let cursor = picker.state.lock().cursor;
+                            let from_start = (cursor.weekday().index() + 7 - first_day.index()) % 7;
+
+                            match key {
+                                Qwerty::Home => picker.move_cursor_days(-(from_start as i64)),
+                                _ => picker.move_cursor_days(6 - from_start as i64),
+                            }
+                        },
+                        _ => {
+                            let cursor = picker.state.lock().cursor;
+                            picker.select_date(cursor);
+                        },
+                    }
+
+                    Default::default()
+                });
+            }
+        }
+
+        picker.update();
+        picker
+    }
+
+    /// The year & month displayed.
+    pub fn view(&self) -> (i32, u8) {
+        let view = self.state.lock().view;
+        (view.year(), view.month())
+    }
+
+    /// Display a month. The month is limited to `1` through `12`.
+    pub fn set_view(&self, year: i32, month: u8) {
+        let view = Date::new(year, month.clamp(1, 12), 1).unwrap();
+
+        {
+            let mut state = self.state.lock();
+            state.view = view;
+            state.cursor = view.add_days(state.cursor.day() as i64 - 1);
+
+            if state.cursor.month() != view.month() {
+                state.cursor = view;
+            }
+        }
+
+        self.update();
+    }
+
+    pub fn previous_month(&self) {
+        self.move_cursor_months(-1);
+    }
+
+    pub fn next_month(&self) {
+        self.move_cursor_months(1);
+    }
+
+    /// The selected date. In `Range` mode this is the start of the range.
+    pub fn selected(&self) -> Option<Date> {
+        self.state.lock().selection.map(|(start, _)| start)
+    }
+
+    /// The start & end of the selected range. In `Single` mode both are the selected date.
+    pub fn selected_range(&self) -> Option<(Date, Date)> {
+        self.state.lock().selection
+    }
+
+    /// Select a date and display its month.
+    ///
+    /// In `Range` mode this selects a range of a single day.
+    pub fn select(&self, date: Date) {
+        self.select_range(date, date);
+    }
+
+    /// Select a range and display the month of its start.
+    ///
+    /// The dates are swapped if the end is before the start and limited to the min & max.
+    pub fn select_range(&self, start: Date, end: Date) {
+        let selection = {
+            let mut state = self.state.lock();
+            let start = state.constrain(start.min(end));
+
+            let end = match self.mode {
+                DateSelectMode::Single => start,
+                DateSelectMode::Range => state.constrain(start.max(end)),
+            };
+
+            state.anchor = None;
+            state.view = start.first_of_month();
+            state.cursor = start;
+
+            if state.selection == Some((start, end)) {
+                None
+            } else {
+                state.selection = Some((start, end));
+                state.selection
+            }
+        };
+
+        self.update();
+
+        if selection.is_some() {
+            self.call_on_change(selection);
+        }
+    }
+
+    /// Clear the selection.
+    pub fn clear(&self) {
+        let changed = {
+            let mut state = self.state.lock();
+            state.anchor = None;
+            state.selection.take().is_some()
+        };
+
+        self.update();
+
+        if changed {
+            self.call_on_change(None);
+        }
+    }
+
+    /// Set the earliest & latest dates that can be selected.
+    ///
+    /// A selection outside of the dates is cleared.
+    pub fn set_min_max(&self, min: Option<Date>, max: Option<Date>) {
+        let cleared = {
+            let mut state = self.state.lock();
+            state.min = min;
+            state.max = max;
+            state.cursor = state.constrain(state.cursor);
+
+            if state.anchor.map(|anchor| !state.in_bounds(anchor)) == Some(true) {
+                state.anchor = None;
+            }
+
+            match state.selection {
+                Some((start, end)) if !state.in_bounds(start) || !state.in_bounds(end) => {
+                    state.selection = None;
+                    true
+                },
+                _ => false,
+            }
+        };
+
+        self.update();
+
+        if cleared {
+            self.call_on_change(None);
+        }
+    }
+
+    pub fn min_max(&self) -> (Option<Date>, Option<Date>) {
+        let state = self.state.lock();
+        (state.min, state.max)
+    }
+
+    /// Set the date highlighted as today.
+    ///
+    /// **Default**: `Date::today_utc()` at creation.
+    pub fn set_today(&self, today: Date) {
+        self.state.lock().today = today;
+        self.update();
+    }
+
+    /// Add a function to be called when the selection changes.
+    ///
+    /// The function is called with the start & end of the selection. In `Range` mode it is
+    /// called once the end of the range is clicked.
+    pub fn on_change<F: FnMut(Option<(Date, Date)>) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    fn call_on_change(&self, selection: Option<(Date, Date)>) {
+        for func in self.on_change.lock().iter_mut() {
+            func(selection);
+        }
+    }
+
+    fn select_date(&self, date: Date) {
+        let selection = {
+            let mut state = self.state.lock();
+
+            if !state.in_bounds(date) {
+                return;
+            }
+
+            state.cursor = date;
+            state.view = date.first_of_month();
+
+            match (self.mode, state.anchor.take()) {
+                (DateSelectMode::Single, _) => {
+                    state.selection = Some((date, date));
+                    state.selection
+                },
+                (DateSelectMode::Range, Some(anchor)) => {
+                    state.selection = Some((anchor.min(date), anchor.max(date)));
+                    state.selection
+                },
+                (DateSelectMode::Range, None) => {
+                    state.anchor = Some(date);
+                    None
+                },
+            }
+        };
+
+        self.update();
+
+        if selection.is_some() {
+            self.call_on_change(selection);
+        }
+    }
+
+    fn move_cursor_days(&self, days: i64) {
+        {
+            let mut state = self.state.lock();
+            state.cursor = state.constrain(state.cursor.add_days(days));
+            state.view = state.cursor.first_of_month();
+        }
+
+        self.update();
+    }
+
+    fn move_cursor_months(&self, months: i32) {
+        {
+            let mut state = self.state.lock();
+            state.view = state.view.add_months(months);
+            // Keep the day of the cursor within the new month, the view stays even if limited.
+            state.cursor = state.constrain(state.cursor.add_months(months));
+        }
+
+        self.update();
+    }
+
+    fn update(&self) {
+        let state = self.state.lock();
+        let theme = &self.theme;
+        let cell = theme.cell_size;
+        let grid_t = theme.pad + cell + (theme.text_height * 1.8);
+        let grid_start = state.grid_start(theme.first_day_of_week);
+
+        // While picking the end of a range, the anchor is displayed as selected.
+        let selection = match state.anchor {
+            Some(anchor) => Some((anchor, anchor)),
+            None => state.selection,
+        };
+
+        self.title
+            .style_update(BinStyle {
+                text: format!(
+                    "{} {}",
+                    theme.month_names[state.view.month() as usize - 1],
+                    state.view.year()
+                ),
+                ..self.title.style_copy()
+            })
+            .expect_valid();
+
+        for (i, day) in self.days.iter().enumerate() {
+            let date = grid_start.add_days(i as i64);
+            let endpoint = selection
+                .map(|(start, end)| date == start || date == end)
+                .unwrap_or(false);
+            let within = selection
+                .map(|(start, end)| date > start && date < end)
+                .unwrap_or(false);
+
+            let back_color = if endpoint {
+                Some(theme.selected_color.clone())
+            } else if within {
+                Some(theme.range_color.clone())
+            } else {
+                None
+            };
+
+            let text_color = if !state.in_bounds(date) {
+                theme.disabled_color.clone()
+            } else if endpoint {
+                theme.selected_text_color.clone()
+            } else if date.month() != state.view.month() {
+                theme.outside_color.clone()
+            } else if date == state.today {
+                theme.today_color.clone()
+            } else {
+                theme.text_color.clone()
+            };
+
+            let border_color = match date == state.cursor {
+                true => Some(theme.focus_color.clone()),
+                false => None,
+            };
+
+            day.style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(grid_t + ((i / 7) as f32 * cell)),
+                pos_from_l: Some(theme.pad + ((i % 7) as f32 * cell)),
+                width: Some(cell),
+                height: Some(cell),
+                back_color,
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: border_color.clone(),
+                border_color_b: border_color.clone(),
+                border_color_l: border_color.clone(),
+                border_color_r: border_color,
+                border_radius_tl: Some(3.0),
+                border_radius_tr: Some(3.0),
+                border_radius_bl: Some(3.0),
+                border_radius_br: Some(3.0),
+                text: format!("{}", date.day()),
+                text_color: Some(text_color),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, State, Weekday};
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn validation() {
+        assert!(Date::is_leap_year(2024));
+        assert!(Date::is_leap_year(2000));
+        assert!(!Date::is_leap_year(1900));
+        assert!(!Date::is_leap_year(2023));
+        assert_eq!(Date::days_in_month(2024, 2), 29);
+        assert_eq!(Date::days_in_month(2100, 2), 28);
+        assert_eq!(Date::days_in_month(2023, 4), 30);
+        assert_eq!(Date::days_in_month(2023, 12), 31);
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2023, 0, 1).is_none());
+        assert!(Date::new(2023, 13, 1).is_none());
+        assert!(Date::new(2023, 1, 0).is_none());
+        assert!(Date::new(2023, 1, 32).is_none());
+    }
+
+    #[test]
+    fn days_since_epoch() {
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(date(1969, 12, 31).to_days(), -1);
+        assert_eq!(date(2000, 3, 1).to_days(), 11017);
+        assert_eq!(Date::from_days(0), date(1970, 1, 1));
+        assert_eq!(Date::from_days(11017), date(2000, 3, 1));
+        assert_eq!(Date::from_days(-719468), date(0, 3, 1));
+
+        let mut expected = date(-401, 1, 1);
+
+        for days in expected.to_days()..date(2401, 1, 1).to_days() {
+            let from = Date::from_days(days);
+            assert_eq!(from, expected);
+            assert_eq!(from.to_days(), days);
+
+            expected = match Date::new(expected.year, expected.month, expected.day + 1) {
+                Some(next) => next,
+                None => {
+                    match expected.month {
+                        12 => date(expected.year + 1, 1, 1),
+                        month => date(expected.year, month + 1, 1),
+                    }
+                },
+            };
+        }
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(1969, 12, 29).weekday(), Weekday::Monday);
+        assert_eq!(date(2000, 1, 1).weekday(), Weekday::Saturday);
+        assert_eq!(date(2024, 2, 29).weekday(), Weekday::Thursday);
+        assert_eq!(date(2024, 9, 1).weekday(), Weekday::Sunday);
+
+        for index in 0..14 {
+            assert_eq!(Weekday::from_index(index).index(), index % 7);
+        }
+    }
+
+    #[test]
+    fn add_days() {
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 1).add_days(366), date(2025, 1, 1));
+        assert_eq!(date(1970, 1, 1).add_days(-365), date(1969, 1, 1));
+    }
+
+    #[test]
+    fn add_months() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 3, 31).add_months(-13), date(2023, 2, 28));
+        assert_eq!(date(2024, 12, 15).add_months(1), date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(0, 1, 1).add_months(-1), date(-1, 12, 1));
+        assert_eq!(date(2024, 5, 31).first_of_month(), date(2024, 5, 1));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(date(2024, 2, 9).to_string(), "2024-02-09");
+        assert_eq!(" 2024-02-09 ".parse(), Ok(date(2024, 2, 9)));
+        assert_eq!("-1-12-31".parse(), Ok(date(-1, 12, 31)));
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-02".parse::<Date>().is_err());
+        assert!("2024-xx-01".parse::<Date>().is_err());
+
+        for date in [date(1, 1, 1), date(1999, 12, 31), date(-44, 3, 15)] {
+            assert_eq!(date.to_string().parse(), Ok(date));
+        }
+    }
+
+    #[test]
+    fn grid_start() {
+        let view = date(2024, 9, 1);
+
+        let state = State {
+            view,
+            cursor: view,
+            selection: None,
+            anchor: None,
+            min: Some(date(2024, 9, 10)),
+            max: Some(date(2024, 9, 20)),
+            today: view,
+        };
+
+        assert_eq!(state.grid_start(Weekday::Monday), date(2024, 8, 26));
+        assert_eq!(state.grid_start(Weekday::Sunday), date(2024, 9, 1));
+        assert_eq!(state.grid_start(Weekday::Saturday), date(2024, 8, 31));
+        assert!(!state.in_bounds(date(2024, 9, 9)));
+        assert!(state.in_bounds(date(2024, 9, 10)));
+        assert!(state.in_bounds(date(2024, 9, 20)));
+        assert!(!state.in_bounds(date(2024, 9, 21)));
+        assert_eq!(state.constrain(date(2024, 1, 1)), date(2024, 9, 10));
+        assert_eq!(state.constrain(date(2025, 1, 1)), date(2024, 9, 20));
+        assert_eq!(state.constrain(date(2024, 9, 15)), date(2024, 9, 15));
+    }
+}
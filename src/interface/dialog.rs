@@ -0,0 +1,799 @@
+use std::sync::Arc;
+
+use crossbeam::channel::{self, Receiver};
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, InputHookID, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interface::overlay::{OverlayID, OverlayLayer, MODAL_BLOCK_WEIGHT};
+use crate::Basalt;
+
+impl KeepAlive for Dialog {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogTheme {
+    /// Color of the backdrop covering the window behind the dialog.
+    pub backdrop_color: bin::Color,
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    pub title_back_color: bin::Color,
+    pub title_color: bin::Color,
+    pub text_color: bin::Color,
+    pub button_color: bin::Color,
+    pub button_text_color: bin::Color,
+    /// Border color of the focused button or input.
+    pub focus_color: bin::Color,
+    pub input_back_color: bin::Color,
+    pub text_height: f32,
+    pub width: f32,
+    pub pad: f32,
+    pub title_height: f32,
+    pub button_height: f32,
+    /// Label of the accepting button used by `alert`, `confirm` & `prompt`.
+    pub accept_label: String,
+    /// Label of the cancelling button used by `confirm` & `prompt`.
+    pub cancel_label: String,
+}
+
+impl Default for DialogTheme {
+    fn default() -> Self {
+        DialogTheme {
+            backdrop_color: bin::Color::srgb_hex("00000080"),
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            border_color: bin::Color::srgb_hex("505058"),
+            title_back_color: bin::Color::srgb_hex("202024"),
+            title_color: bin::Color::srgb_hex("ffffff"),
+            text_color: bin::Color::srgb_hex("d0d0d8"),
+            button_color: bin::Color::srgb_hex("404048"),
+            button_text_color: bin::Color::srgb_hex("ffffff"),
+            focus_color: bin::Color::srgb_hex("3a7bd5"),
+            input_back_color: bin::Color::srgb_hex("202024"),
+            text_height: 12.0,
+            width: 360.0,
+            pad: 10.0,
+            title_height: 28.0,
+            button_height: 24.0,
+            accept_label: String::from("OK"),
+            cancel_label: String::from("Cancel"),
+        }
+    }
+}
+
+/// A modal dialog with a title bar, a content `Bin` and a row of buttons.
+///
+/// The dialog is centered over a backdrop covering the window and shown on the `Modal` overlay
+/// layer, so input to the rest of the interface is blocked while it is shown.
+///
+/// ### Keyboard
+/// - `Enter` activates the focused button or the default button.
+/// - `Esc` activates the cancel button or closes the dialog without a button.
+/// - `Tab` & `Shift+Tab` move focus between the buttons & bins added with `add_focusable`
+/// without leaving the dialog.
+pub struct Dialog {
+    pub basalt: Arc<Basalt>,
+    pub backdrop: Arc<Bin>,
+    pub panel: Arc<Bin>,
+    pub title_bar: Arc<Bin>,
+    pub content: Arc<Bin>,
+    pub button_row: Arc<Bin>,
+    theme: DialogTheme,
+    state: Mutex<State>,
+    on_close: Mutex<Vec<Box<dyn FnMut(Option<usize>) + Send + 'static>>>,
+    hooks: Mutex<Vec<InputHookID>>,
+}
+
+struct State {
+    content_height: f32,
+    buttons: Vec<Arc<Bin>>,
+    default_button: Option<usize>,
+    cancel_button: Option<usize>,
+    focusable: Vec<Arc<Bin>>,
+    overlay: Option<OverlayID>,
+}
+
+impl Drop for Dialog {
+    fn drop(&mut self) {
+        for id in self.hooks.get_mut().split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+
+        if let Some(id) = self.state.get_mut().overlay.take() {
+            self.basalt.interface_ref().overlays().hide(id);
+        }
+    }
+}
+
+impl Dialog {
+    pub fn new<T: Into<String>>(basalt: Arc<Basalt>, theme: DialogTheme, title: T) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(5);
+        let backdrop = bins.pop().unwrap();
+        let panel = bins.pop().unwrap();
+        let title_bar = bins.pop().unwrap();
+        let content = bins.pop().unwrap();
+        let button_row = bins.pop().unwrap();
+
+        backdrop.add_child(panel.clone());
+        panel.add_children(vec![title_bar.clone(), content.clone(), button_row.clone()]);
+
+        backdrop
+            .style_update(BinStyle {
+                position: Some(BinPosition::Window),
+                hidden: Some(true),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                back_color: Some(theme.backdrop_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        title_bar
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(theme.title_height),
+                pad_l: Some(theme.pad),
+                back_color: Some(theme.title_back_color.clone()),
+                text: title.into(),
+                text_color: Some(theme.title_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        content
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(theme.title_height + theme.pad),
+                pos_from_l: Some(theme.pad),
+                pos_from_r: Some(theme.pad),
+                pos_from_b: Some(theme.button_height + (theme.pad * 2.0)),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        button_row
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_b: Some(theme.pad),
+                pos_from_l: Some(theme.pad),
+                pos_from_r: Some(theme.pad),
+                height: Some(theme.button_height),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let dialog = Arc::new(Dialog {
+            basalt,
+            backdrop,
+            panel,
+            title_bar,
+            content,
+            button_row,
+            theme,
+            state: Mutex::new(State {
+                content_height: 60.0,
+                buttons: Vec::new(),
+                default_button: None,
+                cancel_button: None,
+                focusable: Vec::new(),
+                overlay: None,
+            }),
+            on_close: Mutex::new(Vec::new()),
+            hooks: Mutex::new(Vec::new()),
+        });
+
+        dialog.layout();
+        dialog
+    }
+
+    /// Set the title displayed in the title bar.
+    pub fn set_title<T: Into<String>>(&self, title: T) {
+        self.title_bar
+            .style_update(BinStyle {
+                text: title.into(),
+                ..self.title_bar.style_copy()
+            })
+            .expect_valid();
+    }
+
+    /// Set the height of the content `Bin`. The width is the width of the theme minus padding.
+    ///
+    /// **Default**: `60.0`
+    pub fn set_content_height(&self, height: f32) {
+        self.state.lock().content_height = height;
+        self.layout();
+    }
+
+    /// Add a button to the end of the button row. Returns the index of the button.
+    ///
+    /// Pressing a button closes the dialog with its index.
+    pub fn add_button<T: Into<String>>(self: &Arc<Self>, label: T) -> usize {
+        let label = label.into();
+        let button = self.basalt.interface_ref().new_bin();
+        self.button_row.add_child(button.clone());
+        button.set_focusable(true);
+
+        let width = (label.chars().count() as f32 * self.theme.text_height * 0.6) + 24.0;
+
+        button
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                width: Some(width.max(64.0)),
+                back_color: Some(self.theme.button_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(self.theme.button_color.clone()),
+                border_color_b: Some(self.theme.button_color.clone()),
+                border_color_l: Some(self.theme.button_color.clone()),
+                border_color_r: Some(self.theme.button_color.clone()),
+                border_radius_tl: Some(3.0),
+                border_radius_tr: Some(3.0),
+                border_radius_bl: Some(3.0),
+                border_radius_br: Some(3.0),
+                text: label,
+                text_color: Some(self.theme.button_text_color.clone()),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let index = {
+            let mut state = self.state.lock();
+            state.buttons.push(button.clone());
+            state.buttons.len() - 1
+        };
+
+        let dialog_wk = Arc::downgrade(self);
+
+        button.on_press(MouseButton::Left, move |_, _, _| {
+            match dialog_wk.upgrade() {
+                Some(dialog) => {
+                    dialog.close(Some(index));
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        self.focus_indicator(&button, self.theme.button_color.clone());
+        self.layout();
+        index
+    }
+
+    /// Set the button activated by `Enter` when no button is focused.
+    pub fn set_default_button(&self, index: usize) {
+        self.state.lock().default_button = Some(index);
+    }
+
+    /// Set the button activated by `Esc`.
+    ///
+    /// When not set, `Esc` closes the dialog without a button.
+    pub fn set_cancel_button(&self, index: usize) {
+        self.state.lock().cancel_button = Some(index);
+    }
+
+    /// Add a `Bin` within the content that can be focused with `Tab`.
+    ///
+    /// These are focused before the buttons in the order they're added.
+    pub fn add_focusable(&self, bin: Arc<Bin>) {
+        bin.set_focusable(true);
+        self.state.lock().focusable.push(bin);
+    }
+
+    /// Add a function to be called when the dialog is closed.
+    ///
+    /// The function is called with the index of the button that closed the dialog.
+    pub fn on_close<F: FnMut(Option<usize>) + Send + 'static>(&self, func: F) {
+        self.on_close.lock().push(Box::new(func));
+    }
+
+    pub fn is_shown(&self) -> bool {
+        self.state.lock().overlay.is_some()
+    }
+
+    /// Show the dialog.
+    ///
+    /// The dialog is kept alive while it is shown.
+    pub fn show(self: &Arc<Self>) {
+        if self.is_shown() {
+            return;
+        }
+
+        let overlays = self.basalt.interface_ref().overlays();
+        let id = overlays.show(self.backdrop.clone(), OverlayLayer::Modal);
+        self.state.lock().overlay = Some(id);
+        let dialog = self.clone();
+
+        // Dismissed by `Esc`, the overlay is already hidden.
+        overlays.on_dismiss(id, move || {
+            let cancel = {
+                let mut state = dialog.state.lock();
+
+                if state.overlay.take().is_none() {
+                    return;
+                }
+
+                state.cancel_button
+            };
+
+            dialog.closed(cancel);
+        });
+
+        let window = self.basalt.window();
+        let mut hooks = self.hooks.lock();
+
+        for key in [Qwerty::Enter, Qwerty::Tab] {
+            let dialog_wk = Arc::downgrade(self);
+
+            hooks.push(
+                self.basalt
+                    .input_ref()
+                    .hook()
+                    .window(&window)
+                    .on_press()
+                    .keys(key)
+                    .weight(MODAL_BLOCK_WEIGHT)
+                    .call(move |_, window, _| {
+                        let dialog = match dialog_wk.upgrade() {
+                            Some(some) => some,
+                            None => return InputHookCtrl::Remove,
+                        };
+
+                        let focused = window
+                            .focused_bin_id()
+                            .and_then(|id| dialog.basalt.interface_ref().get_bin(id));
+
+                        // Only the dialog containing the focused bin handles keys.
+                        let within = match focused.as_ref() {
+                            Some(bin) => {
+                                bin.id() == dialog.backdrop.id()
+                                    || bin
+                                        .ancestors()
+                                        .into_iter()
+                                        .any(|bin| bin.id() == dialog.backdrop.id())
+                            },
+                            None => false,
+                        };
+
+                        if !within {
+                            return Default::default();
+                        }
+
+                        match key {
+                            Qwerty::Tab => {
+                                let reverse = window.is_key_pressed(Qwerty::LShift)
+                                    || window.is_key_pressed(Qwerty::RShift);
+                                dialog.cycle_focus(focused.as_ref(), reverse);
+                            },
+                            _ => {
+                                let index = {
+                                    let state = dialog.state.lock();
+
+                                    focused
+                                        .as_ref()
+                                        .and_then(|focused| {
+                                            state
+                                                .buttons
+                                                .iter()
+                                                .position(|button| button.id() == focused.id())
+                                        })
+                                        .or(state.default_button)
+                                };
+
+                                dialog.close(index);
+                            },
+                        }
+
+                        InputHookCtrl::RetainNoPass
+                    })
+                    .finish()
+                    .unwrap(),
+            );
+        }
+
+        drop(hooks);
+
+        let first = {
+            let state = self.state.lock();
+
+            state
+                .focusable
+                .first()
+                .cloned()
+                .or_else(|| state.default_button.map(|i| state.buttons[i].clone()))
+        };
+
+        if let Some(bin) = first {
+            self.basalt.input_ref().set_bin_focused(&bin);
+        }
+    }
+
+    /// Close the dialog as if the button at the index was pressed.
+    pub fn close(&self, button: Option<usize>) {
+        let overlay = match self.state.lock().overlay.take() {
+            Some(some) => some,
+            None => return,
+        };
+
+        self.basalt.interface_ref().overlays().hide(overlay);
+        self.closed(button);
+    }
+
+    fn closed(&self, button: Option<usize>) {
+        for id in self.hooks.lock().split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+
+        for func in self.on_close.lock().iter_mut() {
+            func(button);
+        }
+    }
+
+    fn cycle_focus(&self, focused: Option<&Arc<Bin>>, reverse: bool) {
+        let order: Vec<_> = {
+            let state = self.state.lock();
+
+            state
+                .focusable
+                .iter()
+                .chain(state.buttons.iter())
+                .cloned()
+                .collect()
+        };
+
+        if order.is_empty() {
+            return;
+        }
+
+        let current =
+            focused.and_then(|focused| order.iter().position(|bin| bin.id() == focused.id()));
+
+        let next = match (current, reverse) {
+            (Some(i), false) => (i + 1) % order.len(),
+            (Some(i), true) => (i + order.len() - 1) % order.len(),
+            (None, false) => 0,
+            (None, true) => order.len() - 1,
+        };
+
+        self.basalt.input_ref().set_bin_focused(&order[next]);
+    }
+
+    fn focus_indicator(self: &Arc<Self>, bin: &Arc<Bin>, unfocused: bin::Color) {
+        for focused in [true, false] {
+            let dialog_wk = Arc::downgrade(self);
+            let bin_wk = Arc::downgrade(bin);
+            let unfocused = unfocused.clone();
+
+            let method = move |_, _: &_| {
+                let (dialog, bin) = match (dialog_wk.upgrade(), bin_wk.upgrade()) {
+                    (Some(dialog), Some(bin)) => (dialog, bin),
+                    _ => return InputHookCtrl::Remove,
+                };
+
+                let color = match focused {
+                    true => dialog.theme.focus_color.clone(),
+                    false => unfocused.clone(),
+                };
+
+                bin.style_update(BinStyle {
+                    border_color_t: Some(color.clone()),
+                    border_color_b: Some(color.clone()),
+                    border_color_l: Some(color.clone()),
+                    border_color_r: Some(color),
+                    ..bin.style_copy()
+                })
+                .expect_valid();
+
+                Default::default()
+            };
+
+            match focused {
+                true => bin.on_focus(method),
+                false => bin.on_focus_lost(method),
+            };
+        }
+    }
+
+    fn layout(&self) {
+        let theme = &self.theme;
+        let state = self.state.lock();
+        let height =
+            theme.title_height + state.content_height + theme.button_height + (theme.pad * 3.0);
+
+        self.panel
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t_pct: Some(50.0),
+                pos_from_t_offset: Some(height / -2.0),
+                pos_from_l_pct: Some(50.0),
+                pos_from_l_offset: Some(theme.width / -2.0),
+                width: Some(theme.width),
+                height: Some(height),
+                back_color: Some(theme.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(theme.border_color.clone()),
+                border_color_b: Some(theme.border_color.clone()),
+                border_color_l: Some(theme.border_color.clone()),
+                border_color_r: Some(theme.border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        // Buttons are right aligned in the order they were added.
+        let mut right = 0.0;
+
+        for button in state.buttons.iter().rev() {
+            let width = button.style_copy().width.unwrap_or(0.0);
+
+            button
+                .style_update(BinStyle {
+                    pos_from_r: Some(right),
+                    ..button.style_copy()
+                })
+                .expect_valid();
+
+            right += width + theme.pad;
+        }
+
+        self.panel.update_children();
+    }
+
+    /// Create a dialog displaying a message with a single accepting button.
+    fn message(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: String,
+        message: String,
+    ) -> Arc<Self> {
+        let text_w = theme.width - (theme.pad * 2.0);
+        let line_h = theme.text_height * 1.3;
+        let lines = ((message.chars().count() as f32 * theme.text_height * 0.6) / text_w)
+            .ceil()
+            .max(1.0)
+            + message.matches('\n').count() as f32;
+
+        let dialog = Self::new(basalt, theme, title);
+
+        dialog
+            .content
+            .style_update(BinStyle {
+                text: message,
+                text_color: Some(dialog.theme.text_color.clone()),
+                text_height: Some(dialog.theme.text_height),
+                ..dialog.content.style_copy()
+            })
+            .expect_valid();
+
+        dialog.set_content_height(lines * line_h);
+        dialog
+    }
+
+    /// Show a message with an accepting button.
+    ///
+    /// The function is called once the dialog is closed.
+    pub fn alert<T, M, F>(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: T,
+        message: M,
+        func: F,
+    ) -> Arc<Self>
+    where
+        T: Into<String>,
+        M: Into<String>,
+        F: FnOnce() + Send + 'static,
+    {
+        let dialog = Self::message(basalt, theme, title.into(), message.into());
+        let accept = dialog.add_button(dialog.theme.accept_label.clone());
+        dialog.set_default_button(accept);
+        dialog.set_cancel_button(accept);
+        let mut func = Some(func);
+
+        dialog.on_close(move |_| {
+            if let Some(func) = func.take() {
+                func();
+            }
+        });
+
+        dialog.show();
+        dialog
+    }
+
+    /// Show a message with accepting & cancelling buttons.
+    ///
+    /// The function is called with `true` if the dialog was accepted.
+    pub fn confirm<T, M, F>(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: T,
+        message: M,
+        func: F,
+    ) -> Arc<Self>
+    where
+        T: Into<String>,
+        M: Into<String>,
+        F: FnOnce(bool) + Send + 'static,
+    {
+        let dialog = Self::message(basalt, theme, title.into(), message.into());
+        let cancel = dialog.add_button(dialog.theme.cancel_label.clone());
+        let accept = dialog.add_button(dialog.theme.accept_label.clone());
+        dialog.set_default_button(accept);
+        dialog.set_cancel_button(cancel);
+        let mut func = Some(func);
+
+        dialog.on_close(move |button| {
+            if let Some(func) = func.take() {
+                func(button == Some(accept));
+            }
+        });
+
+        dialog.show();
+        dialog
+    }
+
+    /// Show a message with a text input and accepting & cancelling buttons.
+    ///
+    /// The function is called with the entered text if the dialog was accepted.
+    pub fn prompt<T, M, I, F>(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: T,
+        message: M,
+        initial: I,
+        func: F,
+    ) -> Arc<Self>
+    where
+        T: Into<String>,
+        M: Into<String>,
+        I: Into<String>,
+        F: FnOnce(Option<String>) + Send + 'static,
+    {
+        let dialog = Self::message(basalt, theme, title.into(), message.into());
+        let theme = &dialog.theme;
+        let input_h = theme.text_height + 12.0;
+        let message_h = dialog.state.lock().content_height;
+        let text = Arc::new(Mutex::new(initial.into()));
+
+        let input = dialog.basalt.interface_ref().new_bin();
+        dialog.content.add_child(input.clone());
+
+        input
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(input_h),
+                pad_l: Some(4.0),
+                back_color: Some(theme.input_back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(theme.border_color.clone()),
+                border_color_b: Some(theme.border_color.clone()),
+                border_color_l: Some(theme.border_color.clone()),
+                border_color_r: Some(theme.border_color.clone()),
+                text: text.lock().clone(),
+                text_color: Some(theme.text_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        dialog.focus_indicator(&input, theme.border_color.clone());
+        dialog.add_focusable(input.clone());
+
+        let input_wk = Arc::downgrade(&input);
+        let input_text = text.clone();
+
+        input.on_character(move |_, _, c| {
+            let input = match input_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            // Enter & Tab are handled by the dialog.
+            if c.0.is_control() && !c.is_backspace() {
+                return Default::default();
+            }
+
+            let mut text = input_text.lock();
+            c.modify_string(&mut text);
+
+            input
+                .style_update(BinStyle {
+                    text: text.clone(),
+                    ..input.style_copy()
+                })
+                .expect_valid();
+
+            Default::default()
+        });
+
+        let cancel = dialog.add_button(dialog.theme.cancel_label.clone());
+        let accept = dialog.add_button(dialog.theme.accept_label.clone());
+        dialog.set_default_button(accept);
+        dialog.set_cancel_button(cancel);
+        dialog.set_content_height(message_h + input_h + dialog.theme.pad);
+        let mut func = Some(func);
+
+        dialog.on_close(move |button| {
+            if let Some(func) = func.take() {
+                func((button == Some(accept)).then(|| text.lock().clone()));
+            }
+        });
+
+        dialog.show();
+        dialog
+    }
+
+    /// Same as `alert`, but the result is received through a channel.
+    pub fn alert_channel<T: Into<String>, M: Into<String>>(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: T,
+        message: M,
+    ) -> Receiver<()> {
+        let (send, recv) = channel::bounded(1);
+
+        Self::alert(basalt, theme, title, message, move || {
+            let _ = send.send(());
+        });
+
+        recv
+    }
+
+    /// Same as `confirm`, but the result is received through a channel.
+    pub fn confirm_channel<T: Into<String>, M: Into<String>>(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: T,
+        message: M,
+    ) -> Receiver<bool> {
+        let (send, recv) = channel::bounded(1);
+
+        Self::confirm(basalt, theme, title, message, move |accepted| {
+            let _ = send.send(accepted);
+        });
+
+        recv
+    }
+
+    /// Same as `prompt`, but the result is received through a channel.
+    pub fn prompt_channel<T: Into<String>, M: Into<String>, I: Into<String>>(
+        basalt: Arc<Basalt>,
+        theme: DialogTheme,
+        title: T,
+        message: M,
+        initial: I,
+    ) -> Receiver<Option<String>> {
+        let (send, recv) = channel::bounded(1);
+
+        Self::prompt(basalt, theme, title, message, initial, move |text| {
+            let _ = send.send(text);
+        });
+
+        recv
+    }
+}
@@ -0,0 +1,934 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::interface::bin::{self, Bin, BinPosition, BinStyle, KeepAlive};
+use crate::interface::overlay::{OverlayID, OverlayLayer};
+use crate::interface::split_pane::{SplitDirection, SplitPane, SplitPaneTheme};
+use crate::interface::tab_container::{TabContainer, TabContainerTheme, TabID};
+use crate::Basalt;
+
+impl KeepAlive for Dock {}
+
+/// Portion of a leaf's size from its edges that splits it when a panel is dropped there.
+const EDGE_ZONE: f32 = 0.25;
+
+/// The arrangement of the panels of a `Dock`.
+///
+/// A layout can be converted into text with `to_string()` and back with `DockLayout::parse`.
+/// The text format has one node per line where children are indented by two spaces:
+///
+/// ```text
+/// split horizontal 0.25
+///   tabs 0 files outline
+///   split vertical 0.7
+///     tabs 0 editor
+///     tabs 1 console output
+/// ```
+///
+/// A `tabs` line has the index of the active panel followed by the names of the panels. Since
+/// names are separated by whitespace, panel names can not contain whitespace.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DockLayout {
+    /// Panels displayed as tabs of a `TabContainer`.
+    Tabs {
+        panels: Vec<String>,
+        /// The index of the panel that is displayed.
+        active: usize,
+    },
+    /// Two layouts displayed in a `SplitPane`.
+    Split {
+        direction: SplitDirection,
+        ratio: f32,
+        first: Box<DockLayout>,
+        second: Box<DockLayout>,
+    },
+}
+
+impl DockLayout {
+    /// Create a layout of a single `TabContainer`.
+    pub fn tabs<N: Into<String>, I: IntoIterator<Item = N>>(panels: I) -> Self {
+        DockLayout::Tabs {
+            panels: panels.into_iter().map(|name| name.into()).collect(),
+            active: 0,
+        }
+    }
+
+    /// Create a layout of a `SplitPane`.
+    pub fn split(
+        direction: SplitDirection,
+        ratio: f32,
+        first: DockLayout,
+        second: DockLayout,
+    ) -> Self {
+        DockLayout::Split {
+            direction,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Parse a layout from the text produced by `to_string()`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let indent = line.len() - line.trim_start_matches(' ').len();
+
+            if indent % 2 != 0 {
+                return Err(format!(
+                    "Line {}: indentation is not a multiple of two.",
+                    i + 1
+                ));
+            }
+
+            lines.push((
+                i + 1,
+                indent / 2,
+                line.split_whitespace().collect::<Vec<_>>(),
+            ));
+        }
+
+        let mut lines = lines.into_iter().peekable();
+        let layout = Self::parse_node(&mut lines, 0)?;
+
+        if let Some((line, ..)) = lines.next() {
+            return Err(format!(
+                "Line {}: unexpected node after the root node.",
+                line
+            ));
+        }
+
+        Ok(layout)
+    }
+
+    fn parse_node<'a, I: Iterator<Item = (usize, usize, Vec<&'a str>)>>(
+        lines: &mut std::iter::Peekable<I>,
+        depth: usize,
+    ) -> Result<Self, String> {
+        let (line, indent, words) = match lines.next() {
+            Some(some) => some,
+            None => return Err(String::from("Unexpected end of layout.")),
+        };
+
+        if indent != depth {
+            return Err(format!(
+                "Line {}: expected an indentation of {} spaces.",
+                line,
+                depth * 2
+            ));
+        }
+
+        match words.first().copied() {
+            Some("tabs") => {
+                let active = words
+                    .get(1)
+                    .ok_or_else(|| format!("Line {}: missing active index.", line))?
+                    .parse::<usize>()
+                    .map_err(|e| format!("Line {}: invalid active index: {}", line, e))?;
+
+                let panels: Vec<String> = words[2..].iter().map(|name| name.to_string()).collect();
+
+                if panels.is_empty() {
+                    return Err(format!("Line {}: tabs without any panels.", line));
+                }
+
+                if active >= panels.len() {
+                    return Err(format!("Line {}: active index is out of range.", line));
+                }
+
+                Ok(DockLayout::Tabs {
+                    panels,
+                    active,
+                })
+            },
+            Some("split") => {
+                if words.len() != 3 {
+                    return Err(format!(
+                        "Line {}: expected 'split <direction> <ratio>'.",
+                        line
+                    ));
+                }
+
+                let direction = match words[1] {
+                    "horizontal" => SplitDirection::Horizontal,
+                    "vertical" => SplitDirection::Vertical,
+                    invalid => {
+                        return Err(format!("Line {}: invalid direction '{}'.", line, invalid))
+                    },
+                };
+
+                let ratio = words[2]
+                    .parse::<f32>()
+                    .map_err(|e| format!("Line {}: invalid ratio: {}", line, e))?;
+
+                if !(0.0..=1.0).contains(&ratio) {
+                    return Err(format!("Line {}: ratio must be from 0.0 to 1.0.", line));
+                }
+
+                let first = Self::parse_node(lines, depth + 1)?;
+                let second = Self::parse_node(lines, depth + 1)?;
+
+                Ok(DockLayout::split(direction, ratio, first, second))
+            },
+            Some(invalid) => Err(format!("Line {}: unknown node '{}'.", line, invalid)),
+            None => unreachable!(),
+        }
+    }
+
+    /// The names of the panels within this layout.
+    pub fn panels(&self) -> Vec<String> {
+        match self {
+            DockLayout::Tabs {
+                panels, ..
+            } => panels.clone(),
+            DockLayout::Split {
+                first,
+                second,
+                ..
+            } => {
+                let mut panels = first.panels();
+                panels.extend(second.panels());
+                panels
+            },
+        }
+    }
+
+    /// Remove a panel, collapsing any nodes left empty.
+    fn without(self, name: &str) -> Option<Self> {
+        match self {
+            DockLayout::Tabs {
+                mut panels,
+                active,
+            } => {
+                let index = match panels.iter().position(|panel| panel == name) {
+                    Some(some) => some,
+                    None => {
+                        return Some(DockLayout::Tabs {
+                            panels,
+                            active,
+                        })
+                    },
+                };
+
+                panels.remove(index);
+
+                if panels.is_empty() {
+                    return None;
+                }
+
+                let active = match active > index {
+                    true => active - 1,
+                    false => active.min(panels.len() - 1),
+                };
+
+                Some(DockLayout::Tabs {
+                    panels,
+                    active,
+                })
+            },
+            DockLayout::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                match (first.without(name), second.without(name)) {
+                    (Some(first), Some(second)) => {
+                        Some(DockLayout::split(direction, ratio, first, second))
+                    },
+                    (Some(remaining), None) | (None, Some(remaining)) => Some(remaining),
+                    (None, None) => None,
+                }
+            },
+        }
+    }
+
+    /// Keep only the panels that `keep` returns `true` for.
+    fn retain<F: Fn(&str) -> bool>(self, keep: F) -> Option<Self> {
+        self.panels()
+            .into_iter()
+            .filter(|name| !keep(name))
+            .try_fold(self, |layout, name| layout.without(&name))
+    }
+
+    /// Insert a panel relative to the leaf containing `anchor`.
+    fn insert(&mut self, anchor: &str, name: String, zone: DropZone) -> bool {
+        match self {
+            DockLayout::Tabs {
+                panels,
+                active,
+            } => {
+                if !panels.iter().any(|panel| panel == anchor) {
+                    return false;
+                }
+
+                let (direction, before) = match zone {
+                    DropZone::Center => {
+                        panels.push(name);
+                        *active = panels.len() - 1;
+                        return true;
+                    },
+                    DropZone::Left => (SplitDirection::Horizontal, true),
+                    DropZone::Right => (SplitDirection::Horizontal, false),
+                    DropZone::Top => (SplitDirection::Vertical, true),
+                    DropZone::Bottom => (SplitDirection::Vertical, false),
+                };
+
+                let existing = self.clone();
+                let new = DockLayout::tabs([name]);
+
+                *self = match before {
+                    true => DockLayout::split(direction, 0.5, new, existing),
+                    false => DockLayout::split(direction, 0.5, existing, new),
+                };
+
+                true
+            },
+            DockLayout::Split {
+                first,
+                second,
+                ..
+            } => first.insert(anchor, name.clone(), zone) || second.insert(anchor, name, zone),
+        }
+    }
+
+    fn first_panel(&self) -> Option<&str> {
+        match self {
+            DockLayout::Tabs {
+                panels, ..
+            } => panels.first().map(|name| name.as_str()),
+            DockLayout::Split {
+                first, ..
+            } => first.first_panel(),
+        }
+    }
+
+    fn fmt_node(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+
+        match self {
+            DockLayout::Tabs {
+                panels,
+                active,
+            } => writeln!(f, "{}tabs {} {}", indent, active, panels.join(" ")),
+            DockLayout::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                let direction = match direction {
+                    SplitDirection::Horizontal => "horizontal",
+                    SplitDirection::Vertical => "vertical",
+                };
+
+                writeln!(f, "{}split {} {}", indent, direction, ratio)?;
+                first.fmt_node(f, depth + 1)?;
+                second.fmt_node(f, depth + 1)
+            },
+        }
+    }
+}
+
+impl fmt::Display for DockLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_node(f, 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockTheme {
+    pub tabs: TabContainerTheme,
+    pub split: SplitPaneTheme,
+    /// Color of the area displayed where a dragged panel will be dropped.
+    pub preview_color: bin::Color,
+}
+
+impl Default for DockTheme {
+    fn default() -> Self {
+        DockTheme {
+            tabs: TabContainerTheme::default(),
+            split: SplitPaneTheme::default(),
+            preview_color: bin::Color::srgb_hex("3a5a8c60"),
+        }
+    }
+}
+
+/// Panels arranged in tabs & split panes that can be rearranged by dragging their tabs.
+///
+/// Dragging a tab outside of its strip and releasing it over another group of tabs either adds
+/// the panel to those tabs or splits them when released near one of their edges. The current
+/// arrangement is available with `layout` and can be saved to & loaded from disk.
+pub struct Dock {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    theme: DockTheme,
+    preview: Arc<Bin>,
+    state: Mutex<State>,
+}
+
+struct State {
+    panels: BTreeMap<String, Panel>,
+    root: Option<Node>,
+    preview: Option<OverlayID>,
+}
+
+#[derive(Clone)]
+struct Panel {
+    title: String,
+    content: Arc<Bin>,
+    closable: bool,
+}
+
+enum Node {
+    Tabs {
+        tabs: Arc<TabContainer>,
+        panels: Vec<(TabID, String)>,
+    },
+    Split {
+        split: Arc<SplitPane>,
+        first: Box<Node>,
+        second: Box<Node>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DropZone {
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Node {
+    fn layout(&self) -> Option<DockLayout> {
+        match self {
+            Node::Tabs {
+                tabs,
+                panels,
+            } => {
+                let selected = tabs.selected();
+                let mut active = 0;
+
+                let names: Vec<String> = tabs
+                    .tabs()
+                    .into_iter()
+                    .filter_map(|id| {
+                        let (_, name) = panels.iter().find(|(tab_id, _)| *tab_id == id)?;
+                        Some((id, name.clone()))
+                    })
+                    .enumerate()
+                    .map(|(i, (id, name))| {
+                        if Some(id) == selected {
+                            active = i;
+                        }
+
+                        name
+                    })
+                    .collect();
+
+                match names.is_empty() {
+                    true => None,
+                    false => {
+                        Some(DockLayout::Tabs {
+                            panels: names,
+                            active,
+                        })
+                    },
+                }
+            },
+            Node::Split {
+                split,
+                first,
+                second,
+            } => {
+                match (first.layout(), second.layout()) {
+                    (Some(first), Some(second)) => {
+                        Some(DockLayout::split(
+                            split.direction(),
+                            split.ratio(),
+                            first,
+                            second,
+                        ))
+                    },
+                    (Some(remaining), None) | (None, Some(remaining)) => Some(remaining),
+                    (None, None) => None,
+                }
+            },
+        }
+    }
+
+    fn leaves(&self, leaves: &mut Vec<(Arc<TabContainer>, Vec<String>)>) {
+        match self {
+            Node::Tabs {
+                tabs,
+                panels,
+            } => {
+                leaves.push((
+                    tabs.clone(),
+                    panels.iter().map(|(_, name)| name.clone()).collect(),
+                ))
+            },
+            Node::Split {
+                first,
+                second,
+                ..
+            } => {
+                first.leaves(leaves);
+                second.leaves(leaves);
+            },
+        }
+    }
+
+    fn container(&self) -> &Arc<Bin> {
+        match self {
+            Node::Tabs {
+                tabs, ..
+            } => &tabs.container,
+            Node::Split {
+                split, ..
+            } => &split.container,
+        }
+    }
+
+    /// Detach the content of the panels so they can be added to new tabs.
+    fn dismantle(&self) {
+        match self {
+            Node::Tabs {
+                tabs,
+                panels,
+            } => {
+                for (id, _) in panels.iter() {
+                    tabs.remove_tab(*id);
+                }
+            },
+            Node::Split {
+                first,
+                second,
+                ..
+            } => {
+                first.dismantle();
+                second.dismantle();
+            },
+        }
+    }
+}
+
+impl Dock {
+    pub fn new(basalt: Arc<Basalt>, theme: DockTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(2);
+        let container = bins.pop().unwrap();
+        let preview = bins.pop().unwrap();
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        preview
+            .style_update(BinStyle {
+                hidden: Some(true),
+                back_color: Some(theme.preview_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        Arc::new(Dock {
+            basalt,
+            container,
+            theme,
+            preview,
+            state: Mutex::new(State {
+                panels: BTreeMap::new(),
+                root: None,
+                preview: None,
+            }),
+        })
+    }
+
+    /// Add a panel to the dock.
+    ///
+    /// The panel is added as a tab to the first group of tabs. If a panel with the same name
+    /// exists, it is replaced.
+    ///
+    /// # Notes
+    /// - Panel names can not contain whitespace, it is replaced with `_`.
+    /// - The content's position & size fields are controlled by the dock.
+    pub fn add_panel<N: Into<String>, T: Into<String>>(
+        self: &Arc<Self>,
+        name: N,
+        title: T,
+        content: Arc<Bin>,
+        closable: bool,
+    ) {
+        let name = sanitize_name(name.into());
+        self.remove_panel(&name);
+
+        self.state.lock().panels.insert(
+            name.clone(),
+            Panel {
+                title: title.into(),
+                content,
+                closable,
+            },
+        );
+
+        self.show_panel(&name);
+    }
+
+    /// Remove a panel from the dock returning its content.
+    pub fn remove_panel(self: &Arc<Self>, name: &str) -> Option<Arc<Bin>> {
+        let layout = self.layout();
+        let panel = self.state.lock().panels.remove(name)?;
+        self.rebuild(layout.and_then(|layout| layout.without(name)));
+        panel.content.hidden(Some(true));
+        Some(panel.content)
+    }
+
+    /// The names of the panels within the dock including ones that are closed.
+    pub fn panels(&self) -> Vec<String> {
+        self.state.lock().panels.keys().cloned().collect()
+    }
+
+    /// Display a panel, reopening it if it was closed.
+    ///
+    /// Returns `false` if there isn't a panel with the name.
+    pub fn show_panel(self: &Arc<Self>, name: &str) -> bool {
+        if !self.state.lock().panels.contains_key(name) {
+            return false;
+        }
+
+        let mut layout = self.layout();
+
+        let shown = layout
+            .as_ref()
+            .map(|layout| layout.panels().iter().any(|panel| panel == name))
+            .unwrap_or(false);
+
+        if shown {
+            let mut leaves = Vec::new();
+
+            if let Some(root) = self.state.lock().root.as_ref() {
+                root.leaves(&mut leaves);
+            }
+
+            for (tabs, _) in leaves {
+                for id in tabs.tabs() {
+                    if self.find_panel(&tabs, id).as_deref() == Some(name) {
+                        tabs.select(id);
+                    }
+                }
+            }
+
+            return true;
+        }
+
+        layout = match layout {
+            Some(mut layout) => {
+                let anchor = layout.first_panel().unwrap().to_string();
+                layout.insert(&anchor, name.to_string(), DropZone::Center);
+                Some(layout)
+            },
+            None => Some(DockLayout::tabs([name])),
+        };
+
+        self.rebuild(layout);
+        true
+    }
+
+    /// The current arrangement of the panels.
+    ///
+    /// Returns `None` if no panels are displayed.
+    pub fn layout(&self) -> Option<DockLayout> {
+        self.state
+            .lock()
+            .root
+            .as_ref()
+            .and_then(|root| root.layout())
+    }
+
+    /// Arrange the panels with the layout.
+    ///
+    /// Panels within the layout that haven't been added are ignored.
+    pub fn set_layout(self: &Arc<Self>, layout: DockLayout) {
+        let layout = {
+            let state = self.state.lock();
+            layout.retain(|name| state.panels.contains_key(name))
+        };
+
+        self.rebuild(layout);
+    }
+
+    /// Save the current layout to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let text = self
+            .layout()
+            .map(|layout| layout.to_string())
+            .unwrap_or_default();
+
+        std::fs::write(path, text).map_err(|e| format!("Failed to write layout: {}", e))
+    }
+
+    /// Load a layout from a file saved with `save`.
+    pub fn load<P: AsRef<Path>>(self: &Arc<Self>, path: P) -> Result<(), String> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read layout: {}", e))?;
+
+        if text.trim().is_empty() {
+            self.rebuild(None);
+            return Ok(());
+        }
+
+        self.set_layout(DockLayout::parse(&text)?);
+        Ok(())
+    }
+
+    fn find_panel(&self, tabs: &Arc<TabContainer>, id: TabID) -> Option<String> {
+        let content = tabs.content(id)?;
+
+        self.state
+            .lock()
+            .panels
+            .iter()
+            .find(|(_, panel)| panel.content.id() == content.id())
+            .map(|(name, _)| name.clone())
+    }
+
+    fn rebuild(self: &Arc<Self>, layout: Option<DockLayout>) {
+        let (old, panels) = {
+            let mut state = self.state.lock();
+            (state.root.take(), state.panels.clone())
+        };
+
+        if let Some(old) = old {
+            old.dismantle();
+            self.container.remove_child(old.container());
+        }
+
+        for panel in panels.values() {
+            panel.content.hidden(Some(true));
+        }
+
+        let root = layout.map(|layout| self.build(&layout, &self.container, &panels));
+        self.state.lock().root = root;
+        self.container.update_children();
+    }
+
+    fn build(
+        self: &Arc<Self>,
+        layout: &DockLayout,
+        parent: &Arc<Bin>,
+        panels: &BTreeMap<String, Panel>,
+    ) -> Node {
+        match layout {
+            DockLayout::Tabs {
+                panels: names,
+                active,
+            } => {
+                let tabs = TabContainer::new(
+                    self.basalt.clone(),
+                    self.theme.tabs.clone(),
+                    Some(parent.clone()),
+                );
+
+                let mut ids = Vec::new();
+
+                for name in names.iter() {
+                    if let Some(panel) = panels.get(name) {
+                        let id = tabs.add_tab(
+                            panel.title.clone(),
+                            panel.content.clone(),
+                            panel.closable,
+                        );
+
+                        ids.push((id, name.clone()));
+                    }
+                }
+
+                if let Some((id, _)) = ids.get(*active) {
+                    tabs.select(*id);
+                }
+
+                let dock_wk = Arc::downgrade(self);
+
+                tabs.on_close(move |_, content| {
+                    if let Some(dock) = dock_wk.upgrade() {
+                        content.hidden(Some(true));
+                        let layout = dock.layout();
+                        dock.rebuild(layout);
+                    }
+                });
+
+                let dock_wk = Arc::downgrade(self);
+                let names = ids.clone();
+
+                tabs.on_drag_out(move |id, cursor, released| {
+                    if let Some(dock) = dock_wk.upgrade() {
+                        if let Some((_, name)) = names.iter().find(|(tab_id, _)| *tab_id == id) {
+                            dock.drag_panel(name, cursor, released);
+                        }
+                    }
+                });
+
+                Node::Tabs {
+                    tabs,
+                    panels: ids,
+                }
+            },
+            DockLayout::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                let split = SplitPane::new(
+                    self.basalt.clone(),
+                    self.theme.split.clone(),
+                    Some(parent.clone()),
+                    *direction,
+                );
+
+                split.set_ratio(*ratio);
+                let first = Box::new(self.build(first, &split.first, panels));
+                let second = Box::new(self.build(second, &split.second, panels));
+
+                Node::Split {
+                    split,
+                    first,
+                    second,
+                }
+            },
+        }
+    }
+
+    /// Find the group of tabs under the cursor and the zone within it.
+    fn drop_target(&self, cursor: [f32; 2]) -> Option<(Vec<String>, DropZone, [f32; 4])> {
+        let mut leaves = Vec::new();
+        self.state.lock().root.as_ref()?.leaves(&mut leaves);
+
+        leaves.into_iter().find_map(|(tabs, names)| {
+            let post = tabs.container.post_update();
+            let [l, t] = post.tli;
+            let [r, b] = post.bri;
+
+            if cursor[0] < l || cursor[0] > r || cursor[1] < t || cursor[1] > b {
+                return None;
+            }
+
+            let (w, h) = (r - l, b - t);
+            let fx = (cursor[0] - l) / w;
+            let fy = (cursor[1] - t) / h;
+
+            let (zone, area) = if fx < EDGE_ZONE {
+                (DropZone::Left, [l, t, w / 2.0, h])
+            } else if fx > 1.0 - EDGE_ZONE {
+                (DropZone::Right, [l + (w / 2.0), t, w / 2.0, h])
+            } else if fy < EDGE_ZONE {
+                (DropZone::Top, [l, t, w, h / 2.0])
+            } else if fy > 1.0 - EDGE_ZONE {
+                (DropZone::Bottom, [l, t + (h / 2.0), w, h / 2.0])
+            } else {
+                (DropZone::Center, [l, t, w, h])
+            };
+
+            Some((names, zone, area))
+        })
+    }
+
+    fn drag_panel(self: &Arc<Self>, name: &str, cursor: [f32; 2], released: bool) {
+        let target = self.drop_target(cursor).filter(|(names, zone, _)| {
+            // Dropping a panel onto the center of its own tabs or splitting tabs that only
+            // contain the panel wouldn't change anything.
+            !names.iter().any(|panel| panel == name)
+                || (*zone != DropZone::Center && names.len() > 1)
+        });
+
+        if released {
+            self.hide_preview();
+
+            let (names, zone, _) = match target {
+                Some(some) => some,
+                None => return,
+            };
+
+            let anchor = match names.into_iter().find(|panel| panel != name) {
+                Some(some) => some,
+                None => return,
+            };
+
+            let mut layout = match self.layout().and_then(|layout| layout.without(name)) {
+                Some(some) => some,
+                None => return,
+            };
+
+            if layout.insert(&anchor, name.to_string(), zone) {
+                self.rebuild(Some(layout));
+            }
+
+            return;
+        }
+
+        let [l, t, w, h] = match target {
+            Some((_, _, area)) => area,
+            None => {
+                self.hide_preview();
+                return;
+            },
+        };
+
+        self.preview
+            .style_update(BinStyle {
+                pos_from_t: Some(t),
+                pos_from_l: Some(l),
+                width: Some(w),
+                height: Some(h),
+                ..self.preview.style_copy()
+            })
+            .expect_valid();
+
+        let overlays = self.basalt.interface_ref().overlays();
+        let mut state = self.state.lock();
+
+        let shown = state
+            .preview
+            .map(|id| overlays.is_shown(id))
+            .unwrap_or(false);
+
+        if !shown {
+            state.preview = Some(overlays.show(self.preview.clone(), OverlayLayer::Popup));
+        }
+    }
+
+    fn hide_preview(&self) {
+        if let Some(id) = self.state.lock().preview.take() {
+            self.basalt.interface_ref().overlays().hide(id);
+        }
+    }
+}
+
+fn sanitize_name(name: String) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join("_")
+}
@@ -0,0 +1,812 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, InputHookID, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextVertAlign, TextWrap,
+};
+use crate::interface::overlay::{
+    OverlayAnchor, OverlayID, OverlayLayer, Placement, PlacementAlign,
+};
+use crate::Basalt;
+
+impl KeepAlive for Dropdown {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropdownTheme {
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    pub text_color: bin::Color,
+    pub arrow_color: bin::Color,
+    /// Color of the popup list.
+    pub popup_color: bin::Color,
+    /// Color of the highlighted item in the popup list.
+    pub highlight_color: bin::Color,
+    pub text_height: f32,
+    pub item_height: f32,
+    /// Maximum amount of items displayed before the popup list scrolls.
+    pub max_visible: usize,
+}
+
+impl Default for DropdownTheme {
+    fn default() -> Self {
+        DropdownTheme {
+            back_color: bin::Color::srgb_hex("35353c"),
+            border_color: bin::Color::srgb_hex("222227"),
+            text_color: bin::Color::srgb_hex("e0e0e0"),
+            arrow_color: bin::Color::srgb_hex("c0c0c0"),
+            popup_color: bin::Color::srgb_hex("2a2a30"),
+            highlight_color: bin::Color::srgb_hex("3a5a8c"),
+            text_height: 12.0,
+            item_height: 22.0,
+            max_visible: 8,
+        }
+    }
+}
+
+/// A dropdown that displays a popup list of items to select from.
+///
+/// # Keyboard Navigation
+/// Once clicked, the dropdown will have focus.
+/// - `ArrowUp` & `ArrowDown` move the highlighted item, opening the popup list if closed.
+/// - `Enter` selects the highlighted item.
+/// - `Esc` closes the popup list.
+/// - Typing filters the items to those containing the typed text.
+///
+/// # Combo Box
+/// Dropdowns created with `new_combo_box` are editable. The typed text is the value of the
+/// dropdown and is used to filter the items.
+pub struct Dropdown {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub popup: Arc<Bin>,
+    arrow: Arc<Bin>,
+    theme: DropdownTheme,
+    editable: bool,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(Option<usize>, &str) + Send + 'static>>>,
+    hooks: Mutex<Vec<InputHookID>>,
+}
+
+struct State {
+    items: Vec<String>,
+    selected: Option<usize>,
+    text: String,
+    query: String,
+    open: bool,
+    overlay: Option<OverlayID>,
+    filtered: Vec<usize>,
+    highlighted: Option<usize>,
+    rows: Vec<Arc<Bin>>,
+}
+
+impl Drop for Dropdown {
+    fn drop(&mut self) {
+        let mut hooks = self.hooks.lock();
+
+        for id in hooks.split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+    }
+}
+
+impl Dropdown {
+    pub fn new(basalt: Arc<Basalt>, theme: DropdownTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        Self::create(basalt, theme, parent, false)
+    }
+
+    /// Create an editable dropdown.
+    pub fn new_combo_box(
+        basalt: Arc<Basalt>,
+        theme: DropdownTheme,
+        parent: Option<Arc<Bin>>,
+    ) -> Arc<Self> {
+        Self::create(basalt, theme, parent, true)
+    }
+
+    fn create(
+        basalt: Arc<Basalt>,
+        theme: DropdownTheme,
+        parent: Option<Arc<Bin>>,
+        editable: bool,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(3);
+        let container = bins.pop().unwrap();
+        let arrow = bins.pop().unwrap();
+        let popup = bins.pop().unwrap();
+        container.add_child(arrow.clone());
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(160.0),
+                height: Some(24.0),
+                pad_l: Some(6.0),
+                pad_r: Some(20.0),
+                back_color: Some(theme.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(theme.border_color.clone()),
+                border_color_b: Some(theme.border_color.clone()),
+                border_color_l: Some(theme.border_color.clone()),
+                border_color_r: Some(theme.border_color.clone()),
+                text_color: Some(theme.text_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        popup
+            .style_update(BinStyle {
+                position: Some(BinPosition::Window),
+                hidden: Some(true),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(0.0),
+                height: Some(0.0),
+                back_color: Some(theme.popup_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(theme.border_color.clone()),
+                border_color_b: Some(theme.border_color.clone()),
+                border_color_l: Some(theme.border_color.clone()),
+                border_color_r: Some(theme.border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let dropdown = Arc::new(Dropdown {
+            basalt,
+            container,
+            arrow,
+            popup,
+            theme,
+            editable,
+            state: Mutex::new(State {
+                items: Vec::new(),
+                selected: None,
+                text: String::new(),
+                query: String::new(),
+                open: false,
+                overlay: None,
+                filtered: Vec::new(),
+                highlighted: None,
+                rows: Vec::new(),
+            }),
+            on_change: Mutex::new(Vec::new()),
+            hooks: Mutex::new(Vec::new()),
+        });
+
+        dropdown.update_container();
+
+        for bin in [dropdown.container.clone(), dropdown.arrow.clone()] {
+            let dropdown_wk = Arc::downgrade(&dropdown);
+
+            bin.on_press(MouseButton::Left, move |_, _, _| {
+                let dropdown = match dropdown_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                if dropdown.is_open() {
+                    dropdown.close();
+                } else {
+                    dropdown.open();
+                }
+
+                dropdown
+                    .basalt
+                    .input_ref()
+                    .set_bin_focused(&dropdown.container);
+                Default::default()
+            });
+        }
+
+        for key in [
+            Qwerty::ArrowUp,
+            Qwerty::ArrowDown,
+            Qwerty::Enter,
+            Qwerty::Esc,
+        ] {
+            let dropdown_wk = Arc::downgrade(&dropdown);
+
+            dropdown.container.on_press(key, move |_, _, _| {
+                let dropdown = match dropdown_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                match key {
+                    Qwerty::ArrowUp => dropdown.move_highlight(-1),
+                    Qwerty::ArrowDown => dropdown.move_highlight(1),
+                    Qwerty::Enter => dropdown.accept(),
+                    Qwerty::Esc => dropdown.close(),
+                    _ => unreachable!(),
+                }
+
+                Default::default()
+            });
+        }
+
+        let dropdown_wk = Arc::downgrade(&dropdown);
+
+        dropdown.container.on_character(move |_, _, c| {
+            let dropdown = match dropdown_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if c.is_new_line() || (c.0.is_control() && !c.is_backspace()) {
+                return Default::default();
+            }
+
+            dropdown.type_char(c.0);
+            Default::default()
+        });
+
+        let dropdown_wk = Arc::downgrade(&dropdown);
+
+        dropdown.container.on_focus_lost(move |_, window| {
+            let dropdown = match dropdown_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            let [x, y] = window.cursor_pos();
+
+            // Focus is lost to the items or the arrow when they are clicked.
+            if !dropdown.popup.mouse_inside(x, y) && !dropdown.container.mouse_inside(x, y) {
+                dropdown.close();
+            }
+
+            Default::default()
+        });
+
+        let dropdown_wk = Arc::downgrade(&dropdown);
+
+        dropdown.popup.attach_input_hook(
+            dropdown
+                .basalt
+                .input_ref()
+                .hook()
+                .bin(&dropdown.popup)
+                .on_scroll()
+                .enable_smooth(true)
+                .call(move |_, _, amt, _| {
+                    let dropdown = match dropdown_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    let max = dropdown.popup.calc_vert_overflow();
+                    let scroll_y = dropdown.popup.style().scroll_y.unwrap_or(0.0);
+                    dropdown.set_popup_scroll((scroll_y + amt).clamp(0.0, max.max(0.0)));
+                    Default::default()
+                })
+                .finish()
+                .unwrap(),
+        );
+
+        // Clicks outside of the popup list & `Esc` are handled by the overlay it is shown in.
+        let window = dropdown.basalt.window();
+        let dropdown_wk = Arc::downgrade(&dropdown);
+
+        let window_focus_hook = dropdown
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_focus_lost()
+            .call(move |_, _| {
+                match dropdown_wk.upgrade() {
+                    Some(dropdown) => {
+                        dropdown.close();
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            })
+            .finish()
+            .unwrap();
+
+        dropdown.hooks.lock().push(window_focus_hook);
+        dropdown
+    }
+
+    /// Set the items of the dropdown. This clears the selection.
+    pub fn set_items<S: Into<String>, I: IntoIterator<Item = S>>(self: &Arc<Self>, items: I) {
+        {
+            let mut state = self.state.lock();
+            state.items = items.into_iter().map(|item| item.into()).collect();
+            state.selected = None;
+
+            if !self.editable {
+                state.text.clear();
+            }
+        }
+
+        self.update_container();
+
+        if self.is_open() {
+            self.refresh_popup();
+        }
+    }
+
+    pub fn items(&self) -> Vec<String> {
+        self.state.lock().items.clone()
+    }
+
+    /// Index of the selected item.
+    ///
+    /// # Notes
+    /// - For combo boxes this is `None` if the text was edited after selecting an item.
+    pub fn selected(&self) -> Option<usize> {
+        self.state.lock().selected
+    }
+
+    /// Select an item by its index. `None` clears the selection.
+    pub fn select(&self, index: Option<usize>) {
+        let text = {
+            let mut state = self.state.lock();
+
+            let index = match index {
+                Some(index) if index < state.items.len() => Some(index),
+                _ => None,
+            };
+
+            state.selected = index;
+
+            match index {
+                Some(index) => state.text = state.items[index].clone(),
+                None => {
+                    if !self.editable {
+                        state.text.clear();
+                    }
+                },
+            }
+
+            state.text.clone()
+        };
+
+        self.update_container();
+        self.call_on_change(index, &text);
+    }
+
+    /// The text of the selected item, or for combo boxes the entered text.
+    pub fn text(&self) -> String {
+        self.state.lock().text.clone()
+    }
+
+    /// Set the text of a combo box.
+    ///
+    /// # Notes
+    /// - Does nothing if the dropdown isn't editable.
+    pub fn set_text<T: Into<String>>(&self, text: T) {
+        if !self.editable {
+            return;
+        }
+
+        let (selected, text) = {
+            let mut state = self.state.lock();
+            state.text = text.into();
+            state.selected = state.items.iter().position(|item| *item == state.text);
+            (state.selected, state.text.clone())
+        };
+
+        self.update_container();
+        self.call_on_change(selected, &text);
+    }
+
+    /// Add a function to be called when the selected item or the text of a combo box changes.
+    pub fn on_change<F: FnMut(Option<usize>, &str) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state.lock().open
+    }
+
+    /// Open the popup list.
+    pub fn open(self: &Arc<Self>) {
+        {
+            let mut state = self.state.lock();
+
+            if state.open {
+                return;
+            }
+
+            state.open = true;
+            state.query.clear();
+            state.highlighted = None;
+        }
+
+        self.refresh_popup();
+    }
+
+    /// Close the popup list.
+    pub fn close(&self) {
+        let overlay = {
+            let mut state = self.state.lock();
+
+            if !state.open {
+                return;
+            }
+
+            state.open = false;
+            state.query.clear();
+            state.highlighted = None;
+            state.overlay.take()
+        };
+
+        if let Some(overlay) = overlay {
+            self.basalt.interface_ref().overlays().hide(overlay);
+        }
+
+        self.update_container();
+    }
+
+    fn call_on_change(&self, selected: Option<usize>, text: &str) {
+        for func in self.on_change.lock().iter_mut() {
+            func(selected, text);
+        }
+    }
+
+    fn type_char(self: &Arc<Self>, c: char) {
+        let edited = {
+            let mut state = self.state.lock();
+
+            if self.editable {
+                if c == '\x08' {
+                    state.text.pop();
+                } else {
+                    state.text.push(c);
+                }
+
+                state.selected = state.items.iter().position(|item| *item == state.text);
+                Some((state.selected, state.text.clone()))
+            } else {
+                if c == '\x08' {
+                    state.query.pop();
+                } else {
+                    state.query.push(c);
+                }
+
+                None
+            }
+        };
+
+        if !self.is_open() {
+            self.open();
+        }
+
+        self.refresh_popup();
+
+        if let Some((selected, text)) = edited {
+            self.call_on_change(selected, &text);
+        }
+    }
+
+    fn move_highlight(self: &Arc<Self>, amount: isize) {
+        if !self.is_open() {
+            self.open();
+        }
+
+        {
+            let mut state = self.state.lock();
+
+            if state.filtered.is_empty() {
+                return;
+            }
+
+            let last = state.filtered.len() as isize - 1;
+
+            state.highlighted = Some(match state.highlighted {
+                Some(highlighted) => (highlighted as isize + amount).clamp(0, last) as usize,
+                None if amount < 0 => last as usize,
+                None => 0,
+            });
+        }
+
+        self.update_rows();
+        self.scroll_to_highlighted();
+    }
+
+    fn accept(self: &Arc<Self>) {
+        let index = {
+            let state = self.state.lock();
+
+            if !state.open {
+                drop(state);
+                self.open();
+                return;
+            }
+
+            state
+                .highlighted
+                .and_then(|highlighted| state.filtered.get(highlighted).cloned())
+        };
+
+        if let Some(index) = index {
+            self.select(Some(index));
+        }
+
+        self.close();
+    }
+
+    fn update_container(&self) {
+        let state = self.state.lock();
+
+        let text = match state.query.is_empty() {
+            true => state.text.clone(),
+            false => state.query.clone(),
+        };
+
+        self.container
+            .style_update(BinStyle {
+                text,
+                ..self.container.style_copy()
+            })
+            .expect_valid();
+
+        let custom_verts = match state.open {
+            false => {
+                vec![
+                    BinVert {
+                        position: (14.0, 8.0, 0),
+                        color: self.theme.arrow_color.clone(),
+                    },
+                    BinVert {
+                        position: (6.0, 8.0, 0),
+                        color: self.theme.arrow_color.clone(),
+                    },
+                    BinVert {
+                        position: (10.0, 13.0, 0),
+                        color: self.theme.arrow_color.clone(),
+                    },
+                ]
+            },
+            true => {
+                vec![
+                    BinVert {
+                        position: (10.0, 7.0, 0),
+                        color: self.theme.arrow_color.clone(),
+                    },
+                    BinVert {
+                        position: (6.0, 12.0, 0),
+                        color: self.theme.arrow_color.clone(),
+                    },
+                    BinVert {
+                        position: (14.0, 12.0, 0),
+                        color: self.theme.arrow_color.clone(),
+                    },
+                ]
+            },
+        };
+
+        self.arrow
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t_pct: Some(50.0),
+                pos_from_t_offset: Some(-10.0),
+                pos_from_r: Some(0.0),
+                width: Some(20.0),
+                height: Some(20.0),
+                custom_verts,
+                ..BinStyle::default()
+            })
+            .expect_valid();
+    }
+
+    fn refresh_popup(self: &Arc<Self>) {
+        let mut state = self.state.lock();
+
+        if !state.open {
+            return;
+        }
+
+        let filter = match self.editable {
+            true => state.text.to_lowercase(),
+            false => state.query.to_lowercase(),
+        };
+
+        state.filtered = state
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| filter.is_empty() || item.to_lowercase().contains(&filter))
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some(highlighted) = state.highlighted {
+            if highlighted >= state.filtered.len() {
+                state.highlighted = None;
+            }
+        }
+
+        if state.highlighted.is_none() && !filter.is_empty() && !state.filtered.is_empty() {
+            state.highlighted = Some(0);
+        }
+
+        while state.rows.len() < state.filtered.len() {
+            let row = self.basalt.interface_ref().new_bin();
+            self.popup.add_child(row.clone());
+
+            // Rows are reused, so the item they represent is looked up when used.
+            let row_i = state.rows.len();
+            let dropdown_wk = Arc::downgrade(self);
+
+            row.on_press(MouseButton::Left, move |_, _, _| {
+                let dropdown = match dropdown_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let index = dropdown.state.lock().filtered.get(row_i).cloned();
+
+                if let Some(index) = index {
+                    dropdown.select(Some(index));
+                }
+
+                dropdown.close();
+                dropdown
+                    .basalt
+                    .input_ref()
+                    .set_bin_focused(&dropdown.container);
+                Default::default()
+            });
+
+            let dropdown_wk = Arc::downgrade(self);
+
+            row.on_enter(move |_, _| {
+                let dropdown = match dropdown_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                dropdown.state.lock().highlighted = Some(row_i);
+                dropdown.update_rows();
+                Default::default()
+            });
+
+            state.rows.push(row);
+        }
+
+        drop(state);
+        self.update_rows();
+        self.place_popup();
+        self.update_container();
+    }
+
+    fn update_rows(&self) {
+        let state = self.state.lock();
+
+        for (i, row) in state.rows.iter().enumerate() {
+            match state.filtered.get(i) {
+                Some(index) => {
+                    row.style_update(BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t: Some(i as f32 * self.theme.item_height),
+                        pos_from_l: Some(0.0),
+                        pos_from_r: Some(0.0),
+                        height: Some(self.theme.item_height),
+                        pad_l: Some(6.0),
+                        back_color: match state.highlighted == Some(i) {
+                            true => Some(self.theme.highlight_color.clone()),
+                            false => None,
+                        },
+                        text: state.items[*index].clone(),
+                        text_color: Some(self.theme.text_color.clone()),
+                        text_height: Some(self.theme.text_height),
+                        text_vert_align: Some(TextVertAlign::Center),
+                        text_wrap: Some(TextWrap::None),
+                        ..BinStyle::default()
+                    })
+                    .expect_valid();
+                },
+                None => {
+                    row.style_update(BinStyle {
+                        position: Some(BinPosition::Parent),
+                        hidden: Some(true),
+                        pos_from_t: Some(0.0),
+                        pos_from_l: Some(0.0),
+                        width: Some(0.0),
+                        height: Some(0.0),
+                        ..BinStyle::default()
+                    })
+                    .expect_valid();
+                },
+            }
+        }
+    }
+
+    fn place_popup(self: &Arc<Self>) {
+        let (count, overlay) = {
+            let state = self.state.lock();
+            (state.filtered.len(), state.overlay)
+        };
+
+        let post = self.container.post_update();
+        let height = count.min(self.theme.max_visible).max(1) as f32 * self.theme.item_height;
+
+        self.popup
+            .style_update(BinStyle {
+                width: Some(post.tro[0] - post.tlo[0] - 2.0),
+                height: Some(height),
+                scroll_y: Some(0.0),
+                ..self.popup.style_copy()
+            })
+            .expect_valid();
+
+        let overlays = self.basalt.interface_ref().overlays();
+
+        if let Some(overlay) = overlay {
+            overlays.reposition(overlay);
+            return;
+        }
+
+        // The overlay flips the popup above the dropdown if there isn't enough space below it.
+        let overlay = overlays.show_anchored(
+            self.popup.clone(),
+            OverlayLayer::Popup,
+            OverlayAnchor::Bin(self.container.clone()),
+            Placement::Below,
+            PlacementAlign::Start,
+        );
+
+        let dropdown_wk = Arc::downgrade(self);
+
+        overlays.on_dismiss(overlay, move || {
+            if let Some(dropdown) = dropdown_wk.upgrade() {
+                dropdown.close();
+            }
+        });
+
+        self.state.lock().overlay = Some(overlay);
+    }
+
+    fn set_popup_scroll(&self, scroll_y: f32) {
+        self.popup
+            .style_update(BinStyle {
+                scroll_y: Some(scroll_y),
+                ..self.popup.style_copy()
+            })
+            .expect_valid();
+
+        self.popup.update_children();
+    }
+
+    fn scroll_to_highlighted(&self) {
+        let highlighted = match self.state.lock().highlighted {
+            Some(some) => some,
+            None => return,
+        };
+
+        let top = highlighted as f32 * self.theme.item_height;
+        let bottom = top + self.theme.item_height;
+        let post = self.popup.post_update();
+        let view_h = post.bli[1] - post.tli[1];
+        let scroll_y = self.popup.style().scroll_y.unwrap_or(0.0);
+
+        if top < scroll_y {
+            self.set_popup_scroll(top);
+        } else if bottom > scroll_y + view_h {
+            self.set_popup_scroll(bottom - view_h);
+        }
+    }
+}
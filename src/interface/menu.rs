@@ -0,0 +1,1303 @@
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interface::overlay::{
+    OverlayAnchor, OverlayID, OverlayLayer, Placement, PlacementAlign,
+};
+use crate::interval::IntvlHookCtrl;
+use crate::Basalt;
+
+impl KeepAlive for Menu {}
+impl KeepAlive for MenuBar {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuTheme {
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    pub text_color: bin::Color,
+    /// Color of the text of disabled items.
+    pub disabled_color: bin::Color,
+    /// Color of the shortcut hint text.
+    pub shortcut_color: bin::Color,
+    /// Color of the highlighted item.
+    pub highlight_color: bin::Color,
+    pub separator_color: bin::Color,
+    /// Color of check & radio marks and submenu arrows.
+    pub mark_color: bin::Color,
+    pub text_height: f32,
+    pub item_height: f32,
+    pub separator_height: f32,
+    /// Minimum width of a menu.
+    pub min_width: f32,
+    /// How long the cursor has to rest on an item before its submenu is opened.
+    pub submenu_delay: Duration,
+    /// Color of a `MenuBar`.
+    pub bar_color: bin::Color,
+    /// Height of a `MenuBar`.
+    pub bar_height: f32,
+}
+
+impl Default for MenuTheme {
+    fn default() -> Self {
+        MenuTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            border_color: bin::Color::srgb_hex("4a4a52"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            disabled_color: bin::Color::srgb_hex("7a7a80"),
+            shortcut_color: bin::Color::srgb_hex("a0a0a8"),
+            highlight_color: bin::Color::srgb_hex("3a5a8c"),
+            separator_color: bin::Color::srgb_hex("4a4a52"),
+            mark_color: bin::Color::srgb_hex("e6e6e6"),
+            text_height: 12.0,
+            item_height: 22.0,
+            separator_height: 9.0,
+            min_width: 140.0,
+            submenu_delay: Duration::from_millis(300),
+            bar_color: bin::Color::srgb_hex("232328"),
+            bar_height: 24.0,
+        }
+    }
+}
+
+/// An item of a `Menu`.
+///
+/// ```no_run
+/// let menu = Menu::new(
+///     basalt.clone(),
+///     MenuTheme::default(),
+///     vec![
+///         MenuItem::action("Open", || println!("Open")).shortcut("Ctrl+O"),
+///         MenuItem::separator(),
+///         MenuItem::check("Word Wrap", true, |checked| {
+///             println!("Word Wrap: {}", checked)
+///         }),
+///         MenuItem::submenu(
+///             "Recent",
+///             vec![MenuItem::action("a.txt", || ()).enabled(false)],
+///         ),
+///     ],
+/// );
+/// ```
+pub struct MenuItem {
+    label: String,
+    shortcut: Option<String>,
+    enabled: bool,
+    kind: MenuItemKind,
+}
+
+enum MenuItemKind {
+    Action(Arc<dyn Fn() + Send + Sync>),
+    Check {
+        checked: bool,
+        on_toggle: Arc<dyn Fn(bool) + Send + Sync>,
+    },
+    Radio {
+        checked: bool,
+        on_select: Arc<dyn Fn() + Send + Sync>,
+    },
+    Submenu(Vec<MenuItem>),
+    Separator,
+}
+
+impl MenuItem {
+    /// An item that calls the function when selected.
+    pub fn action<L: Into<String>, F: Fn() + Send + Sync + 'static>(label: L, func: F) -> Self {
+        Self::with_kind(label.into(), MenuItemKind::Action(Arc::new(func)))
+    }
+
+    /// An item that toggles a check mark when selected. The function is called with the new state.
+    pub fn check<L: Into<String>, F: Fn(bool) + Send + Sync + 'static>(
+        label: L,
+        checked: bool,
+        func: F,
+    ) -> Self {
+        Self::with_kind(
+            label.into(),
+            MenuItemKind::Check {
+                checked,
+                on_toggle: Arc::new(func),
+            },
+        )
+    }
+
+    /// An item that is checked when selected, unchecking other radio items of its group.
+    ///
+    /// A group is made up of adjacent radio items.
+    pub fn radio<L: Into<String>, F: Fn() + Send + Sync + 'static>(
+        label: L,
+        checked: bool,
+        func: F,
+    ) -> Self {
+        Self::with_kind(
+            label.into(),
+            MenuItemKind::Radio {
+                checked,
+                on_select: Arc::new(func),
+            },
+        )
+    }
+
+    /// An item that opens a nested menu.
+    pub fn submenu<L: Into<String>>(label: L, items: Vec<MenuItem>) -> Self {
+        Self::with_kind(label.into(), MenuItemKind::Submenu(items))
+    }
+
+    /// A line separating groups of items.
+    pub fn separator() -> Self {
+        Self::with_kind(String::new(), MenuItemKind::Separator)
+    }
+
+    /// Set the shortcut hint displayed on the right side of the item.
+    ///
+    /// # Notes
+    /// - This is only a hint. The shortcut itself should be registered with `Shortcuts`.
+    pub fn shortcut<S: Into<String>>(mut self, shortcut: S) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Set if the item can be selected.
+    ///
+    /// **Default**: `true`
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    fn with_kind(label: String, kind: MenuItemKind) -> Self {
+        MenuItem {
+            label,
+            shortcut: None,
+            enabled: true,
+            kind,
+        }
+    }
+
+    fn selectable(&self) -> bool {
+        self.enabled && !matches!(self.kind, MenuItemKind::Separator)
+    }
+
+    fn height(&self, theme: &MenuTheme) -> f32 {
+        match self.kind {
+            MenuItemKind::Separator => theme.separator_height,
+            _ => theme.item_height,
+        }
+    }
+}
+
+/// A menu of items displayed as a popup.
+///
+/// Opened with `open_at`, `open_below` or by attaching it to a `Bin` with `attach` so that it is
+/// opened with a right click.
+///
+/// # Keyboard Navigation
+/// Once opened, the menu has focus.
+/// - `ArrowUp` & `ArrowDown` move the highlighted item.
+/// - `ArrowRight` opens the submenu of the highlighted item.
+/// - `ArrowLeft` closes the current submenu.
+/// - `Enter` & `Space` select the highlighted item.
+/// - `Esc` closes the current submenu or the menu.
+pub struct Menu {
+    pub basalt: Arc<Basalt>,
+    theme: MenuTheme,
+    items: Mutex<Vec<MenuItem>>,
+    state: Mutex<State>,
+    bar: Mutex<Option<(Weak<MenuBar>, usize)>>,
+    on_close: Mutex<Vec<Box<dyn FnMut() + Send + 'static>>>,
+}
+
+#[derive(Default)]
+struct State {
+    panels: Vec<Panel>,
+    /// Incremented to cancel pending submenu timers.
+    hover: u64,
+}
+
+struct Panel {
+    path: Vec<usize>,
+    container: Arc<Bin>,
+    rows: Vec<Row>,
+    width: f32,
+    highlighted: Option<usize>,
+    overlay: Option<OverlayID>,
+}
+
+struct Row {
+    bin: Arc<Bin>,
+    /// The mark & shortcut hint of an item or the line of a separator.
+    children: Vec<Arc<Bin>>,
+}
+
+impl Menu {
+    pub fn new(basalt: Arc<Basalt>, theme: MenuTheme, items: Vec<MenuItem>) -> Arc<Self> {
+        Arc::new(Menu {
+            basalt,
+            theme,
+            items: Mutex::new(items),
+            state: Mutex::new(State::default()),
+            bar: Mutex::new(None),
+            on_close: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Replace the items of the menu. This will close the menu if it is open.
+    pub fn set_items(&self, items: Vec<MenuItem>) {
+        self.close();
+        *self.items.lock() = items;
+    }
+
+    /// Set if the item can be selected.
+    ///
+    /// `path` is the index of the item within each submenu leading to it.
+    pub fn set_enabled(&self, path: &[usize], enabled: bool) {
+        if let Some(item) = item_mut(&mut self.items.lock(), path) {
+            item.enabled = enabled;
+        }
+
+        self.refresh_panels();
+    }
+
+    /// Set the state of a check or radio item.
+    ///
+    /// `path` is the index of the item within each submenu leading to it.
+    ///
+    /// # Notes
+    /// - Doesn't call the function of the item.
+    /// - Checking a radio item will uncheck the other radio items of its group.
+    pub fn set_checked(&self, path: &[usize], checked: bool) {
+        if let Some((index, parent)) = path.split_last() {
+            if let Some(items) = items_mut(&mut self.items.lock(), parent) {
+                set_checked(items, *index, checked);
+            }
+        }
+
+        self.refresh_panels();
+    }
+
+    /// The state of a check or radio item.
+    pub fn is_checked(&self, path: &[usize]) -> Option<bool> {
+        match item_mut(&mut self.items.lock(), path).map(|item| &item.kind) {
+            Some(MenuItemKind::Check {
+                checked, ..
+            })
+            | Some(MenuItemKind::Radio {
+                checked, ..
+            }) => Some(*checked),
+            _ => None,
+        }
+    }
+
+    /// Open the menu when the `Bin` or one of its descendants is right clicked.
+    ///
+    /// # Notes
+    /// - The menu is kept alive by the hook until the `Bin` is dropped.
+    /// - Bin press hooks are only called for the focused `Bin`, so a window hook is used that
+    /// checks the `Bin` under the cursor instead.
+    pub fn attach(self: &Arc<Self>, bin: &Arc<Bin>) {
+        let menu = self.clone();
+        let bin_wk = Arc::downgrade(bin);
+
+        let hook_id = self
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&self.basalt.window())
+            .on_press()
+            .keys(MouseButton::Right)
+            .call(move |_, window, _| {
+                let bin = match bin_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let interface = menu.basalt.interface_ref();
+                let [x, y] = window.cursor_pos();
+
+                let inside = match interface.get_bin_atop(window.window_id(), x, y) {
+                    Some(atop) => {
+                        atop.id() == bin.id()
+                            || atop
+                                .ancestors()
+                                .iter()
+                                .any(|ancestor| ancestor.id() == bin.id())
+                    },
+                    None => false,
+                };
+
+                if !inside {
+                    return InputHookCtrl::Retain;
+                }
+
+                let scale = interface.current_effective_scale();
+                menu.open_at([x / scale, y / scale]);
+                InputHookCtrl::RetainNoPass
+            })
+            .finish()
+            .unwrap();
+
+        bin.attach_input_hook(hook_id);
+    }
+
+    /// Open the menu with its top left corner at the position.
+    pub fn open_at(self: &Arc<Self>, position: [f32; 2]) {
+        self.close();
+        self.open_panel(
+            Vec::new(),
+            OverlayAnchor::Point(position),
+            Placement::Below,
+            PlacementAlign::Start,
+        );
+        self.focus(0);
+    }
+
+    /// Open the menu below the `Bin`.
+    pub fn open_below(self: &Arc<Self>, bin: &Arc<Bin>) {
+        self.close();
+        self.open_panel(
+            Vec::new(),
+            OverlayAnchor::Bin(bin.clone()),
+            Placement::Below,
+            PlacementAlign::Start,
+        );
+        self.focus(0);
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.state.lock().panels.is_empty()
+    }
+
+    /// Close the menu and all of its submenus.
+    pub fn close(&self) {
+        self.close_from(0);
+    }
+
+    /// Add a function to be called when the menu is closed.
+    pub fn on_close<F: FnMut() + Send + 'static>(&self, func: F) {
+        self.on_close.lock().push(Box::new(func));
+    }
+
+    fn close_from(&self, level: usize) {
+        let (panels, parent) = {
+            let mut state = self.state.lock();
+            state.hover += 1;
+
+            if level >= state.panels.len() {
+                return;
+            }
+
+            let panels = state.panels.split_off(level);
+
+            let parent = match level {
+                0 => None,
+                _ => Some(state.panels[level - 1].container.clone()),
+            };
+
+            (panels, parent)
+        };
+
+        let overlays = self.basalt.interface_ref().overlays();
+
+        for panel in panels {
+            if let Some(overlay) = panel.overlay {
+                overlays.hide(overlay);
+            }
+        }
+
+        match parent {
+            Some(parent) => self.basalt.input_ref().set_bin_focused(&parent),
+            None => {
+                for func in self.on_close.lock().iter_mut() {
+                    func();
+                }
+            },
+        }
+    }
+
+    fn focus(&self, level: usize) {
+        let container = match self.state.lock().panels.get(level) {
+            Some(panel) => panel.container.clone(),
+            None => return,
+        };
+
+        self.basalt.input_ref().set_bin_focused(&container);
+    }
+
+    fn open_panel(
+        self: &Arc<Self>,
+        path: Vec<usize>,
+        anchor: OverlayAnchor,
+        placement: Placement,
+        align: PlacementAlign,
+    ) {
+        let level = path.len();
+        let (container, rows, width) = match self.build_panel(&path) {
+            Some(some) => some,
+            None => return,
+        };
+
+        self.state.lock().panels.push(Panel {
+            path,
+            container: container.clone(),
+            rows,
+            width,
+            highlighted: None,
+            overlay: None,
+        });
+
+        self.update_rows(level);
+
+        let overlays = self.basalt.interface_ref().overlays();
+        let overlay =
+            overlays.show_anchored(container, OverlayLayer::Popup, anchor, placement, align);
+        let menu_wk = Arc::downgrade(self);
+
+        overlays.on_dismiss(overlay, move || {
+            if let Some(menu) = menu_wk.upgrade() {
+                menu.close_from(level);
+            }
+        });
+
+        if let Some(panel) = self.state.lock().panels.get_mut(level) {
+            panel.overlay = Some(overlay);
+        }
+    }
+
+    fn build_panel(self: &Arc<Self>, path: &[usize]) -> Option<(Arc<Bin>, Vec<Row>, f32)> {
+        let items = self.items.lock();
+        let items = items_at(&items, path)?;
+        let level = path.len();
+        let container = self.basalt.interface_ref().new_bin();
+        let mut rows = Vec::with_capacity(items.len());
+        let mut top = 2.0;
+        let mut width: f32 = 0.0;
+
+        for (i, item) in items.iter().enumerate() {
+            let row = self.basalt.interface_ref().new_bin();
+            container.add_child(row.clone());
+            let height = item.height(&self.theme);
+
+            row.style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(top),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(height),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+            top += height;
+
+            if let MenuItemKind::Separator = item.kind {
+                let line = self.basalt.interface_ref().new_bin();
+                row.add_child(line.clone());
+
+                line.style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t_pct: Some(50.0),
+                    pos_from_t_offset: Some(-0.5),
+                    pos_from_l: Some(6.0),
+                    pos_from_r: Some(6.0),
+                    height: Some(1.0),
+                    back_color: Some(self.theme.separator_color.clone()),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+
+                rows.push(Row {
+                    bin: row,
+                    children: vec![line],
+                });
+
+                continue;
+            }
+
+            width = width.max(
+                text_width(&item.label, self.theme.text_height)
+                    + item
+                        .shortcut
+                        .as_ref()
+                        .map(|shortcut| text_width(shortcut, self.theme.text_height) + 24.0)
+                        .unwrap_or(0.0)
+                    + 48.0,
+            );
+
+            let mark = self.basalt.interface_ref().new_bin();
+            let hint = self.basalt.interface_ref().new_bin();
+            row.add_children(vec![mark.clone(), hint.clone()]);
+
+            let menu_wk = Arc::downgrade(self);
+
+            row.on_enter(move |_, _| {
+                let menu = match menu_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                menu.hover(level, i);
+                Default::default()
+            });
+
+            // Focus is given to the top-most bin when clicked, so the children need the hook too.
+            for bin in [row.clone(), mark.clone(), hint.clone()] {
+                let menu_wk = Arc::downgrade(self);
+
+                bin.on_press(MouseButton::Left, move |_, _, _| {
+                    let menu = match menu_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    menu.activate(level, i);
+                    let top = menu.state.lock().panels.len().saturating_sub(1);
+                    menu.focus(top);
+                    Default::default()
+                });
+            }
+
+            rows.push(Row {
+                bin: row,
+                children: vec![mark, hint],
+            });
+        }
+
+        let width = width.max(self.theme.min_width);
+
+        container
+            .style_update(BinStyle {
+                position: Some(BinPosition::Window),
+                hidden: Some(true),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(width),
+                height: Some(top + 2.0),
+                back_color: Some(self.theme.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(self.theme.border_color.clone()),
+                border_color_b: Some(self.theme.border_color.clone()),
+                border_color_l: Some(self.theme.border_color.clone()),
+                border_color_r: Some(self.theme.border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        for key in [
+            Qwerty::ArrowUp,
+            Qwerty::ArrowDown,
+            Qwerty::ArrowLeft,
+            Qwerty::ArrowRight,
+            Qwerty::Enter,
+            Qwerty::Space,
+        ] {
+            let menu_wk = Arc::downgrade(self);
+
+            container.on_press(key, move |_, _, _| {
+                let menu = match menu_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                menu.key_press(level, key);
+                Default::default()
+            });
+        }
+
+        Some((container, rows, width))
+    }
+
+    fn key_press(self: &Arc<Self>, level: usize, key: Qwerty) {
+        let highlighted = match self.state.lock().panels.get(level) {
+            Some(panel) => panel.highlighted,
+            None => return,
+        };
+
+        match key {
+            Qwerty::ArrowUp => self.move_highlight(level, -1),
+            Qwerty::ArrowDown => self.move_highlight(level, 1),
+            Qwerty::ArrowLeft => {
+                if level > 0 {
+                    self.close_from(level);
+                } else {
+                    self.move_in_bar(-1);
+                }
+            },
+            Qwerty::ArrowRight => {
+                let opened = match highlighted {
+                    Some(index) => self.open_submenu(level, index, true),
+                    None => false,
+                };
+
+                if !opened {
+                    self.move_in_bar(1);
+                }
+            },
+            Qwerty::Enter | Qwerty::Space => {
+                if let Some(index) = highlighted {
+                    self.activate(level, index);
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn hover(self: &Arc<Self>, level: usize, index: usize) {
+        let hover = {
+            let mut state = self.state.lock();
+
+            match state.panels.get_mut(level) {
+                Some(panel) => panel.highlighted = Some(index),
+                None => return,
+            }
+
+            state.hover += 1;
+            state.hover
+        };
+
+        self.update_rows(level);
+
+        // Open the submenu of the item or close the submenu of another item once the cursor has
+        // rested on the item.
+        let menu_wk = Arc::downgrade(self);
+        let interval = self.basalt.interval_ref();
+
+        let id = interval.do_every(
+            Duration::from_millis(10),
+            Some(self.theme.submenu_delay),
+            move |_| {
+                if let Some(menu) = menu_wk.upgrade() {
+                    if menu.state.lock().hover == hover {
+                        menu.open_submenu(level, index, false);
+                    }
+                }
+
+                IntvlHookCtrl::Remove
+            },
+        );
+
+        interval.start(id);
+    }
+
+    /// Returns `true` if the item has a submenu that is now open.
+    fn open_submenu(self: &Arc<Self>, level: usize, index: usize, focus: bool) -> bool {
+        let (path, row, is_open) = {
+            let state = self.state.lock();
+
+            let panel = match state.panels.get(level) {
+                Some(some) => some,
+                None => return false,
+            };
+
+            let mut path = panel.path.clone();
+            path.push(index);
+
+            let is_open = state
+                .panels
+                .get(level + 1)
+                .map(|sub| sub.path == path)
+                .unwrap_or(false);
+
+            (
+                path,
+                panel.rows.get(index).map(|row| row.bin.clone()),
+                is_open,
+            )
+        };
+
+        let is_submenu = matches!(
+            item_mut(&mut self.items.lock(), &path),
+            Some(MenuItem {
+                enabled: true,
+                kind: MenuItemKind::Submenu(_),
+                ..
+            })
+        );
+
+        if !is_open {
+            self.close_from(level + 1);
+
+            if let (true, Some(row)) = (is_submenu, row) {
+                self.open_panel(
+                    path,
+                    OverlayAnchor::Bin(row),
+                    Placement::Right,
+                    PlacementAlign::Start,
+                );
+            }
+        }
+
+        if is_submenu && focus {
+            self.focus(level + 1);
+            self.move_highlight(level + 1, 1);
+        }
+
+        is_submenu
+    }
+
+    fn activate(self: &Arc<Self>, level: usize, index: usize) {
+        let mut path = match self.state.lock().panels.get(level) {
+            Some(panel) => panel.path.clone(),
+            None => return,
+        };
+
+        path.push(index);
+
+        let call = {
+            let mut items = self.items.lock();
+
+            let siblings = match items_mut(&mut items, &path[..level]) {
+                Some(some) => some,
+                None => return,
+            };
+
+            match siblings.get(index) {
+                Some(item) if item.selectable() => (),
+                _ => return,
+            }
+
+            let (call, checked): (Option<Box<dyn FnOnce()>>, _) = match &siblings[index].kind {
+                MenuItemKind::Action(func) => {
+                    let func = func.clone();
+                    (Some(Box::new(move || func())), None)
+                },
+                MenuItemKind::Check {
+                    checked,
+                    on_toggle,
+                } => {
+                    let checked = !*checked;
+                    let func = on_toggle.clone();
+                    (Some(Box::new(move || func(checked))), Some(checked))
+                },
+                MenuItemKind::Radio {
+                    on_select, ..
+                } => {
+                    let func = on_select.clone();
+                    (Some(Box::new(move || func())), Some(true))
+                },
+                MenuItemKind::Submenu(_) => (None, None),
+                MenuItemKind::Separator => return,
+            };
+
+            if let Some(checked) = checked {
+                set_checked(siblings, index, checked);
+            }
+
+            call
+        };
+
+        match call {
+            Some(call) => {
+                self.close();
+                call();
+            },
+            None => {
+                self.open_submenu(level, index, true);
+            },
+        }
+    }
+
+    fn move_highlight(&self, level: usize, amt: isize) {
+        {
+            let mut state = self.state.lock();
+
+            let panel = match state.panels.get_mut(level) {
+                Some(some) => some,
+                None => return,
+            };
+
+            let items = self.items.lock();
+
+            let items = match items_at(&items, &panel.path) {
+                Some(some) if !some.is_empty() => some,
+                _ => return,
+            };
+
+            let count = items.len() as isize;
+
+            let mut index = match panel.highlighted {
+                Some(some) => some as isize,
+                None if amt > 0 => -1,
+                None => count,
+            };
+
+            for _ in 0..count {
+                index = (index + amt).rem_euclid(count);
+
+                if items[index as usize].selectable() {
+                    panel.highlighted = Some(index as usize);
+                    break;
+                }
+            }
+        }
+
+        self.update_rows(level);
+    }
+
+    fn move_in_bar(&self, amt: isize) {
+        let bar = self.bar.lock().clone();
+
+        if let Some((bar_wk, index)) = bar {
+            if let Some(bar) = bar_wk.upgrade() {
+                bar.open_adjacent(index, amt);
+            }
+        }
+    }
+
+    fn refresh_panels(&self) {
+        let count = self.state.lock().panels.len();
+
+        for level in 0..count {
+            self.update_rows(level);
+        }
+    }
+
+    fn update_rows(&self, level: usize) {
+        let state = self.state.lock();
+
+        let panel = match state.panels.get(level) {
+            Some(some) => some,
+            None => return,
+        };
+
+        let items = self.items.lock();
+
+        let items = match items_at(&items, &panel.path) {
+            Some(some) => some,
+            None => return,
+        };
+
+        for (i, (item, row)) in items.iter().zip(panel.rows.iter()).enumerate() {
+            if let MenuItemKind::Separator = item.kind {
+                continue;
+            }
+
+            let text_color = match item.enabled {
+                true => self.theme.text_color.clone(),
+                false => self.theme.disabled_color.clone(),
+            };
+
+            row.bin
+                .style_update(BinStyle {
+                    back_color: match panel.highlighted == Some(i) && item.enabled {
+                        true => Some(self.theme.highlight_color.clone()),
+                        false => None,
+                    },
+                    pad_l: Some(24.0),
+                    text: item.label.clone(),
+                    text_color: Some(text_color.clone()),
+                    text_height: Some(self.theme.text_height),
+                    text_vert_align: Some(TextVertAlign::Center),
+                    text_wrap: Some(TextWrap::None),
+                    ..row.bin.style_copy()
+                })
+                .expect_valid();
+
+            if let Some(mark) = row.children.first() {
+                mark.style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(0.0),
+                    pos_from_b: Some(0.0),
+                    pos_from_l: Some(0.0),
+                    width: Some(24.0),
+                    custom_verts: self.mark_verts(item),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+            }
+
+            if let Some(hint) = row.children.get(1) {
+                hint.style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(0.0),
+                    pos_from_b: Some(0.0),
+                    pos_from_l: Some(0.0),
+                    pos_from_r: Some(0.0),
+                    pad_r: Some(10.0),
+                    text: item.shortcut.clone().unwrap_or_default(),
+                    text_color: Some(match item.enabled {
+                        true => self.theme.shortcut_color.clone(),
+                        false => self.theme.disabled_color.clone(),
+                    }),
+                    text_height: Some(self.theme.text_height),
+                    text_vert_align: Some(TextVertAlign::Center),
+                    text_hori_align: Some(TextHoriAlign::Right),
+                    text_wrap: Some(TextWrap::None),
+                    custom_verts: match item.kind {
+                        MenuItemKind::Submenu(_) => self.arrow_verts(panel.width),
+                        _ => Vec::new(),
+                    },
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+            }
+        }
+    }
+
+    fn mark_verts(&self, item: &MenuItem) -> Vec<BinVert> {
+        let color = match item.enabled {
+            true => self.theme.mark_color.clone(),
+            false => self.theme.disabled_color.clone(),
+        };
+
+        let cy = self.theme.item_height / 2.0;
+
+        match item.kind {
+            MenuItemKind::Check {
+                checked: true, ..
+            } => {
+                let mut verts = line_verts((7.0, cy), (10.5, cy + 3.5), 2.0, &color);
+                verts.extend(line_verts((10.5, cy + 3.5), (17.0, cy - 4.0), 2.0, &color));
+                verts
+            },
+            MenuItemKind::Radio {
+                checked: true, ..
+            } => {
+                [
+                    (12.0, cy - 4.0),
+                    (8.0, cy),
+                    (12.0, cy + 4.0),
+                    (12.0, cy - 4.0),
+                    (12.0, cy + 4.0),
+                    (16.0, cy),
+                ]
+                .into_iter()
+                .map(|(x, y)| {
+                    BinVert {
+                        position: (x, y, 0),
+                        color: color.clone(),
+                    }
+                })
+                .collect()
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    fn arrow_verts(&self, width: f32) -> Vec<BinVert> {
+        let right = width - 6.0;
+        let cy = self.theme.item_height / 2.0;
+
+        [
+            (right - 4.0, cy - 4.0),
+            (right - 4.0, cy + 4.0),
+            (right, cy),
+        ]
+        .into_iter()
+        .map(|(x, y)| {
+            BinVert {
+                position: (x, y, 0),
+                color: self.theme.mark_color.clone(),
+            }
+        })
+        .collect()
+    }
+}
+
+/// A bar of menus, usually displayed along the top of the window.
+///
+/// Clicking a title opens its menu. While a menu is open, hovering another title opens its menu
+/// instead and `ArrowLeft` & `ArrowRight` move between them.
+pub struct MenuBar {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    theme: MenuTheme,
+    state: Mutex<BarState>,
+}
+
+struct BarState {
+    menus: Vec<(Arc<Bin>, Arc<Menu>)>,
+    open: Option<usize>,
+}
+
+impl MenuBar {
+    pub fn new(basalt: Arc<Basalt>, theme: MenuTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        let container = basalt.interface_ref().new_bin();
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(theme.bar_height),
+                back_color: Some(theme.bar_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        Arc::new(MenuBar {
+            basalt,
+            container,
+            theme,
+            state: Mutex::new(BarState {
+                menus: Vec::new(),
+                open: None,
+            }),
+        })
+    }
+
+    /// Add a menu to the end of the bar.
+    pub fn add_menu<T: Into<String>>(self: &Arc<Self>, title: T, menu: Arc<Menu>) {
+        let title = title.into();
+        let bin = self.basalt.interface_ref().new_bin();
+        self.container.add_child(bin.clone());
+
+        let index = {
+            let mut state = self.state.lock();
+            let left = state
+                .menus
+                .last()
+                .map(|(last, _)| {
+                    let style = last.style();
+                    style.pos_from_l.unwrap_or(0.0) + style.width.unwrap_or(0.0)
+                })
+                .unwrap_or(0.0);
+
+            bin.style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(left),
+                width: Some(text_width(&title, self.theme.text_height) + 20.0),
+                text: title,
+                text_color: Some(self.theme.text_color.clone()),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+            state.menus.push((bin.clone(), menu.clone()));
+            state.menus.len() - 1
+        };
+
+        *menu.bar.lock() = Some((Arc::downgrade(self), index));
+        let bar_wk = Arc::downgrade(self);
+
+        menu.on_close(move || {
+            if let Some(bar) = bar_wk.upgrade() {
+                let mut state = bar.state.lock();
+
+                if state.open == Some(index) {
+                    state.open = None;
+                    drop(state);
+                    bar.update_titles();
+                }
+            }
+        });
+
+        let bar_wk = Arc::downgrade(self);
+
+        bin.on_press(MouseButton::Left, move |_, _, _| {
+            let bar = match bar_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if bar.state.lock().open == Some(index) {
+                bar.close();
+            } else {
+                bar.open(index);
+            }
+
+            Default::default()
+        });
+
+        let bar_wk = Arc::downgrade(self);
+
+        bin.on_enter(move |_, _| {
+            let bar = match bar_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            let open = bar.state.lock().open;
+
+            if open.is_some() && open != Some(index) {
+                bar.open(index);
+            }
+
+            Default::default()
+        });
+    }
+
+    /// Open the menu at the index.
+    pub fn open(&self, index: usize) {
+        let (title, menu) = match self.state.lock().menus.get(index) {
+            Some((title, menu)) => (title.clone(), menu.clone()),
+            None => return,
+        };
+
+        self.close();
+        menu.open_below(&title);
+        self.state.lock().open = Some(index);
+        self.update_titles();
+    }
+
+    /// Close the open menu.
+    pub fn close(&self) {
+        let menu = {
+            let state = self.state.lock();
+            state
+                .open
+                .and_then(|index| state.menus.get(index))
+                .map(|(_, menu)| menu.clone())
+        };
+
+        if let Some(menu) = menu {
+            menu.close();
+        }
+    }
+
+    /// The index of the open menu.
+    pub fn open_index(&self) -> Option<usize> {
+        self.state.lock().open
+    }
+
+    fn open_adjacent(&self, index: usize, amt: isize) {
+        let count = self.state.lock().menus.len() as isize;
+
+        if count > 1 {
+            self.open((index as isize + amt).rem_euclid(count) as usize);
+        }
+    }
+
+    fn update_titles(&self) {
+        let state = self.state.lock();
+
+        for (i, (title, _)) in state.menus.iter().enumerate() {
+            title
+                .style_update(BinStyle {
+                    back_color: match state.open == Some(i) {
+                        true => Some(self.theme.highlight_color.clone()),
+                        false => None,
+                    },
+                    ..title.style_copy()
+                })
+                .expect_valid();
+        }
+    }
+}
+
+/// Bins can't be sized to their text, so widths are estimated from the amount of characters.
+fn text_width(text: &str, text_height: f32) -> f32 {
+    text.chars().count() as f32 * text_height * 0.6
+}
+
+fn line_verts(a: (f32, f32), b: (f32, f32), width: f32, color: &bin::Color) -> Vec<BinVert> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+    let (nx, ny) = (-dy / len * width / 2.0, dx / len * width / 2.0);
+
+    [
+        (a.0 + nx, a.1 + ny),
+        (a.0 - nx, a.1 - ny),
+        (b.0 + nx, b.1 + ny),
+        (b.0 + nx, b.1 + ny),
+        (a.0 - nx, a.1 - ny),
+        (b.0 - nx, b.1 - ny),
+    ]
+    .into_iter()
+    .map(|(x, y)| {
+        BinVert {
+            position: (x, y, 0),
+            color: color.clone(),
+        }
+    })
+    .collect()
+}
+
+fn items_at<'a>(items: &'a [MenuItem], path: &[usize]) -> Option<&'a [MenuItem]> {
+    match path.split_first() {
+        None => Some(items),
+        Some((index, rest)) => {
+            match &items.get(*index)?.kind {
+                MenuItemKind::Submenu(items) => items_at(items, rest),
+                _ => None,
+            }
+        },
+    }
+}
+
+fn items_mut<'a>(items: &'a mut Vec<MenuItem>, path: &[usize]) -> Option<&'a mut Vec<MenuItem>> {
+    match path.split_first() {
+        None => Some(items),
+        Some((index, rest)) => {
+            match &mut items.get_mut(*index)?.kind {
+                MenuItemKind::Submenu(items) => items_mut(items, rest),
+                _ => None,
+            }
+        },
+    }
+}
+
+fn item_mut<'a>(items: &'a mut Vec<MenuItem>, path: &[usize]) -> Option<&'a mut MenuItem> {
+    let (index, parent) = path.split_last()?;
+    items_mut(items, parent)?.get_mut(*index)
+}
+
+fn set_checked(items: &mut [MenuItem], index: usize, set: bool) {
+    let is_radio = match items.get_mut(index).map(|item| &mut item.kind) {
+        Some(MenuItemKind::Check {
+            checked, ..
+        }) => {
+            *checked = set;
+            false
+        },
+        Some(MenuItemKind::Radio {
+            checked, ..
+        }) => {
+            *checked = set;
+            true
+        },
+        _ => return,
+    };
+
+    if !is_radio || !set {
+        return;
+    }
+
+    // Uncheck the other radio items adjacent to this one.
+    let is_radio_at =
+        |items: &[MenuItem], i: usize| matches!(items[i].kind, MenuItemKind::Radio { .. });
+
+    let mut start = index;
+
+    while start > 0 && is_radio_at(items, start - 1) {
+        start -= 1;
+    }
+
+    let mut end = index;
+
+    while end + 1 < items.len() && is_radio_at(items, end + 1) {
+        end += 1;
+    }
+
+    for (i, item) in items.iter_mut().enumerate().take(end + 1).skip(start) {
+        if i != index {
+            if let MenuItemKind::Radio {
+                checked, ..
+            } = &mut item.kind
+            {
+                *checked = false;
+            }
+        }
+    }
+}
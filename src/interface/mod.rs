@@ -1,9 +1,28 @@
+pub mod animated_image;
 pub mod bin;
 pub mod checkbox;
+pub mod color_picker;
+pub mod data_table;
+pub mod date_picker;
+pub mod dialog;
+pub mod dock;
+pub mod dropdown;
+pub mod menu;
 pub mod on_off_button;
+pub mod overlay;
+pub mod progress;
+pub mod radio_group;
 pub mod render;
 pub mod scroll_bar;
 pub mod slider;
+pub mod spin_box;
+pub mod split_pane;
+pub mod tab_container;
+pub mod time_picker;
+pub mod toast;
+pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
 
 use std::cmp::Reverse;
 use std::collections::BTreeMap;
@@ -17,9 +36,12 @@ use vulkano::format::Format as VkFormat;
 use vulkano::pipeline::graphics::vertex_input::Vertex;
 
 use self::bin::{Bin, BinID, FontStretch, FontStyle, FontWeight};
+use self::overlay::Overlays;
+use self::progress::ProgressTicker;
 use self::render::composer::{Composer, ComposerEv, ComposerInit};
 pub use self::render::ItfDrawTarget;
 use self::render::{ItfRenderer, ItfRendererInit};
+use self::tooltip::Tooltips;
 use crate::image_view::BstImageView;
 use crate::window::BstWindowID;
 use crate::{Atlas, Basalt, BasaltWindow, BstOptions};
@@ -92,7 +114,11 @@ pub struct Interface {
     composer: Arc<Composer>,
     scale: Mutex<Scale>,
     bins_state: RwLock<BinsState>,
+    focusable: Mutex<BTreeMap<BinID, Weak<Bin>>>,
     default_font: Mutex<DefaultFont>,
+    overlays: Overlays,
+    tooltips: Tooltips,
+    progress_ticker: ProgressTicker,
 }
 
 #[derive(Default)]
@@ -139,6 +165,7 @@ impl Interface {
 
         Arc::new(Interface {
             bins_state: RwLock::new(BinsState::default()),
+            focusable: Mutex::new(BTreeMap::new()),
             scale: Mutex::new(scale),
             renderer: Mutex::new(ItfRenderer::new(ItfRendererInit {
                 options: options.clone(),
@@ -150,12 +177,30 @@ impl Interface {
             composer,
             options,
             default_font: Mutex::new(DefaultFont::default()),
+            overlays: Overlays::new(),
+            tooltips: Tooltips::new(),
+            progress_ticker: ProgressTicker::new(),
         })
     }
 
     pub(crate) fn attach_basalt(&self, basalt: Arc<Basalt>) {
-        let mut bins_state = self.bins_state.write();
-        bins_state.bst = Some(basalt);
+        self.bins_state.write().bst = Some(basalt.clone());
+        self.overlays.attach_basalt(&basalt);
+        self.tooltips.attach_basalt(&basalt);
+    }
+
+    /// The overlays displayed above the rest of the interface.
+    pub fn overlays(&self) -> &Overlays {
+        &self.overlays
+    }
+
+    /// The tooltips set on `Bin`'s with `Bin::set_tooltip`.
+    pub fn tooltips(&self) -> &Tooltips {
+        &self.tooltips
+    }
+
+    pub(crate) fn progress_ticker(&self) -> &ProgressTicker {
+        &self.progress_ticker
     }
 
     /// The current scale without taking into account dpi based window scaling.
@@ -326,6 +371,34 @@ impl Interface {
         self.new_bins(1).pop().unwrap()
     }
 
+    pub(crate) fn set_focusable(&self, bin: &Arc<Bin>, focusable: bool) {
+        let mut bins = self.focusable.lock();
+
+        match focusable {
+            true => bins.insert(bin.id(), Arc::downgrade(bin)),
+            false => bins.remove(&bin.id()),
+        };
+    }
+
+    /// Returns the bins marked with `Bin::set_focusable`.
+    ///
+    /// Unlike `bins`, this doesn't iterate every `Bin` in the interface.
+    pub(crate) fn focusable_bins(&self) -> Vec<Arc<Bin>> {
+        let mut out = Vec::new();
+
+        self.focusable.lock().retain(|_, bin_wk| {
+            match bin_wk.upgrade() {
+                Some(bin) => {
+                    out.push(bin);
+                    true
+                },
+                None => false,
+            }
+        });
+
+        out
+    }
+
     pub fn get_bin(&self, id: BinID) -> Option<Arc<Bin>> {
         match self.bins_state.read().map.get(&id) {
             Some(some) => some.upgrade(),
@@ -0,0 +1,797 @@
+//! Layers for displaying `Bin`'s above the rest of the interface.
+//!
+//! Overlays are managed by `Interface` and accessed with `Interface::overlays`. A `Bin` shown as
+//! an overlay is positioned relative to the window and given a z-index above the normal tree.
+//!
+//! ### Layers
+//! From lowest to highest: `Modal`, `Popup`, `Toast` & `Tooltip`. Within a layer, overlays shown
+//! later are displayed above ones shown earlier.
+//!
+//! ### Dismissal
+//! Overlays can be dismissed by pressing `Esc` or by clicking outside of them. `Esc` only
+//! dismisses the top-most overlay that allows it. A click outside will not dismiss an overlay if
+//! the click is on its anchor or on an overlay displayed above it.
+//!
+//! ### Modals
+//! While a modal is shown, input is blocked from reaching anything below it:
+//! - Presses of any key, mouse or gamepad button, scrolling & characters are blocked with window
+//! hooks using `MODAL_BLOCK_WEIGHT`, which is above the weights used by shortcuts. Mouse presses
+//! & scrolling are let through while the cursor is over the modal, other presses & characters
+//! while the focused `Bin` is within the modal.
+//! - `Bin`'s below the modal are treated as not being under the cursor, so their enter, leave &
+//! cursor hooks behave as if the cursor were elsewhere.
+//! - Directional pad navigation only moves focus between `Bin`'s within the modal.
+//!
+//! Keyboard focus is also moved to the modal when it is shown. Overlays displayed above the
+//! modal, such as popups opened from it, are treated as part of it.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Weak};
+
+use parking_lot::Mutex;
+
+use crate::input::state::WindowState;
+use crate::input::{InputHookCtrl, Key, MouseButton, Qwerty};
+use crate::interface::bin::{Bin, BinID, BinPosition, BinStyle};
+use crate::Basalt;
+
+/// Weight of the hooks used to dismiss overlays.
+pub const DISMISS_WEIGHT: i16 = i16::MAX;
+/// Weight of the hooks used to block input below a modal.
+pub const MODAL_BLOCK_WEIGHT: i16 = i16::MAX - 1;
+
+/// The amount the z-index increases for each overlay shown within the same layer.
+const LAYER_Z_STEP: i16 = 100;
+/// The maximum amount of z-index steps within a layer.
+const LAYER_Z_STEPS: usize = 49;
+
+/// A layer that overlays are displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OverlayLayer {
+    /// Dialogs that block input to everything below them.
+    Modal,
+    /// Menus, dropdown lists & other popups.
+    Popup,
+    /// Notifications.
+    Toast,
+    /// Tooltips.
+    Tooltip,
+}
+
+impl OverlayLayer {
+    /// The z-index of the first overlay shown in this layer.
+    pub fn base_z_index(self) -> i16 {
+        match self {
+            Self::Modal => 10_000,
+            Self::Popup => 15_000,
+            Self::Toast => 20_000,
+            Self::Tooltip => 25_000,
+        }
+    }
+
+    /// The dismissal used by overlays in this layer unless set with `Overlays::set_dismissal`.
+    pub fn default_dismissal(self) -> Dismissal {
+        match self {
+            Self::Modal => {
+                Dismissal {
+                    outside_click: false,
+                    escape: true,
+                }
+            },
+            Self::Popup => {
+                Dismissal {
+                    outside_click: true,
+                    escape: true,
+                }
+            },
+            Self::Toast => {
+                Dismissal {
+                    outside_click: false,
+                    escape: false,
+                }
+            },
+            Self::Tooltip => {
+                Dismissal {
+                    outside_click: true,
+                    escape: true,
+                }
+            },
+        }
+    }
+}
+
+/// How an overlay can be dismissed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dismissal {
+    /// Dismiss when a mouse button is pressed outside of the overlay.
+    pub outside_click: bool,
+    /// Dismiss when `Esc` is pressed and this is the top-most overlay that allows it.
+    pub escape: bool,
+}
+
+/// An ID of an overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OverlayID(u64);
+
+/// What an anchored overlay is placed relative to.
+#[derive(Debug, Clone)]
+pub enum OverlayAnchor {
+    /// Relative to the outer edges of a `Bin`.
+    Bin(Arc<Bin>),
+    /// Relative to a point in the window such as the cursor.
+    Point([f32; 2]),
+}
+
+/// The side of the anchor an overlay is placed on.
+///
+/// If there isn't enough space on the preferred side, the opposite side is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Below,
+    Above,
+    Left,
+    Right,
+}
+
+impl Placement {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Below => Self::Above,
+            Self::Above => Self::Below,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// How an overlay is aligned along the side of the anchor it is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementAlign {
+    /// Align the left or top edges.
+    Start,
+    /// Center on the anchor.
+    Center,
+    /// Align the right or bottom edges.
+    End,
+}
+
+/// Manages the overlays of an `Interface`.
+pub struct Overlays {
+    basalt: Mutex<Weak<Basalt>>,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    id: OverlayID,
+    bin: Arc<Bin>,
+    layer: OverlayLayer,
+    z_index: i16,
+    anchor: Option<(OverlayAnchor, Placement, PlacementAlign)>,
+    placed: Option<Placement>,
+    dismissal: Dismissal,
+    on_dismiss: Vec<Box<dyn FnMut() + Send + 'static>>,
+}
+
+impl Overlays {
+    pub(crate) fn new() -> Self {
+        Overlays {
+            basalt: Mutex::new(Weak::new()),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    pub(crate) fn attach_basalt(&self, basalt: &Arc<Basalt>) {
+        *self.basalt.lock() = Arc::downgrade(basalt);
+        let window = basalt.window();
+
+        for key in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
+            let basalt_wk = Arc::downgrade(basalt);
+
+            basalt
+                .input_ref()
+                .hook()
+                .window(&window)
+                .on_press()
+                .keys(key)
+                .weight(DISMISS_WEIGHT)
+                .call(move |_, window, _| {
+                    match basalt_wk.upgrade() {
+                        Some(basalt) => {
+                            basalt.interface_ref().overlays().outside_click(window);
+                            Default::default()
+                        },
+                        None => InputHookCtrl::Remove,
+                    }
+                })
+                .finish()
+                .unwrap();
+        }
+
+        let basalt_wk = Arc::downgrade(basalt);
+
+        // Logical keys are pressed along with the physical key, so blocking the physical key
+        // blocks both.
+        basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_any_press()
+            .weight(MODAL_BLOCK_WEIGHT)
+            .call(move |_, window, key| {
+                match basalt_wk.upgrade() {
+                    Some(basalt) => {
+                        match key {
+                            Key::Mouse(_) => basalt.interface_ref().overlays().block_cursor(window),
+                            _ => basalt.interface_ref().overlays().block_focused(window),
+                        }
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            })
+            .finish()
+            .unwrap();
+
+        let basalt_wk = Arc::downgrade(basalt);
+
+        basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_press()
+            .keys(Qwerty::Esc)
+            .weight(DISMISS_WEIGHT)
+            .call(move |_, _, _| {
+                match basalt_wk.upgrade() {
+                    Some(basalt) => {
+                        match basalt.interface_ref().overlays().escape() {
+                            true => InputHookCtrl::RetainNoPass,
+                            false => InputHookCtrl::Retain,
+                        }
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            })
+            .finish()
+            .unwrap();
+
+        for smooth in [false, true] {
+            let basalt_wk = Arc::downgrade(basalt);
+
+            basalt
+                .input_ref()
+                .hook()
+                .window(&window)
+                .on_scroll()
+                .enable_smooth(smooth)
+                .weight(MODAL_BLOCK_WEIGHT)
+                .call(move |_, window, _, _| {
+                    match basalt_wk.upgrade() {
+                        Some(basalt) => basalt.interface_ref().overlays().block_cursor(window),
+                        None => InputHookCtrl::Remove,
+                    }
+                })
+                .finish()
+                .unwrap();
+        }
+
+        let basalt_wk = Arc::downgrade(basalt);
+
+        basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_character()
+            .weight(MODAL_BLOCK_WEIGHT)
+            .call(move |_, window, _| {
+                match basalt_wk.upgrade() {
+                    Some(basalt) => basalt.interface_ref().overlays().block_focused(window),
+                    None => InputHookCtrl::Remove,
+                }
+            })
+            .finish()
+            .unwrap();
+    }
+
+    /// Show a `Bin` as an overlay.
+    ///
+    /// The `Bin` is positioned relative to the window, so the position fields of its style should
+    /// be relative to the window as well.
+    ///
+    /// # Notes
+    /// - The `Bin` is kept alive until the overlay is hidden or dismissed.
+    /// - If the `Bin` is already shown, it is hidden first.
+    pub fn show(&self, bin: Arc<Bin>, layer: OverlayLayer) -> OverlayID {
+        self.insert(bin, layer, None)
+    }
+
+    /// Show a `Bin` as an overlay placed next to an anchor.
+    ///
+    /// The size of the `Bin` is taken from its `width` & `height` style fields. If there isn't
+    /// enough space on the side of `placement`, the opposite side is used. The overlay is then
+    /// kept within the window.
+    pub fn show_anchored(
+        &self,
+        bin: Arc<Bin>,
+        layer: OverlayLayer,
+        anchor: OverlayAnchor,
+        placement: Placement,
+        align: PlacementAlign,
+    ) -> OverlayID {
+        self.insert(bin, layer, Some((anchor, placement, align)))
+    }
+
+    /// Hide an overlay without calling its dismiss functions.
+    ///
+    /// Returns `false` if the overlay isn't shown.
+    pub fn hide(&self, id: OverlayID) -> bool {
+        let entry = {
+            let mut state = self.state.lock();
+
+            match state.entries.iter().position(|entry| entry.id == id) {
+                Some(i) => state.entries.remove(i),
+                None => return false,
+            }
+        };
+
+        entry.bin.hidden(Some(true));
+        true
+    }
+
+    /// Hide an overlay and call its dismiss functions.
+    ///
+    /// Returns `false` if the overlay isn't shown.
+    pub fn dismiss(&self, id: OverlayID) -> bool {
+        let entry = {
+            let mut state = self.state.lock();
+
+            match state.entries.iter().position(|entry| entry.id == id) {
+                Some(i) => state.entries.remove(i),
+                None => return false,
+            }
+        };
+
+        entry.bin.hidden(Some(true));
+
+        for mut func in entry.on_dismiss {
+            func();
+        }
+
+        true
+    }
+
+    /// Hide every overlay in the layer without calling their dismiss functions.
+    pub fn hide_layer(&self, layer: OverlayLayer) {
+        let entries = {
+            let mut state = self.state.lock();
+            let (hide, keep): (Vec<_>, Vec<_>) = state
+                .entries
+                .drain(..)
+                .partition(|entry| entry.layer == layer);
+            state.entries = keep;
+            hide
+        };
+
+        for entry in entries {
+            entry.bin.hidden(Some(true));
+        }
+    }
+
+    pub fn is_shown(&self, id: OverlayID) -> bool {
+        self.state.lock().entries.iter().any(|entry| entry.id == id)
+    }
+
+    /// The `Bin` of the overlay.
+    pub fn bin(&self, id: OverlayID) -> Option<Arc<Bin>> {
+        self.state
+            .lock()
+            .entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.bin.clone())
+    }
+
+    /// The side of the anchor an anchored overlay was placed on after flipping.
+    pub fn placement(&self, id: OverlayID) -> Option<Placement> {
+        self.state
+            .lock()
+            .entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .and_then(|entry| entry.placed)
+    }
+
+    /// Set how an overlay can be dismissed.
+    ///
+    /// **Default**: `OverlayLayer::default_dismissal`
+    pub fn set_dismissal(&self, id: OverlayID, dismissal: Dismissal) {
+        if let Some(entry) = self
+            .state
+            .lock()
+            .entries
+            .iter_mut()
+            .find(|entry| entry.id == id)
+        {
+            entry.dismissal = dismissal;
+        }
+    }
+
+    /// Add a function to be called when the overlay is dismissed.
+    ///
+    /// # Notes
+    /// - Not called when the overlay is hidden with `hide` or `hide_layer`.
+    pub fn on_dismiss<F: FnMut() + Send + 'static>(&self, id: OverlayID, func: F) {
+        if let Some(entry) = self
+            .state
+            .lock()
+            .entries
+            .iter_mut()
+            .find(|entry| entry.id == id)
+        {
+            entry.on_dismiss.push(Box::new(func));
+        }
+    }
+
+    /// Place an anchored overlay again.
+    ///
+    /// Should be called when the anchor moves or the size of the overlay changes.
+    pub fn reposition(&self, id: OverlayID) {
+        let (bin, anchor) = {
+            let state = self.state.lock();
+
+            match state.entries.iter().find(|entry| entry.id == id) {
+                Some(entry) => (entry.bin.clone(), entry.anchor.clone()),
+                None => return,
+            }
+        };
+
+        if let Some((anchor, placement, align)) = anchor {
+            let placed = self.place(&bin, &anchor, placement, align);
+
+            if let Some(entry) = self
+                .state
+                .lock()
+                .entries
+                .iter_mut()
+                .find(|entry| entry.id == id)
+            {
+                entry.placed = Some(placed);
+            }
+        }
+    }
+
+    /// Returns `true` if a modal is currently shown.
+    pub fn modal_active(&self) -> bool {
+        self.state
+            .lock()
+            .entries
+            .iter()
+            .any(|entry| entry.layer == OverlayLayer::Modal)
+    }
+
+    fn insert(
+        &self,
+        bin: Arc<Bin>,
+        layer: OverlayLayer,
+        anchor: Option<(OverlayAnchor, Placement, PlacementAlign)>,
+    ) -> OverlayID {
+        let (id, z_index) = {
+            let mut state = self.state.lock();
+            state.entries.retain(|entry| entry.bin.id() != bin.id());
+            let id = OverlayID(state.next_id);
+            state.next_id += 1;
+
+            let slot = state
+                .entries
+                .iter()
+                .filter(|entry| entry.layer == layer)
+                .map(|entry| ((entry.z_index - layer.base_z_index()) / LAYER_Z_STEP) as usize + 1)
+                .max()
+                .unwrap_or(0)
+                .min(LAYER_Z_STEPS);
+
+            let z_index = layer.base_z_index() + (slot as i16 * LAYER_Z_STEP);
+
+            state.entries.push(Entry {
+                id,
+                bin: bin.clone(),
+                layer,
+                z_index,
+                anchor: anchor.clone(),
+                placed: None,
+                dismissal: layer.default_dismissal(),
+                on_dismiss: Vec::new(),
+            });
+
+            (id, z_index)
+        };
+
+        bin.style_update(BinStyle {
+            position: Some(BinPosition::Window),
+            z_index: Some(z_index),
+            hidden: None,
+            ..bin.style_copy()
+        })
+        .expect_valid();
+
+        match anchor {
+            Some((anchor, placement, align)) => {
+                let placed = self.place(&bin, &anchor, placement, align);
+
+                if let Some(entry) = self
+                    .state
+                    .lock()
+                    .entries
+                    .iter_mut()
+                    .find(|entry| entry.id == id)
+                {
+                    entry.placed = Some(placed);
+                }
+            },
+            None => bin.update_children(),
+        }
+
+        if layer == OverlayLayer::Modal {
+            if let Some(basalt) = self.basalt.lock().upgrade() {
+                basalt.input_ref().set_bin_focused(&bin);
+            }
+        }
+
+        id
+    }
+
+    fn place(
+        &self,
+        bin: &Arc<Bin>,
+        anchor: &OverlayAnchor,
+        placement: Placement,
+        align: PlacementAlign,
+    ) -> Placement {
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return placement,
+        };
+
+        let extent = basalt.window().inner_dimensions();
+        let scale = basalt.interface_ref().current_effective_scale();
+        let win_w = extent[0] as f32 / scale;
+        let win_h = extent[1] as f32 / scale;
+
+        let [a_t, a_b, a_l, a_r] = match anchor {
+            OverlayAnchor::Bin(anchor) => {
+                let post = anchor.post_update();
+                [post.tlo[1], post.blo[1], post.tlo[0], post.tro[0]]
+            },
+            OverlayAnchor::Point([x, y]) => [*y, *y, *x, *x],
+        };
+
+        let style = bin.style_copy();
+        let post = bin.post_update();
+        let border_t = style.border_size_t.unwrap_or(0.0);
+        let border_b = style.border_size_b.unwrap_or(0.0);
+        let border_l = style.border_size_l.unwrap_or(0.0);
+        let border_r = style.border_size_r.unwrap_or(0.0);
+        let width = style.width.unwrap_or(post.tri[0] - post.tli[0]) + border_l + border_r;
+        let height = style.height.unwrap_or(post.bli[1] - post.tli[1]) + border_t + border_b;
+
+        let fits = |placement: Placement| -> bool {
+            match placement {
+                Placement::Below => a_b + height <= win_h,
+                Placement::Above => a_t - height >= 0.0,
+                Placement::Left => a_l - width >= 0.0,
+                Placement::Right => a_r + width <= win_w,
+            }
+        };
+
+        let placed = if !fits(placement) && fits(placement.opposite()) {
+            placement.opposite()
+        } else {
+            placement
+        };
+
+        let align_on = |start: f32, end: f32, size: f32| -> f32 {
+            match align {
+                PlacementAlign::Start => start,
+                PlacementAlign::Center => ((start + end) / 2.0) - (size / 2.0),
+                PlacementAlign::End => end - size,
+            }
+        };
+
+        let (top, left) = match placed {
+            Placement::Below => (a_b, align_on(a_l, a_r, width)),
+            Placement::Above => (a_t - height, align_on(a_l, a_r, width)),
+            Placement::Left => (align_on(a_t, a_b, height), a_l - width),
+            Placement::Right => (align_on(a_t, a_b, height), a_r),
+        };
+
+        let top = top.min(win_h - height).max(0.0);
+        let left = left.min(win_w - width).max(0.0);
+
+        bin.style_update(BinStyle {
+            pos_from_t: Some(top + border_t),
+            pos_from_b: None,
+            pos_from_l: Some(left + border_l),
+            pos_from_r: None,
+            pos_from_t_pct: None,
+            pos_from_b_pct: None,
+            pos_from_l_pct: None,
+            pos_from_r_pct: None,
+            pos_from_t_offset: None,
+            pos_from_b_offset: None,
+            pos_from_l_offset: None,
+            pos_from_r_offset: None,
+            width: Some(width - border_l - border_r),
+            width_pct: None,
+            width_offset: None,
+            height: Some(height - border_t - border_b),
+            height_pct: None,
+            height_offset: None,
+            ..bin.style_copy()
+        })
+        .expect_valid();
+
+        bin.update_children();
+        placed
+    }
+
+    /// The ids of the bin under the cursor & its ancestors.
+    fn ids_under_cursor(&self, window: &WindowState) -> HashSet<BinID> {
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return HashSet::new(),
+        };
+
+        let [x, y] = window.cursor_pos();
+
+        match basalt
+            .interface_ref()
+            .get_bin_atop(window.window_id(), x, y)
+        {
+            Some(bin) => Self::with_ancestors(&bin),
+            None => HashSet::new(),
+        }
+    }
+
+    fn with_ancestors(bin: &Arc<Bin>) -> HashSet<BinID> {
+        let mut ids: HashSet<_> = bin.ancestors().into_iter().map(|bin| bin.id()).collect();
+        ids.insert(bin.id());
+        ids
+    }
+
+    fn outside_click(&self, window: &WindowState) {
+        let under = self.ids_under_cursor(window);
+
+        let dismiss: Vec<_> = {
+            let state = self.state.lock();
+
+            state
+                .entries
+                .iter()
+                .filter(|entry| {
+                    if !entry.dismissal.outside_click {
+                        return false;
+                    }
+
+                    if let Some((OverlayAnchor::Bin(anchor), ..)) = &entry.anchor {
+                        if under.contains(&anchor.id()) {
+                            return false;
+                        }
+                    }
+
+                    !state.entries.iter().any(|other| {
+                        other.z_index >= entry.z_index && under.contains(&other.bin.id())
+                    })
+                })
+                .map(|entry| entry.id)
+                .collect()
+        };
+
+        for id in dismiss {
+            self.dismiss(id);
+        }
+    }
+
+    fn escape(&self) -> bool {
+        let top = self
+            .state
+            .lock()
+            .entries
+            .iter()
+            .filter(|entry| entry.dismissal.escape)
+            .max_by_key(|entry| entry.z_index)
+            .map(|entry| entry.id);
+
+        match top {
+            Some(id) => self.dismiss(id),
+            None => false,
+        }
+    }
+
+    /// The z-index of the top-most modal.
+    fn modal_z_index(&self) -> Option<i16> {
+        self.state
+            .lock()
+            .entries
+            .iter()
+            .filter(|entry| entry.layer == OverlayLayer::Modal)
+            .map(|entry| entry.z_index)
+            .max()
+    }
+
+    /// Check if any of the ids belong to the top-most modal or an overlay displayed above it.
+    fn above_modal(&self, modal_z: i16, ids: &HashSet<BinID>) -> bool {
+        self.state
+            .lock()
+            .entries
+            .iter()
+            .any(|entry| entry.z_index >= modal_z && ids.contains(&entry.bin.id()))
+    }
+
+    /// Returns `true` if a modal is shown and the `Bin` isn't within it or an overlay above it.
+    pub(crate) fn below_modal(&self, bin: &Arc<Bin>) -> bool {
+        match self.modal_z_index() {
+            Some(modal_z) => !self.above_modal(modal_z, &Self::with_ancestors(bin)),
+            None => false,
+        }
+    }
+
+    /// Remove the ids of `Bin`'s that are below the top-most modal.
+    pub(crate) fn retain_above_modal(&self, ids: &mut Vec<BinID>) {
+        let modal_z = match self.modal_z_index() {
+            Some(some) => some,
+            None => return,
+        };
+
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return,
+        };
+
+        ids.retain(|id| {
+            match basalt.interface_ref().get_bin(*id) {
+                Some(bin) => self.above_modal(modal_z, &Self::with_ancestors(&bin)),
+                None => false,
+            }
+        });
+    }
+
+    fn block_cursor(&self, window: &WindowState) -> InputHookCtrl {
+        let modal_z = match self.modal_z_index() {
+            Some(some) => some,
+            None => return InputHookCtrl::Retain,
+        };
+
+        match self.above_modal(modal_z, &self.ids_under_cursor(window)) {
+            true => InputHookCtrl::Retain,
+            false => InputHookCtrl::RetainNoPass,
+        }
+    }
+
+    fn block_focused(&self, window: &WindowState) -> InputHookCtrl {
+        let modal_z = match self.modal_z_index() {
+            Some(some) => some,
+            None => return InputHookCtrl::Retain,
+        };
+
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return InputHookCtrl::Retain,
+        };
+
+        let focused = window
+            .focused_bin_id()
+            .and_then(|id| basalt.interface_ref().get_bin(id));
+
+        match focused {
+            Some(bin) if self.above_modal(modal_z, &Self::with_ancestors(&bin)) => {
+                InputHookCtrl::Retain
+            },
+            _ => InputHookCtrl::RetainNoPass,
+        }
+    }
+}
@@ -0,0 +1,525 @@
+use std::collections::BTreeMap;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::interface::bin::{
+    self, curve_line_segments, Bin, BinID, BinPosition, BinStyle, BinVert, KeepAlive,
+    TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interval::{IntvlHookCtrl, IntvlHookID};
+use crate::Basalt;
+
+impl KeepAlive for ProgressBar {}
+impl KeepAlive for Spinner {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressTheme {
+    pub track_color: bin::Color,
+    pub fill_color: bin::Color,
+    pub text_color: bin::Color,
+    pub text_height: f32,
+    /// Height of a `ProgressBar`.
+    pub bar_height: f32,
+    /// Diameter of a `Spinner`.
+    pub spinner_size: f32,
+    /// Thickness of a `Spinner`'s arc.
+    pub spinner_width: f32,
+    /// Time between frames of animations.
+    ///
+    /// Indicators with the same `frame_interval` are animated together, so each frame wakes the
+    /// composer once regardless of how many are animated. Each frame still causes the composer to
+    /// rebuild the vertex buffers of the whole interface, so longer intervals reduce this cost.
+    pub frame_interval: Duration,
+}
+
+impl Default for ProgressTheme {
+    fn default() -> Self {
+        ProgressTheme {
+            track_color: bin::Color::srgb_hex("303036"),
+            fill_color: bin::Color::srgb_hex("3a7bd5"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            text_height: 12.0,
+            bar_height: 18.0,
+            spinner_size: 24.0,
+            spinner_width: 3.0,
+            frame_interval: Duration::from_millis(33),
+        }
+    }
+}
+
+/// Time for the indeterminate segment to cross the bar.
+const SWEEP_PERIOD: f32 = 1.5;
+/// Width of the indeterminate segment relative to the bar.
+const SWEEP_WIDTH: f32 = 30.0;
+/// Time for a spinner to complete a rotation.
+const SPIN_PERIOD: f32 = 1.0;
+
+/// A bar that fills to display progress.
+///
+/// While indeterminate, a segment repeatedly sweeps across the bar instead.
+pub struct ProgressBar {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub fill: Arc<Bin>,
+    pub label: Arc<Bin>,
+    theme: ProgressTheme,
+    state: Mutex<BarState>,
+}
+
+struct BarState {
+    progress: f32,
+    indeterminate: Option<Instant>,
+    label: BarLabel,
+}
+
+enum BarLabel {
+    None,
+    Text(String),
+    Percent,
+}
+
+impl ProgressBar {
+    pub fn new(basalt: Arc<Basalt>, theme: ProgressTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(3);
+        let container = bins.pop().unwrap();
+        let fill = bins.pop().unwrap();
+        let label = bins.pop().unwrap();
+        container.add_children(vec![fill.clone(), label.clone()]);
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(200.0),
+                height: Some(theme.bar_height),
+                back_color: Some(theme.track_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let bar = Arc::new(ProgressBar {
+            basalt,
+            container,
+            fill,
+            label,
+            theme,
+            state: Mutex::new(BarState {
+                progress: 0.0,
+                indeterminate: None,
+                label: BarLabel::None,
+            }),
+        });
+
+        bar.update();
+        bar
+    }
+
+    /// The progress from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        self.state.lock().progress
+    }
+
+    /// Set the progress from `0.0` to `1.0`.
+    pub fn set_progress(&self, progress: f32) {
+        self.state.lock().progress = progress.clamp(0.0, 1.0);
+        self.update();
+    }
+
+    /// Display text on the bar.
+    pub fn set_label<T: Into<String>>(&self, text: T) {
+        self.state.lock().label = BarLabel::Text(text.into());
+        self.update();
+    }
+
+    /// Display the progress as a percentage on the bar.
+    pub fn set_percent_label(&self) {
+        self.state.lock().label = BarLabel::Percent;
+        self.update();
+    }
+
+    pub fn remove_label(&self) {
+        self.state.lock().label = BarLabel::None;
+        self.update();
+    }
+
+    /// Set whether the bar displays an animation instead of the progress.
+    ///
+    /// # Notes
+    /// - The animation is skipped while the bar isn't visible.
+    pub fn set_indeterminate(self: &Arc<Self>, indeterminate: bool) {
+        {
+            let mut state = self.state.lock();
+
+            if state.indeterminate.is_some() == indeterminate {
+                return;
+            }
+
+            state.indeterminate = indeterminate.then(Instant::now);
+        }
+
+        if indeterminate {
+            self.basalt.interface_ref().progress_ticker().add(
+                &self.basalt,
+                self.theme.frame_interval,
+                self.container.id(),
+                Arc::downgrade(self) as Weak<dyn Animated>,
+            );
+        }
+
+        self.update();
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        self.state.lock().indeterminate.is_some()
+    }
+
+    /// The style of the fill, this is all that changes between frames of the animation.
+    fn fill_style(&self, state: &BarState) -> BinStyle {
+        let (left, width) = match state.indeterminate {
+            Some(start) => {
+                let t = (start.elapsed().as_secs_f32() / SWEEP_PERIOD).fract();
+                ((t * (100.0 + SWEEP_WIDTH)) - SWEEP_WIDTH, SWEEP_WIDTH)
+            },
+            None => (0.0, state.progress * 100.0),
+        };
+
+        BinStyle {
+            position: Some(BinPosition::Parent),
+            pos_from_t: Some(0.0),
+            pos_from_b: Some(0.0),
+            pos_from_l_pct: Some(left),
+            width_pct: Some(width),
+            back_color: Some(self.theme.fill_color.clone()),
+            ..BinStyle::default()
+        }
+    }
+
+    fn update(&self) {
+        let (fill_style, text) = {
+            let state = self.state.lock();
+
+            let text = match &state.label {
+                BarLabel::None => String::new(),
+                BarLabel::Text(text) => text.clone(),
+                BarLabel::Percent => {
+                    match state.indeterminate.is_some() {
+                        true => String::new(),
+                        false => format!("{:.0}%", state.progress * 100.0),
+                    }
+                },
+            };
+
+            (self.fill_style(&state), text)
+        };
+
+        self.fill.style_update(fill_style).expect_valid();
+
+        self.label
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                text,
+                text_color: Some(self.theme.text_color.clone()),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+    }
+}
+
+/// A rotating arc indicating activity.
+pub struct Spinner {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    theme: ProgressTheme,
+    started: Mutex<Option<Instant>>,
+}
+
+impl Spinner {
+    pub fn new(basalt: Arc<Basalt>, theme: ProgressTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        let container = basalt.interface_ref().new_bin();
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(theme.spinner_size),
+                height: Some(theme.spinner_size),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        Arc::new(Spinner {
+            basalt,
+            container,
+            theme,
+            started: Mutex::new(None),
+        })
+    }
+
+    /// Start rotating.
+    ///
+    /// # Notes
+    /// - The animation is skipped while the spinner isn't visible.
+    pub fn start(self: &Arc<Self>) {
+        {
+            let mut started = self.started.lock();
+
+            if started.is_some() {
+                return;
+            }
+
+            *started = Some(Instant::now());
+        }
+
+        self.basalt.interface_ref().progress_ticker().add(
+            &self.basalt,
+            self.theme.frame_interval,
+            self.container.id(),
+            Arc::downgrade(self) as Weak<dyn Animated>,
+        );
+    }
+
+    /// Stop rotating, hiding the arc.
+    pub fn stop(&self) {
+        let mut started = self.started.lock();
+
+        // The lock is held while the arc is removed, so that a frame can't be stored after.
+        if started.take().is_some() {
+            self.container
+                .style_update(self.arc_style(None))
+                .expect_valid();
+        }
+    }
+
+    pub fn is_spinning(&self) -> bool {
+        self.started.lock().is_some()
+    }
+
+    fn arc_style(&self, angle: Option<f32>) -> BinStyle {
+        let custom_verts = match angle {
+            Some(angle) => {
+                arc_verts(
+                    self.theme.spinner_size,
+                    self.theme.spinner_width,
+                    angle,
+                    &self.theme.fill_color,
+                )
+            },
+            None => Vec::new(),
+        };
+
+        BinStyle {
+            custom_verts,
+            ..self.container.style_copy()
+        }
+    }
+}
+
+/// A progress indicator animated by `ProgressTicker`.
+trait Animated: Send + Sync {
+    /// Store the style of the next frame without waking the composer.
+    ///
+    /// Returns `None` once the animation has stopped, otherwise whether a style was stored.
+    fn store_frame(&self) -> Option<bool>;
+}
+
+impl Animated for ProgressBar {
+    fn store_frame(&self) -> Option<bool> {
+        let state = self.state.lock();
+
+        if state.indeterminate.is_none() {
+            return None;
+        }
+
+        if !self.container.visible() {
+            return Some(false);
+        }
+
+        self.fill
+            .style_store(self.fill_style(&state))
+            .expect_valid();
+
+        Some(true)
+    }
+}
+
+impl Animated for Spinner {
+    fn store_frame(&self) -> Option<bool> {
+        let started = self.started.lock();
+        let t = (started.as_ref()?.elapsed().as_secs_f32() / SPIN_PERIOD).fract();
+
+        if !self.container.visible() {
+            return Some(false);
+        }
+
+        self.container
+            .style_store(self.arc_style(Some(t * TAU)))
+            .expect_valid();
+
+        Some(true)
+    }
+}
+
+/// Animates the `ProgressBar`'s & `Spinner`'s of an `Interface`.
+///
+/// Indicators sharing a `frame_interval` are animated by a single `Interval` hook. Each frame
+/// stores the styles of all of them before waking the composer once, instead of every indicator
+/// causing its own rebuild.
+pub(crate) struct ProgressTicker {
+    groups: Mutex<BTreeMap<Duration, TickGroup>>,
+}
+
+struct TickGroup {
+    intvl_id: IntvlHookID,
+    animated: BTreeMap<BinID, Weak<dyn Animated>>,
+}
+
+impl ProgressTicker {
+    pub(crate) fn new() -> Self {
+        ProgressTicker {
+            groups: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Animate an indicator until its `store_frame` returns `None`.
+    ///
+    /// # Notes
+    /// - The indicator must not hold the lock used by `store_frame` while calling this.
+    fn add(
+        &self,
+        basalt: &Arc<Basalt>,
+        frame_interval: Duration,
+        id: BinID,
+        animated: Weak<dyn Animated>,
+    ) {
+        let mut groups = self.groups.lock();
+
+        let group = groups.entry(frame_interval).or_insert_with(|| {
+            let basalt_wk = Arc::downgrade(basalt);
+
+            let intvl_id = basalt
+                .interval_ref()
+                .do_every(frame_interval, None, move |_| {
+                    match basalt_wk.upgrade() {
+                        Some(basalt) => {
+                            basalt
+                                .interface_ref()
+                                .progress_ticker()
+                                .tick(&basalt, frame_interval)
+                        },
+                        None => IntvlHookCtrl::Remove,
+                    }
+                });
+
+            TickGroup {
+                intvl_id,
+                animated: BTreeMap::new(),
+            }
+        });
+
+        group.animated.insert(id, animated);
+        basalt.interval_ref().start(group.intvl_id);
+    }
+
+    fn tick(&self, basalt: &Arc<Basalt>, frame_interval: Duration) -> IntvlHookCtrl {
+        let mut groups = self.groups.lock();
+
+        let group = match groups.get_mut(&frame_interval) {
+            Some(some) => some,
+            None => return IntvlHookCtrl::Pause,
+        };
+
+        let mut stored = false;
+
+        group.animated.retain(|_, animated_wk| {
+            match animated_wk
+                .upgrade()
+                .and_then(|animated| animated.store_frame())
+            {
+                Some(frame_stored) => {
+                    stored |= frame_stored;
+                    true
+                },
+                None => false,
+            }
+        });
+
+        if stored {
+            basalt.interface_ref().composer_ref().unpark();
+        }
+
+        match group.animated.is_empty() {
+            true => IntvlHookCtrl::Pause,
+            false => IntvlHookCtrl::Continue,
+        }
+    }
+}
+
+/// Vertexes of an arc spanning three quarters of a circle starting at `angle`.
+///
+/// Each quarter is a curve with its control point where the tangents of its ends meet, the same
+/// as the corners of a `Bin` with a border radius.
+fn arc_verts(size: f32, width: f32, angle: f32, color: &bin::Color) -> Vec<BinVert> {
+    let center = size / 2.0;
+    let radius = center - (width / 2.0);
+    let corner = radius / (PI / 4.0).cos();
+    let point = |a: f32, r: f32| (center + (a.cos() * r), center + (a.sin() * r));
+    let mut verts = Vec::new();
+
+    for quarter in 0..3 {
+        let a0 = angle + (quarter as f32 * FRAC_PI_2);
+
+        let segments = curve_line_segments(
+            point(a0, radius),
+            point(a0 + (FRAC_PI_2 / 2.0), corner),
+            point(a0 + FRAC_PI_2, radius),
+        );
+
+        // Offset each point along its direction from the center to give the arc thickness.
+        let offset = |(x, y): (f32, f32), by: f32| {
+            let (dx, dy) = (x - center, y - center);
+            let len = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+            (x + (dx / len * by), y + (dy / len * by))
+        };
+
+        for (a, b) in segments {
+            let (ai, ao) = (offset(a, width / -2.0), offset(a, width / 2.0));
+            let (bi, bo) = (offset(b, width / -2.0), offset(b, width / 2.0));
+
+            for (x, y) in [ai, ao, bi, bi, ao, bo] {
+                verts.push(BinVert {
+                    position: (x, y, 0),
+                    color: color.clone(),
+                });
+            }
+        }
+    }
+
+    verts
+}
@@ -0,0 +1,463 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::Basalt;
+
+impl KeepAlive for RadioGroup {}
+
+/// How the options of a `RadioGroup` are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadioGroupStyle {
+    /// A vertical list of options with a round indicator next to their label.
+    Radio,
+    /// A horizontal row of separate buttons.
+    Buttons,
+    /// A horizontal row of joined buttons.
+    Segmented,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadioGroupTheme {
+    /// Color of an option when not selected
+    pub color1: bin::Color,
+    /// Color of an option when selected
+    pub color2: bin::Color,
+    /// Color of the indicator's ring & the border of buttons
+    pub color3: bin::Color,
+    /// Color of the text when not selected
+    pub color4: bin::Color,
+    /// Color of the text when selected
+    pub color5: bin::Color,
+}
+
+impl Default for RadioGroupTheme {
+    fn default() -> Self {
+        RadioGroupTheme {
+            color1: bin::Color::srgb_hex("404040d0"),
+            color2: bin::Color::srgb_hex("00ff00d0"),
+            color3: bin::Color::srgb_hex("000000f0"),
+            color4: bin::Color::srgb_hex("ffffffff"),
+            color5: bin::Color::srgb_hex("ffffffff"),
+        }
+    }
+}
+
+const OPTION_HEIGHT: f32 = 24.0;
+const OPTION_WIDTH: f32 = 80.0;
+const LIST_WIDTH: f32 = 150.0;
+const INDICATOR_SIZE: f32 = 14.0;
+const MARK_SIZE: f32 = 8.0;
+const TEXT_HEIGHT: f32 = 12.0;
+
+/// A group of options where only one can be selected at a time.
+///
+/// Clicking an option selects it and while an option is focused, the arrow keys move the
+/// selection to the previous or next option.
+pub struct RadioGroup {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    theme: RadioGroupTheme,
+    style: RadioGroupStyle,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(usize) + Send + 'static>>>,
+}
+
+struct State {
+    options: Vec<RadioOption>,
+    selected: Option<usize>,
+}
+
+struct RadioOption {
+    label: String,
+    bin: Arc<Bin>,
+    indicator: Arc<Bin>,
+    mark: Arc<Bin>,
+}
+
+impl RadioGroup {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: RadioGroupTheme,
+        style: RadioGroupStyle,
+        parent: Option<Arc<Bin>>,
+    ) -> Arc<Self> {
+        let container = basalt.interface_ref().new_bin();
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(0.0),
+                height: Some(0.0),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        Arc::new(RadioGroup {
+            basalt,
+            container,
+            theme,
+            style,
+            state: Mutex::new(State {
+                options: Vec::new(),
+                selected: None,
+            }),
+            on_change: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn style(&self) -> RadioGroupStyle {
+        self.style
+    }
+
+    /// Add an option returning its index.
+    ///
+    /// # Notes
+    /// - The container's `width` & `height` are set to fit the options, they can be changed
+    /// afterwards as the options are sized relative to the container.
+    pub fn add_option<T: Into<String>>(self: &Arc<Self>, label: T) -> usize {
+        let mut bins = self.basalt.interface_ref().new_bins(3);
+        let bin = bins.pop().unwrap();
+        let indicator = bins.pop().unwrap();
+        let mark = bins.pop().unwrap();
+        indicator.add_child(mark.clone());
+        bin.add_child(indicator.clone());
+        self.container.add_child(bin.clone());
+
+        let index = {
+            let mut state = self.state.lock();
+
+            state.options.push(RadioOption {
+                label: label.into(),
+                bin: bin.clone(),
+                indicator: indicator.clone(),
+                mark: mark.clone(),
+            });
+
+            state.options.len() - 1
+        };
+
+        // The press focuses the top-most bin, so every bin of the option needs the hooks.
+        for target in [bin, indicator, mark] {
+            let group_wk = Arc::downgrade(self);
+
+            target.on_press(MouseButton::Left, move |_, _, _| {
+                match group_wk.upgrade() {
+                    Some(group) => {
+                        group.select(index);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+
+            for key in [
+                Qwerty::ArrowUp,
+                Qwerty::ArrowDown,
+                Qwerty::ArrowLeft,
+                Qwerty::ArrowRight,
+            ] {
+                let group_wk = Arc::downgrade(self);
+
+                target.on_press(key, move |_, _, _| {
+                    let group = match group_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    match key {
+                        Qwerty::ArrowUp | Qwerty::ArrowLeft => group.select_previous(),
+                        _ => group.select_next(),
+                    }
+
+                    Default::default()
+                });
+            }
+        }
+
+        self.update();
+        index
+    }
+
+    /// Remove all options and add the provided ones.
+    pub fn set_options<T: Into<String>, I: IntoIterator<Item = T>>(self: &Arc<Self>, labels: I) {
+        {
+            let mut state = self.state.lock();
+            state.selected = None;
+
+            for option in state.options.drain(..) {
+                self.container.remove_child(&option.bin);
+            }
+        }
+
+        for label in labels {
+            self.add_option(label);
+        }
+
+        self.update();
+    }
+
+    /// The amount of options.
+    pub fn len(&self) -> usize {
+        self.state.lock().options.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn label(&self, index: usize) -> Option<String> {
+        self.state
+            .lock()
+            .options
+            .get(index)
+            .map(|option| option.label.clone())
+    }
+
+    /// The index of the selected option.
+    pub fn selected(&self) -> Option<usize> {
+        self.state.lock().selected
+    }
+
+    /// Select an option.
+    ///
+    /// Returns `false` if the index is out of range.
+    pub fn select(&self, index: usize) -> bool {
+        {
+            let mut state = self.state.lock();
+
+            if index >= state.options.len() {
+                return false;
+            }
+
+            if state.selected == Some(index) {
+                return true;
+            }
+
+            state.selected = Some(index);
+        }
+
+        self.update();
+
+        for func in self.on_change.lock().iter_mut() {
+            func(index);
+        }
+
+        true
+    }
+
+    /// Select the option before the selected one, wrapping around to the last.
+    pub fn select_previous(&self) {
+        let (selected, len) = {
+            let state = self.state.lock();
+            (state.selected, state.options.len())
+        };
+
+        if len > 0 {
+            self.select(match selected {
+                Some(0) | None => len - 1,
+                Some(i) => i - 1,
+            });
+        }
+    }
+
+    /// Select the option after the selected one, wrapping around to the first.
+    pub fn select_next(&self) {
+        let (selected, len) = {
+            let state = self.state.lock();
+            (state.selected, state.options.len())
+        };
+
+        if len > 0 {
+            self.select(match selected {
+                Some(i) => (i + 1) % len,
+                None => 0,
+            });
+        }
+    }
+
+    /// Add a function to be called when the selected option changes.
+    pub fn on_change<F: FnMut(usize) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    fn update(&self) {
+        let state = self.state.lock();
+        let count = state.options.len();
+
+        let (width, height) = match self.style {
+            RadioGroupStyle::Radio => (LIST_WIDTH, OPTION_HEIGHT * count as f32),
+            RadioGroupStyle::Buttons | RadioGroupStyle::Segmented => {
+                (OPTION_WIDTH * count as f32, OPTION_HEIGHT)
+            },
+        };
+
+        self.container
+            .style_update(BinStyle {
+                width: Some(width),
+                height: Some(height),
+                ..self.container.style_copy()
+            })
+            .expect_valid();
+
+        let pct = 100.0 / count.max(1) as f32;
+
+        for (i, option) in state.options.iter().enumerate() {
+            let selected = state.selected == Some(i);
+
+            let text_color = match selected {
+                true => self.theme.color5.clone(),
+                false => self.theme.color4.clone(),
+            };
+
+            match self.style {
+                RadioGroupStyle::Radio => {
+                    option
+                        .bin
+                        .style_update(BinStyle {
+                            position: Some(BinPosition::Parent),
+                            pos_from_t_pct: Some(pct * i as f32),
+                            pos_from_l: Some(0.0),
+                            pos_from_r: Some(0.0),
+                            height_pct: Some(pct),
+                            pad_l: Some(INDICATOR_SIZE + 8.0),
+                            text: option.label.clone(),
+                            text_color: Some(text_color),
+                            text_height: Some(TEXT_HEIGHT),
+                            text_vert_align: Some(TextVertAlign::Center),
+                            text_hori_align: Some(TextHoriAlign::Left),
+                            text_wrap: Some(TextWrap::None),
+                            ..BinStyle::default()
+                        })
+                        .expect_valid();
+
+                    let radius = INDICATOR_SIZE / 2.0;
+
+                    option
+                        .indicator
+                        .style_update(BinStyle {
+                            position: Some(BinPosition::Parent),
+                            pos_from_t_pct: Some(50.0),
+                            pos_from_t_offset: Some(-radius),
+                            pos_from_l: Some(1.0),
+                            width: Some(INDICATOR_SIZE),
+                            height: Some(INDICATOR_SIZE),
+                            back_color: Some(self.theme.color1.clone()),
+                            border_size_t: Some(1.0),
+                            border_size_b: Some(1.0),
+                            border_size_l: Some(1.0),
+                            border_size_r: Some(1.0),
+                            border_color_t: Some(self.theme.color3.clone()),
+                            border_color_b: Some(self.theme.color3.clone()),
+                            border_color_l: Some(self.theme.color3.clone()),
+                            border_color_r: Some(self.theme.color3.clone()),
+                            border_radius_tl: Some(radius),
+                            border_radius_tr: Some(radius),
+                            border_radius_bl: Some(radius),
+                            border_radius_br: Some(radius),
+                            ..BinStyle::default()
+                        })
+                        .expect_valid();
+
+                    let offset = (INDICATOR_SIZE - MARK_SIZE) / 2.0;
+                    let radius = MARK_SIZE / 2.0;
+
+                    option
+                        .mark
+                        .style_update(BinStyle {
+                            position: Some(BinPosition::Parent),
+                            hidden: Some(!selected),
+                            pos_from_t: Some(offset),
+                            pos_from_l: Some(offset),
+                            width: Some(MARK_SIZE),
+                            height: Some(MARK_SIZE),
+                            back_color: Some(self.theme.color2.clone()),
+                            border_radius_tl: Some(radius),
+                            border_radius_tr: Some(radius),
+                            border_radius_bl: Some(radius),
+                            border_radius_br: Some(radius),
+                            ..BinStyle::default()
+                        })
+                        .expect_valid();
+                },
+                RadioGroupStyle::Buttons | RadioGroupStyle::Segmented => {
+                    let segmented = self.style == RadioGroupStyle::Segmented;
+                    let first = i == 0;
+                    let last = i + 1 == count;
+
+                    // Separate buttons have a gap & are all rounded, while segmented buttons
+                    // are joined with only the outer corners rounded.
+                    let (offset, radius_l, radius_r) = match segmented {
+                        true => {
+                            (
+                                0.0,
+                                if first { 3.0 } else { 0.0 },
+                                if last { 3.0 } else { 0.0 },
+                            )
+                        },
+                        false => (1.0, 3.0, 3.0),
+                    };
+
+                    let border_l = match segmented && !first {
+                        true => Some(1.0),
+                        false => None,
+                    };
+
+                    option
+                        .bin
+                        .style_update(BinStyle {
+                            position: Some(BinPosition::Parent),
+                            pos_from_t: Some(0.0),
+                            pos_from_b: Some(0.0),
+                            pos_from_l_pct: Some(pct * i as f32),
+                            pos_from_l_offset: Some(offset),
+                            width_pct: Some(pct),
+                            width_offset: Some(offset * -2.0),
+                            back_color: Some(match selected {
+                                true => self.theme.color2.clone(),
+                                false => self.theme.color1.clone(),
+                            }),
+                            border_size_l: border_l,
+                            border_color_l: Some(self.theme.color3.clone()),
+                            border_radius_tl: Some(radius_l),
+                            border_radius_bl: Some(radius_l),
+                            border_radius_tr: Some(radius_r),
+                            border_radius_br: Some(radius_r),
+                            text: option.label.clone(),
+                            text_color: Some(text_color),
+                            text_height: Some(TEXT_HEIGHT),
+                            text_vert_align: Some(TextVertAlign::Center),
+                            text_hori_align: Some(TextHoriAlign::Center),
+                            text_wrap: Some(TextWrap::None),
+                            ..BinStyle::default()
+                        })
+                        .expect_valid();
+
+                    for bin in [&option.indicator, &option.mark] {
+                        bin.style_update(BinStyle {
+                            hidden: Some(true),
+                            ..BinStyle::default()
+                        })
+                        .expect_valid();
+                    }
+                },
+            }
+        }
+
+        drop(state);
+        self.container.update_children();
+    }
+}
@@ -6,6 +6,7 @@ use crate::input::{InputHookCtrl, MouseButton};
 use crate::interface::bin::{self, Bin, BinPosition, BinStyle, BinVert};
 use crate::Basalt;
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct ScrollBarStyle {
     pub border_color: bin::Color,
     pub arrow_color: bin::Color,
@@ -31,33 +31,47 @@ struct Data {
 
 impl Data {
     fn apply_method(&mut self) {
-        match self.method {
-            Method::Float => return,
-            Method::RoundToStep => {
-                self.at -= self.min;
-                self.at /= self.step;
-                self.at = f32::round(self.at);
-                self.at *= self.step;
-                self.at += self.min;
-            },
-            Method::RoundToInt => {
-                self.at = f32::round(self.at);
-            },
-        }
-        if self.at > self.max {
-            self.at = self.max;
-        } else if self.at < self.min {
-            self.at = self.min;
-        }
+        self.at = self.method.apply(self.at, self.min, self.max, self.step);
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Method {
     Float,
     RoundToStep,
     RoundToInt,
 }
 
+impl Method {
+    /// Round the value according to the method and limit it to `min` & `max`.
+    ///
+    /// # Notes
+    /// - `Float` returns the value as is.
+    pub(crate) fn apply(self, mut val: f32, min: f32, max: f32, step: f32) -> f32 {
+        match self {
+            Method::Float => return val,
+            Method::RoundToStep => {
+                val -= min;
+                val /= step;
+                val = f32::round(val);
+                val *= step;
+                val += min;
+            },
+            Method::RoundToInt => {
+                val = f32::round(val);
+            },
+        }
+
+        if val > max {
+            max
+        } else if val < min {
+            min
+        } else {
+            val
+        }
+    }
+}
+
 impl Drop for Slider {
     fn drop(&mut self) {
         let mut hooks = self.hooks.lock();
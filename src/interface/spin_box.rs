@@ -0,0 +1,557 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interface::slider::Method;
+use crate::Basalt;
+
+impl KeepAlive for SpinBox {}
+
+/// How long a button has to be held before the value starts repeating.
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+/// How often the value changes while a button is held.
+const REPEAT_INTERVAL: Duration = Duration::from_millis(60);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpinBoxTheme {
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    /// Color of the border & text while the entered text isn't valid.
+    pub error_color: bin::Color,
+    pub text_color: bin::Color,
+    pub button_color: bin::Color,
+    pub arrow_color: bin::Color,
+    pub text_height: f32,
+    /// Width of the increment & decrement buttons.
+    pub button_width: f32,
+}
+
+impl Default for SpinBoxTheme {
+    fn default() -> Self {
+        SpinBoxTheme {
+            back_color: bin::Color::srgb_hex("202024"),
+            border_color: bin::Color::srgb_hex("505058"),
+            error_color: bin::Color::srgb_hex("e04040"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            button_color: bin::Color::srgb_hex("303036"),
+            arrow_color: bin::Color::srgb_hex("c0c0c8"),
+            text_height: 12.0,
+            button_width: 16.0,
+        }
+    }
+}
+
+/// A numeric input with increment & decrement buttons.
+///
+/// The value can be changed with the buttons, which repeat while held, the arrow keys and the
+/// mouse wheel while hovered, or by typing a value and pressing `Enter`. Typed values that
+/// can't be parsed or are out of range are displayed with `SpinBoxTheme::error_color`.
+pub struct SpinBox {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub input: Arc<Bin>,
+    pub increment_button: Arc<Bin>,
+    pub decrement_button: Arc<Bin>,
+    theme: SpinBoxTheme,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(f32) + Send + 'static>>>,
+}
+
+struct State {
+    min: f32,
+    max: f32,
+    step: f32,
+    method: Method,
+    value: f32,
+    decimals: usize,
+    /// Minimum amount of digits before the decimal point.
+    digits: usize,
+    unit: String,
+    /// The text being entered, `None` while not editing.
+    editing: Option<String>,
+}
+
+impl State {
+    fn constrain(&self, value: f32) -> f32 {
+        self.method
+            .apply(value, self.min, self.max, self.step)
+            .clamp(self.min, self.max)
+    }
+
+    fn format(&self) -> String {
+        let width = match self.decimals {
+            0 => self.digits,
+            decimals => self.digits + decimals + 1,
+        };
+
+        format!(
+            "{:0width$.decimals$}{}",
+            self.value,
+            self.unit,
+            width = width,
+            decimals = self.decimals
+        )
+    }
+
+    fn parse(&self, text: &str) -> Option<f32> {
+        let mut text = text.trim();
+        let unit = self.unit.trim();
+
+        if !unit.is_empty() {
+            text = text.strip_suffix(unit).unwrap_or(text).trim_end();
+        }
+
+        let value = text.parse::<f32>().ok()?;
+
+        if !value.is_finite() || value < self.min || value > self.max {
+            return None;
+        }
+
+        Some(self.constrain(value))
+    }
+}
+
+impl SpinBox {
+    pub fn new(basalt: Arc<Basalt>, theme: SpinBoxTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(4);
+        let container = bins.pop().unwrap();
+        let input = bins.pop().unwrap();
+        let increment_button = bins.pop().unwrap();
+        let decrement_button = bins.pop().unwrap();
+
+        container.add_children(vec![
+            input.clone(),
+            increment_button.clone(),
+            decrement_button.clone(),
+        ]);
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(100.0),
+                height: Some(24.0),
+                back_color: Some(theme.back_color.clone()),
+                border_size_t: Some(1.0),
+                border_size_b: Some(1.0),
+                border_size_l: Some(1.0),
+                border_size_r: Some(1.0),
+                border_color_t: Some(theme.border_color.clone()),
+                border_color_b: Some(theme.border_color.clone()),
+                border_color_l: Some(theme.border_color.clone()),
+                border_color_r: Some(theme.border_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let spin_box = Arc::new(SpinBox {
+            basalt,
+            container,
+            input,
+            increment_button,
+            decrement_button,
+            theme,
+            state: Mutex::new(State {
+                min: 0.0,
+                max: 100.0,
+                step: 1.0,
+                method: Method::RoundToStep,
+                value: 0.0,
+                decimals: 0,
+                digits: 0,
+                unit: String::new(),
+                editing: None,
+            }),
+            on_change: Mutex::new(Vec::new()),
+        });
+
+        for (button, amount) in [
+            (&spin_box.increment_button, 1.0),
+            (&spin_box.decrement_button, -1.0),
+        ] {
+            let spin_box_wk = Arc::downgrade(&spin_box);
+
+            button.on_press(MouseButton::Left, move |_, _, _| {
+                match spin_box_wk.upgrade() {
+                    Some(spin_box) => {
+                        spin_box.step_by(amount);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+
+            let spin_box_wk = Arc::downgrade(&spin_box);
+
+            spin_box
+                .basalt
+                .input_ref()
+                .hook()
+                .bin(button)
+                .on_hold()
+                .keys(MouseButton::Left)
+                .delay(Some(REPEAT_DELAY))
+                .interval(REPEAT_INTERVAL)
+                .call(move |_, _, _| {
+                    match spin_box_wk.upgrade() {
+                        Some(spin_box) => {
+                            spin_box.step_by(amount);
+                            Default::default()
+                        },
+                        None => InputHookCtrl::Remove,
+                    }
+                })
+                .finish()
+                .unwrap();
+        }
+
+        let spin_box_wk = Arc::downgrade(&spin_box);
+
+        // Scroll hooks don't require the bin to be on top, so this includes the input & buttons.
+        spin_box.container.on_scroll(move |_, _, amt, _| {
+            let spin_box = match spin_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if amt < 0.0 {
+                spin_box.step_by(1.0);
+            } else if amt > 0.0 {
+                spin_box.step_by(-1.0);
+            }
+
+            Default::default()
+        });
+
+        for (key, amount) in [(Qwerty::ArrowUp, 1.0), (Qwerty::ArrowDown, -1.0)] {
+            let spin_box_wk = Arc::downgrade(&spin_box);
+
+            spin_box.input.on_press(key, move |_, _, _| {
+                match spin_box_wk.upgrade() {
+                    Some(spin_box) => {
+                        spin_box.commit();
+                        spin_box.step_by(amount);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+        }
+
+        let spin_box_wk = Arc::downgrade(&spin_box);
+
+        spin_box.input.on_press(Qwerty::Esc, move |_, _, _| {
+            match spin_box_wk.upgrade() {
+                Some(spin_box) => {
+                    spin_box.state.lock().editing = None;
+                    spin_box.update();
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        let spin_box_wk = Arc::downgrade(&spin_box);
+
+        spin_box.input.on_character(move |_, _, c| {
+            let spin_box = match spin_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if c.is_new_line() {
+                spin_box.commit();
+                return Default::default();
+            }
+
+            if c.0.is_control() && !c.is_backspace() {
+                return Default::default();
+            }
+
+            {
+                let mut state = spin_box.state.lock();
+
+                // Editing starts from the value without its unit.
+                let mut text = match state.editing.take() {
+                    Some(some) => some,
+                    None => format!("{:.*}", state.decimals, state.value),
+                };
+
+                c.modify_string(&mut text);
+                state.editing = Some(text);
+            }
+
+            spin_box.update();
+            Default::default()
+        });
+
+        let spin_box_wk = Arc::downgrade(&spin_box);
+
+        spin_box.input.on_focus_lost(move |_, _| {
+            let spin_box = match spin_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            // Invalid text is discarded once the input loses focus.
+            if !spin_box.commit() {
+                spin_box.state.lock().editing = None;
+                spin_box.update();
+            }
+
+            Default::default()
+        });
+
+        spin_box.update();
+        spin_box
+    }
+
+    /// The current value.
+    pub fn value(&self) -> f32 {
+        self.state.lock().value
+    }
+
+    /// Set the value.
+    ///
+    /// The value is rounded according to the method and limited to the min & max.
+    pub fn set(&self, value: f32) {
+        let changed = {
+            let mut state = self.state.lock();
+            let value = state.constrain(value);
+            let changed = value != state.value;
+            state.value = value;
+            state.editing = None;
+            changed.then_some(value)
+        };
+
+        self.update();
+
+        if let Some(value) = changed {
+            for func in self.on_change.lock().iter_mut() {
+                func(value);
+            }
+        }
+    }
+
+    /// Increase the value by the step size.
+    pub fn increment(&self) {
+        self.step_by(1.0);
+    }
+
+    /// Decrease the value by the step size.
+    pub fn decrement(&self) {
+        self.step_by(-1.0);
+    }
+
+    /// **Default**: `0.0` & `100.0`
+    pub fn set_min_max(&self, min: f32, max: f32) {
+        {
+            let mut state = self.state.lock();
+            state.min = min;
+            state.max = max;
+        }
+
+        self.set(self.value());
+    }
+
+    pub fn min_max(&self) -> (f32, f32) {
+        let state = self.state.lock();
+        (state.min, state.max)
+    }
+
+    /// **Default**: `1.0`
+    pub fn set_step_size(&self, step: f32) {
+        self.state.lock().step = step;
+        self.set(self.value());
+    }
+
+    /// Set how values are rounded. See `Slider::set_method`.
+    ///
+    /// **Default**: `Method::RoundToStep`
+    pub fn set_method(&self, method: Method) {
+        self.state.lock().method = method;
+        self.set(self.value());
+    }
+
+    /// Set the amount of decimals displayed.
+    ///
+    /// **Default**: `0`
+    pub fn set_decimals(&self, decimals: usize) {
+        self.state.lock().decimals = decimals;
+        self.update();
+    }
+
+    /// Set the minimum amount of digits displayed before the decimal point. The value is padded
+    /// with leading zeros.
+    ///
+    /// **Default**: `0`
+    pub fn set_digits(&self, digits: usize) {
+        self.state.lock().digits = digits;
+        self.update();
+    }
+
+    /// Set text displayed after the value, e.g. `" px"` or `"%"`.
+    ///
+    /// Typed values may include the unit.
+    pub fn set_unit<T: Into<String>>(&self, unit: T) {
+        self.state.lock().unit = unit.into();
+        self.update();
+    }
+
+    /// Returns `false` if the text being entered isn't a valid value.
+    pub fn is_valid(&self) -> bool {
+        let state = self.state.lock();
+
+        match state.editing.as_ref() {
+            Some(text) => state.parse(text).is_some(),
+            None => true,
+        }
+    }
+
+    /// Add a function to be called when the value changes.
+    pub fn on_change<F: FnMut(f32) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    fn step_by(&self, amount: f32) {
+        let value = {
+            let state = self.state.lock();
+            state.value + (state.step * amount)
+        };
+
+        self.set(value);
+    }
+
+    /// Apply the text being entered. Returns `false` if it isn't valid.
+    fn commit(&self) -> bool {
+        let value = {
+            let state = self.state.lock();
+
+            match state.editing.as_ref() {
+                Some(text) => state.parse(text),
+                None => return true,
+            }
+        };
+
+        match value {
+            Some(value) => {
+                self.set(value);
+                true
+            },
+            None => {
+                self.update();
+                false
+            },
+        }
+    }
+
+    fn update(&self) {
+        let state = self.state.lock();
+
+        let (text, valid) = match state.editing.as_ref() {
+            Some(text) => (text.clone(), state.parse(text).is_some()),
+            None => (state.format(), true),
+        };
+
+        drop(state);
+
+        let (border_color, text_color) = match valid {
+            true => {
+                (
+                    self.theme.border_color.clone(),
+                    self.theme.text_color.clone(),
+                )
+            },
+            false => {
+                (
+                    self.theme.error_color.clone(),
+                    self.theme.error_color.clone(),
+                )
+            },
+        };
+
+        self.container
+            .style_update(BinStyle {
+                border_color_t: Some(border_color.clone()),
+                border_color_b: Some(border_color.clone()),
+                border_color_l: Some(border_color.clone()),
+                border_color_r: Some(border_color),
+                ..self.container.style_copy()
+            })
+            .expect_valid();
+
+        let button_w = self.theme.button_width;
+
+        self.input
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(button_w),
+                pad_l: Some(4.0),
+                pad_r: Some(4.0),
+                text,
+                text_color: Some(text_color),
+                text_height: Some(self.theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Left),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        // The arrows are centered within the buttons based on the container's height.
+        let button_h = self.container.style_copy().height.unwrap_or(24.0) / 2.0;
+        let (cx, cy) = (button_w / 2.0, button_h / 2.0);
+
+        for (button, up) in [
+            (&self.increment_button, true),
+            (&self.decrement_button, false),
+        ] {
+            let dir = if up { -1.0 } else { 1.0 };
+
+            let custom_verts = [
+                (cx - 4.0, cy - (2.0 * dir)),
+                (cx + 4.0, cy - (2.0 * dir)),
+                (cx, cy + (2.0 * dir)),
+            ]
+            .into_iter()
+            .map(|(x, y)| {
+                BinVert {
+                    position: (x, y, 0),
+                    color: self.theme.arrow_color.clone(),
+                }
+            })
+            .collect();
+
+            button
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t_pct: Some(if up { 0.0 } else { 50.0 }),
+                    pos_from_r: Some(0.0),
+                    width: Some(button_w),
+                    height_pct: Some(50.0),
+                    back_color: Some(self.theme.button_color.clone()),
+                    custom_verts,
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+        }
+
+        self.container.update_children();
+    }
+}
@@ -0,0 +1,420 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, InputHookID, MouseButton};
+use crate::interface::bin::{self, Bin, BinPosition, BinStyle, KeepAlive};
+use crate::Basalt;
+
+impl KeepAlive for SplitPane {}
+
+/// How the panes of a `SplitPane` are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitDirection {
+    /// The first pane is on the left and the second on the right.
+    Horizontal,
+    /// The first pane is on the top and the second on the bottom.
+    Vertical,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPaneTheme {
+    pub divider_color: bin::Color,
+    /// Color of the divider while it is being dragged.
+    pub divider_drag_color: bin::Color,
+    /// Thickness of the divider.
+    pub divider_size: f32,
+}
+
+impl Default for SplitPaneTheme {
+    fn default() -> Self {
+        SplitPaneTheme {
+            divider_color: bin::Color::srgb_hex("1e1e22"),
+            divider_drag_color: bin::Color::srgb_hex("3a5a8c"),
+            divider_size: 4.0,
+        }
+    }
+}
+
+/// Two panes separated by a divider that can be dragged to resize them.
+///
+/// The size of the panes is defined by a ratio of the container's size, so the panes keep their
+/// proportions when the container is resized.
+pub struct SplitPane {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    /// The left or top pane.
+    pub first: Arc<Bin>,
+    /// The right or bottom pane.
+    pub second: Arc<Bin>,
+    pub divider: Arc<Bin>,
+    direction: SplitDirection,
+    theme: SplitPaneTheme,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(f32) + Send + 'static>>>,
+    hooks: Mutex<Vec<InputHookID>>,
+}
+
+struct State {
+    ratio: f32,
+    min_first: f32,
+    min_second: f32,
+    drag: Option<Drag>,
+}
+
+struct Drag {
+    start: f32,
+    start_ratio: f32,
+}
+
+impl Drop for SplitPane {
+    fn drop(&mut self) {
+        let mut hooks = self.hooks.lock();
+
+        for id in hooks.split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+    }
+}
+
+impl SplitPane {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: SplitPaneTheme,
+        parent: Option<Arc<Bin>>,
+        direction: SplitDirection,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(4);
+        let container = bins.pop().unwrap();
+        let first = bins.pop().unwrap();
+        let second = bins.pop().unwrap();
+        let divider = bins.pop().unwrap();
+        container.add_children(vec![first.clone(), second.clone(), divider.clone()]);
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let split = Arc::new(SplitPane {
+            basalt,
+            container,
+            first,
+            second,
+            divider,
+            direction,
+            theme,
+            state: Mutex::new(State {
+                ratio: 0.5,
+                min_first: 0.0,
+                min_second: 0.0,
+                drag: None,
+            }),
+            on_change: Mutex::new(Vec::new()),
+            hooks: Mutex::new(Vec::new()),
+        });
+
+        split.update_panes();
+        let split_wk = Arc::downgrade(&split);
+
+        split
+            .divider
+            .on_press(MouseButton::Left, move |_, window, _| {
+                let split = match split_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let start = split.cursor_along(window.cursor_pos());
+
+                {
+                    let mut state = split.state.lock();
+
+                    state.drag = Some(Drag {
+                        start,
+                        start_ratio: state.ratio,
+                    });
+                }
+
+                split.update_panes();
+                Default::default()
+            });
+
+        let window = split.basalt.window();
+        let split_wk = Arc::downgrade(&split);
+
+        let cursor_hook = split
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_cursor()
+            .call(move |_, window, _| {
+                let split = match split_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let (start, start_ratio) = match split.state.lock().drag.as_ref() {
+                    Some(drag) => (drag.start, drag.start_ratio),
+                    None => return Default::default(),
+                };
+
+                let length = split.length();
+
+                if length > 0.0 {
+                    let delta = split.cursor_along(window.cursor_pos()) - start;
+                    split.set_ratio(start_ratio + (delta / length));
+                }
+
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        let split_wk = Arc::downgrade(&split);
+
+        let release_hook = split
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_release()
+            .keys(MouseButton::Left)
+            .call(move |_, _, _| {
+                let split = match split_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                if split.state.lock().drag.take().is_some() {
+                    split.update_panes();
+                }
+
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        split.hooks.lock().extend([cursor_hook, release_hook]);
+        split
+    }
+
+    pub fn direction(&self) -> SplitDirection {
+        self.direction
+    }
+
+    /// The size of the first pane relative to the container from `0.0` to `1.0`.
+    pub fn ratio(&self) -> f32 {
+        self.state.lock().ratio
+    }
+
+    /// Set the size of the first pane relative to the container from `0.0` to `1.0`.
+    ///
+    /// # Notes
+    /// - The ratio is limited by the minimum sizes of the panes.
+    pub fn set_ratio(&self, ratio: f32) {
+        let ratio = self.clamp_ratio(ratio);
+
+        {
+            let mut state = self.state.lock();
+
+            if state.ratio == ratio {
+                return;
+            }
+
+            state.ratio = ratio;
+        }
+
+        self.update_panes();
+
+        for func in self.on_change.lock().iter_mut() {
+            func(ratio);
+        }
+    }
+
+    /// Set the minimum sizes of the panes.
+    ///
+    /// **Default**: `0.0` & `0.0`
+    pub fn set_min_sizes(&self, first: f32, second: f32) {
+        {
+            let mut state = self.state.lock();
+            state.min_first = first.max(0.0);
+            state.min_second = second.max(0.0);
+        }
+
+        let ratio = self.ratio();
+        self.set_ratio(ratio);
+    }
+
+    /// Add a function to be called when the ratio changes.
+    pub fn on_change<F: FnMut(f32) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    fn length(&self) -> f32 {
+        let post = self.container.post_update();
+
+        match self.direction {
+            SplitDirection::Horizontal => post.tri[0] - post.tli[0],
+            SplitDirection::Vertical => post.bli[1] - post.tli[1],
+        }
+    }
+
+    fn cursor_along(&self, cursor: [f32; 2]) -> f32 {
+        let scale = self.basalt.interface_ref().current_effective_scale();
+
+        match self.direction {
+            SplitDirection::Horizontal => cursor[0] / scale,
+            SplitDirection::Vertical => cursor[1] / scale,
+        }
+    }
+
+    fn clamp_ratio(&self, ratio: f32) -> f32 {
+        let length = self.length();
+        let ratio = ratio.clamp(0.0, 1.0);
+
+        if length <= 0.0 {
+            return ratio;
+        }
+
+        let (min_first, min_second) = {
+            let state = self.state.lock();
+            (state.min_first, state.min_second)
+        };
+
+        let half = self.theme.divider_size / 2.0;
+        let min = ((min_first + half) / length).min(1.0);
+        let max = (1.0 - ((min_second + half) / length)).max(0.0);
+
+        // If both minimums can't be met, the first pane takes priority.
+        ratio.min(max).max(min)
+    }
+
+    fn update_panes(&self) {
+        let (ratio, dragging) = {
+            let state = self.state.lock();
+            (state.ratio * 100.0, state.drag.is_some())
+        };
+
+        let size = self.theme.divider_size;
+        let half = size / 2.0;
+
+        let divider_color = match dragging {
+            true => self.theme.divider_drag_color.clone(),
+            false => self.theme.divider_color.clone(),
+        };
+
+        let (first, second, divider) = match self.direction {
+            SplitDirection::Horizontal => {
+                (
+                    BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t: Some(0.0),
+                        pos_from_b: Some(0.0),
+                        pos_from_l: Some(0.0),
+                        width_pct: Some(ratio),
+                        width_offset: Some(-half),
+                        ..BinStyle::default()
+                    },
+                    BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t: Some(0.0),
+                        pos_from_b: Some(0.0),
+                        pos_from_l_pct: Some(ratio),
+                        pos_from_l_offset: Some(half),
+                        pos_from_r: Some(0.0),
+                        ..BinStyle::default()
+                    },
+                    BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t: Some(0.0),
+                        pos_from_b: Some(0.0),
+                        pos_from_l_pct: Some(ratio),
+                        pos_from_l_offset: Some(-half),
+                        width: Some(size),
+                        back_color: Some(divider_color),
+                        ..BinStyle::default()
+                    },
+                )
+            },
+            SplitDirection::Vertical => {
+                (
+                    BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t: Some(0.0),
+                        pos_from_l: Some(0.0),
+                        pos_from_r: Some(0.0),
+                        height_pct: Some(ratio),
+                        height_offset: Some(-half),
+                        ..BinStyle::default()
+                    },
+                    BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t_pct: Some(ratio),
+                        pos_from_t_offset: Some(half),
+                        pos_from_b: Some(0.0),
+                        pos_from_l: Some(0.0),
+                        pos_from_r: Some(0.0),
+                        ..BinStyle::default()
+                    },
+                    BinStyle {
+                        position: Some(BinPosition::Parent),
+                        pos_from_t_pct: Some(ratio),
+                        pos_from_t_offset: Some(-half),
+                        pos_from_l: Some(0.0),
+                        pos_from_r: Some(0.0),
+                        height: Some(size),
+                        back_color: Some(divider_color),
+                        ..BinStyle::default()
+                    },
+                )
+            },
+        };
+
+        set_placement(&self.first, first);
+        set_placement(&self.second, second);
+        self.divider.style_update(divider).expect_valid();
+        self.container.update_children();
+    }
+}
+
+/// Replace the position & size fields of the `Bin`'s style with the ones of `placement`.
+fn set_placement(bin: &Arc<Bin>, placement: BinStyle) {
+    bin.style_update(BinStyle {
+        position: placement.position,
+        pos_from_t: placement.pos_from_t,
+        pos_from_b: placement.pos_from_b,
+        pos_from_l: placement.pos_from_l,
+        pos_from_r: placement.pos_from_r,
+        pos_from_t_pct: placement.pos_from_t_pct,
+        pos_from_b_pct: placement.pos_from_b_pct,
+        pos_from_l_pct: placement.pos_from_l_pct,
+        pos_from_r_pct: placement.pos_from_r_pct,
+        pos_from_l_offset: placement.pos_from_l_offset,
+        pos_from_t_offset: placement.pos_from_t_offset,
+        pos_from_r_offset: placement.pos_from_r_offset,
+        pos_from_b_offset: placement.pos_from_b_offset,
+        width: placement.width,
+        width_pct: placement.width_pct,
+        width_offset: placement.width_offset,
+        height: placement.height,
+        height_pct: placement.height_pct,
+        height_offset: placement.height_offset,
+        ..bin.style_copy()
+    })
+    .expect_valid();
+}
@@ -0,0 +1,643 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, InputHookID, MouseButton};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::Basalt;
+
+impl KeepAlive for TabContainer {}
+
+/// The distance the cursor has to move before a press on a tab becomes a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// An ID of a tab within a `TabContainer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TabID(u64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabContainerTheme {
+    /// Color of the area behind the content.
+    pub back_color: bin::Color,
+    /// Color of the strip containing the tabs.
+    pub strip_color: bin::Color,
+    pub tab_color: bin::Color,
+    pub selected_tab_color: bin::Color,
+    pub text_color: bin::Color,
+    /// Color of the close button's cross.
+    pub close_color: bin::Color,
+    pub text_height: f32,
+    /// Height of the strip containing the tabs.
+    pub strip_height: f32,
+    /// Space between tabs.
+    pub tab_spacing: f32,
+}
+
+impl Default for TabContainerTheme {
+    fn default() -> Self {
+        TabContainerTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            strip_color: bin::Color::srgb_hex("1e1e22"),
+            tab_color: bin::Color::srgb_hex("26262b"),
+            selected_tab_color: bin::Color::srgb_hex("2a2a30"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            close_color: bin::Color::srgb_hex("a0a0a8"),
+            text_height: 12.0,
+            strip_height: 26.0,
+            tab_spacing: 1.0,
+        }
+    }
+}
+
+/// A strip of tabs where only the content of the selected tab is displayed.
+///
+/// Tabs can be reordered by dragging them along the strip. Tabs that are dragged away from the
+/// strip call the functions added with `on_drag_out`, this is used by `Dock` to move them.
+pub struct TabContainer {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    /// The strip containing the tabs.
+    pub strip: Arc<Bin>,
+    /// The area containing the content of the tabs.
+    pub body: Arc<Bin>,
+    theme: TabContainerTheme,
+    state: Mutex<State>,
+    on_select: Mutex<Vec<Box<dyn FnMut(TabID) + Send + 'static>>>,
+    on_close: Mutex<Vec<Box<dyn FnMut(TabID, Arc<Bin>) + Send + 'static>>>,
+    on_drag_out: Mutex<Vec<Box<dyn FnMut(TabID, [f32; 2], bool) + Send + 'static>>>,
+    hooks: Mutex<Vec<InputHookID>>,
+}
+
+struct State {
+    next_id: u64,
+    tabs: Vec<Tab>,
+    selected: Option<TabID>,
+    drag: Option<Drag>,
+}
+
+struct Tab {
+    id: TabID,
+    title: String,
+    closable: bool,
+    tab: Arc<Bin>,
+    close: Arc<Bin>,
+    content: Arc<Bin>,
+}
+
+struct Drag {
+    id: TabID,
+    start: [f32; 2],
+    moved: bool,
+}
+
+impl Drop for TabContainer {
+    fn drop(&mut self) {
+        let mut hooks = self.hooks.lock();
+
+        for id in hooks.split_off(0) {
+            self.basalt.input_ref().remove_hook(id);
+        }
+    }
+}
+
+impl TabContainer {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: TabContainerTheme,
+        parent: Option<Arc<Bin>>,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(3);
+        let container = bins.pop().unwrap();
+        let strip = bins.pop().unwrap();
+        let body = bins.pop().unwrap();
+        container.add_children(vec![strip.clone(), body.clone()]);
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                back_color: Some(theme.back_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        strip
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(theme.strip_height),
+                back_color: Some(theme.strip_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        body.style_update(BinStyle {
+            position: Some(BinPosition::Parent),
+            pos_from_t: Some(theme.strip_height),
+            pos_from_b: Some(0.0),
+            pos_from_l: Some(0.0),
+            pos_from_r: Some(0.0),
+            ..BinStyle::default()
+        })
+        .expect_valid();
+
+        let tabs = Arc::new(TabContainer {
+            basalt,
+            container,
+            strip,
+            body,
+            theme,
+            state: Mutex::new(State {
+                next_id: 0,
+                tabs: Vec::new(),
+                selected: None,
+                drag: None,
+            }),
+            on_select: Mutex::new(Vec::new()),
+            on_close: Mutex::new(Vec::new()),
+            on_drag_out: Mutex::new(Vec::new()),
+            hooks: Mutex::new(Vec::new()),
+        });
+
+        let window = tabs.basalt.window();
+        let tabs_wk = Arc::downgrade(&tabs);
+
+        let cursor_hook = tabs
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_cursor()
+            .call(move |_, window, _| {
+                let tabs = match tabs_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                tabs.drag_update(tabs.logical_cursor(window.cursor_pos()), false);
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        let tabs_wk = Arc::downgrade(&tabs);
+
+        let release_hook = tabs
+            .basalt
+            .input_ref()
+            .hook()
+            .window(&window)
+            .on_release()
+            .keys(MouseButton::Left)
+            .call(move |_, window, _| {
+                let tabs = match tabs_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                tabs.drag_update(tabs.logical_cursor(window.cursor_pos()), true);
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        tabs.hooks.lock().extend([cursor_hook, release_hook]);
+        tabs
+    }
+
+    /// Add a tab to the end of the strip.
+    ///
+    /// # Notes
+    /// - The content is added as a child of `body` and its position & size fields are set to fill
+    /// it. Its `hidden` field is controlled by the container.
+    /// - The first tab added is selected.
+    pub fn add_tab<T: Into<String>>(
+        self: &Arc<Self>,
+        title: T,
+        content: Arc<Bin>,
+        closable: bool,
+    ) -> TabID {
+        let mut bins = self.basalt.interface_ref().new_bins(2);
+        let tab = bins.pop().unwrap();
+        let close = bins.pop().unwrap();
+        tab.add_child(close.clone());
+        self.strip.add_child(tab.clone());
+        self.body.add_child(content.clone());
+
+        content
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                pos_from_t_pct: None,
+                pos_from_b_pct: None,
+                pos_from_l_pct: None,
+                pos_from_r_pct: None,
+                pos_from_l_offset: None,
+                pos_from_t_offset: None,
+                pos_from_r_offset: None,
+                pos_from_b_offset: None,
+                width: None,
+                width_pct: None,
+                width_offset: None,
+                height: None,
+                height_pct: None,
+                height_offset: None,
+                ..content.style_copy()
+            })
+            .expect_valid();
+
+        let (id, select) = {
+            let mut state = self.state.lock();
+            let id = TabID(state.next_id);
+            state.next_id += 1;
+
+            state.tabs.push(Tab {
+                id,
+                title: title.into(),
+                closable,
+                tab: tab.clone(),
+                close: close.clone(),
+                content,
+            });
+
+            (id, state.selected.is_none())
+        };
+
+        let tabs_wk = Arc::downgrade(self);
+
+        tab.on_press(MouseButton::Left, move |_, window, _| {
+            let tabs = match tabs_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            tabs.state.lock().drag = Some(Drag {
+                id,
+                start: tabs.logical_cursor(window.cursor_pos()),
+                moved: false,
+            });
+
+            tabs.select(id);
+            Default::default()
+        });
+
+        let tabs_wk = Arc::downgrade(self);
+
+        close.on_press(MouseButton::Left, move |_, _, _| {
+            let tabs = match tabs_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            if let Some(content) = tabs.remove_tab(id) {
+                for func in tabs.on_close.lock().iter_mut() {
+                    func(id, content.clone());
+                }
+            }
+
+            Default::default()
+        });
+
+        if select {
+            self.select(id);
+        } else {
+            self.update_tabs();
+        }
+
+        id
+    }
+
+    /// Remove a tab returning its content.
+    ///
+    /// # Notes
+    /// - The functions added with `on_close` are not called.
+    /// - If the tab was selected, the next tab is selected.
+    pub fn remove_tab(&self, id: TabID) -> Option<Arc<Bin>> {
+        let (tab, select) = {
+            let mut state = self.state.lock();
+            let index = state.tabs.iter().position(|tab| tab.id == id)?;
+            let tab = state.tabs.remove(index);
+
+            if state
+                .drag
+                .as_ref()
+                .map(|drag| drag.id == id)
+                .unwrap_or(false)
+            {
+                state.drag = None;
+            }
+
+            let select = match state.selected == Some(id) {
+                true => {
+                    state.selected = None;
+
+                    state
+                        .tabs
+                        .get(index)
+                        .or_else(|| state.tabs.last())
+                        .map(|tab| tab.id)
+                },
+                false => None,
+            };
+
+            (tab, select)
+        };
+
+        self.strip.remove_child(&tab.tab);
+        self.body.remove_child(&tab.content);
+
+        match select {
+            Some(select) => self.select(select),
+            None => self.update_tabs(),
+        }
+
+        Some(tab.content)
+    }
+
+    /// Display the content of the tab.
+    pub fn select(&self, id: TabID) {
+        {
+            let mut state = self.state.lock();
+
+            if state.selected == Some(id) || !state.tabs.iter().any(|tab| tab.id == id) {
+                return;
+            }
+
+            state.selected = Some(id);
+        }
+
+        self.update_tabs();
+
+        for func in self.on_select.lock().iter_mut() {
+            func(id);
+        }
+    }
+
+    /// The tab that is selected.
+    pub fn selected(&self) -> Option<TabID> {
+        self.state.lock().selected
+    }
+
+    /// The tabs in the order they are displayed.
+    pub fn tabs(&self) -> Vec<TabID> {
+        self.state.lock().tabs.iter().map(|tab| tab.id).collect()
+    }
+
+    /// Move a tab to the index within the strip.
+    pub fn move_tab(&self, id: TabID, index: usize) {
+        {
+            let mut state = self.state.lock();
+
+            let from = match state.tabs.iter().position(|tab| tab.id == id) {
+                Some(some) => some,
+                None => return,
+            };
+
+            let to = index.min(state.tabs.len() - 1);
+
+            if from == to {
+                return;
+            }
+
+            let tab = state.tabs.remove(from);
+            state.tabs.insert(to, tab);
+        }
+
+        self.update_tabs();
+    }
+
+    pub fn title(&self, id: TabID) -> Option<String> {
+        self.state
+            .lock()
+            .tabs
+            .iter()
+            .find(|tab| tab.id == id)
+            .map(|tab| tab.title.clone())
+    }
+
+    pub fn set_title<T: Into<String>>(&self, id: TabID, title: T) {
+        if let Some(tab) = self.state.lock().tabs.iter_mut().find(|tab| tab.id == id) {
+            tab.title = title.into();
+        }
+
+        self.update_tabs();
+    }
+
+    /// The content of a tab.
+    pub fn content(&self, id: TabID) -> Option<Arc<Bin>> {
+        self.state
+            .lock()
+            .tabs
+            .iter()
+            .find(|tab| tab.id == id)
+            .map(|tab| tab.content.clone())
+    }
+
+    /// Add a function to be called when a tab is selected.
+    pub fn on_select<F: FnMut(TabID) + Send + 'static>(&self, func: F) {
+        self.on_select.lock().push(Box::new(func));
+    }
+
+    /// Add a function to be called when a tab is closed with its close button.
+    ///
+    /// The tab has already been removed when called and its content is provided.
+    pub fn on_close<F: FnMut(TabID, Arc<Bin>) + Send + 'static>(&self, func: F) {
+        self.on_close.lock().push(Box::new(func));
+    }
+
+    /// Add a function to be called while a tab is dragged outside of the strip.
+    ///
+    /// Called with the tab, the cursor position and `true` once the tab is released.
+    pub fn on_drag_out<F: FnMut(TabID, [f32; 2], bool) + Send + 'static>(&self, func: F) {
+        self.on_drag_out.lock().push(Box::new(func));
+    }
+
+    fn logical_cursor(&self, cursor: [f32; 2]) -> [f32; 2] {
+        let scale = self.basalt.interface_ref().current_effective_scale();
+        [cursor[0] / scale, cursor[1] / scale]
+    }
+
+    fn drag_update(&self, cursor: [f32; 2], released: bool) {
+        let id = {
+            let mut state = self.state.lock();
+
+            let drag = match state.drag.as_mut() {
+                Some(some) => some,
+                None => return,
+            };
+
+            if !drag.moved
+                && (cursor[0] - drag.start[0]).abs() < DRAG_THRESHOLD
+                && (cursor[1] - drag.start[1]).abs() < DRAG_THRESHOLD
+            {
+                if released {
+                    state.drag = None;
+                }
+
+                return;
+            }
+
+            drag.moved = true;
+            let id = drag.id;
+
+            if released {
+                state.drag = None;
+            }
+
+            id
+        };
+
+        let post = self.strip.post_update();
+        let margin = self.theme.strip_height;
+
+        if cursor[1] >= post.tlo[1] - margin && cursor[1] <= post.blo[1] + margin {
+            let index = {
+                let state = self.state.lock();
+
+                state
+                    .tabs
+                    .iter()
+                    .filter(|tab| tab.id != id)
+                    .take_while(|tab| {
+                        let tab_post = tab.tab.post_update();
+                        cursor[0] > (tab_post.tlo[0] + tab_post.tro[0]) / 2.0
+                    })
+                    .count()
+            };
+
+            self.move_tab(id, index);
+
+            // Moving back into the strip ends a drag out.
+            if !released {
+                return;
+            }
+        }
+
+        for func in self.on_drag_out.lock().iter_mut() {
+            func(id, cursor, released);
+        }
+    }
+
+    fn update_tabs(&self) {
+        let state = self.state.lock();
+        let mut left = 0.0;
+
+        for tab in state.tabs.iter() {
+            let selected = state.selected == Some(tab.id);
+
+            // Bins can't be sized to their text, so the width is estimated from the amount of
+            // characters.
+            let close_w = match tab.closable {
+                true => self.theme.strip_height - 6.0,
+                false => 0.0,
+            };
+
+            let width =
+                (tab.title.chars().count() as f32 * self.theme.text_height * 0.6) + close_w + 20.0;
+
+            tab.tab
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(3.0),
+                    pos_from_b: Some(0.0),
+                    pos_from_l: Some(left),
+                    width: Some(width),
+                    pad_l: Some(10.0),
+                    pad_r: Some(close_w + 10.0),
+                    back_color: Some(match selected {
+                        true => self.theme.selected_tab_color.clone(),
+                        false => self.theme.tab_color.clone(),
+                    }),
+                    text: tab.title.clone(),
+                    text_color: Some(self.theme.text_color.clone()),
+                    text_height: Some(self.theme.text_height),
+                    text_vert_align: Some(TextVertAlign::Center),
+                    text_hori_align: Some(TextHoriAlign::Left),
+                    text_wrap: Some(TextWrap::None),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+
+            let size = close_w;
+            let color = self.theme.close_color.clone();
+
+            let cross = |a: (f32, f32), b: (f32, f32)| -> Vec<BinVert> {
+                let n = ((b.1 - a.1) * 0.5 / size, (a.0 - b.0) * 0.5 / size);
+
+                [
+                    (a.0 + n.0, a.1 + n.1),
+                    (a.0 - n.0, a.1 - n.1),
+                    (b.0 + n.0, b.1 + n.1),
+                    (b.0 + n.0, b.1 + n.1),
+                    (a.0 - n.0, a.1 - n.1),
+                    (b.0 - n.0, b.1 - n.1),
+                ]
+                .into_iter()
+                .map(|(x, y)| {
+                    BinVert {
+                        position: (x, y, 0),
+                        color: color.clone(),
+                    }
+                })
+                .collect()
+            };
+
+            let custom_verts = match tab.closable {
+                true => {
+                    let (lo, hi) = (size * 0.3, size * 0.7);
+                    let mut verts = cross((lo, lo), (hi, hi));
+                    verts.extend(cross((hi, lo), (lo, hi)));
+                    verts
+                },
+                false => Vec::new(),
+            };
+
+            tab.close
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    hidden: match tab.closable {
+                        true => None,
+                        false => Some(true),
+                    },
+                    pos_from_t_pct: Some(50.0),
+                    pos_from_t_offset: Some(size / -2.0),
+                    pos_from_r: Some(4.0),
+                    width: Some(size),
+                    height: Some(size),
+                    custom_verts,
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+
+            tab.content
+                .style_update(BinStyle {
+                    hidden: match selected {
+                        true => None,
+                        false => Some(true),
+                    },
+                    ..tab.content.style_copy()
+                })
+                .expect_valid();
+
+            tab.content.update_children();
+            left += width + self.theme.tab_spacing;
+        }
+    }
+}
@@ -0,0 +1,524 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interface::spin_box::{SpinBox, SpinBoxTheme};
+use crate::Basalt;
+
+impl KeepAlive for TimePicker {}
+
+/// A time of day with minute precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// Create a time from an hour in the 24-hour format. Returns `None` if out of range.
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+
+        Some(Time {
+            hour,
+            minute,
+        })
+    }
+
+    /// The hour from `0` to `23`.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// The hour from `1` to `12` and `true` if it is after noon.
+    pub fn hour12(&self) -> (u8, bool) {
+        let hour = match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+
+        (hour, self.hour >= 12)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for Time {
+    type Err = String;
+
+    /// Parse a time in the 24-hour `HH:MM` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hour, minute) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| format!("'{}' isn't in the HH:MM format.", s))?;
+
+        let hour = hour
+            .parse::<u8>()
+            .map_err(|e| format!("Invalid hour '{}': {}", hour, e))?;
+        let minute = minute
+            .parse::<u8>()
+            .map_err(|e| format!("Invalid minute '{}': {}", minute, e))?;
+
+        Time::new(hour, minute).ok_or_else(|| format!("'{}' isn't a valid time.", s))
+    }
+}
+
+/// How hours are displayed by a `TimePicker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// Hours from `1` to `12` with a button to switch between AM & PM.
+    H12,
+    /// Hours from `0` to `23`.
+    H24,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimePickerTheme {
+    pub spin_box: SpinBoxTheme,
+    pub text_color: bin::Color,
+    pub button_color: bin::Color,
+    pub button_text_color: bin::Color,
+    pub text_height: f32,
+    /// Width of the hour & minute spin boxes.
+    pub spin_box_width: f32,
+    pub height: f32,
+    pub spacing: f32,
+    pub am_label: String,
+    pub pm_label: String,
+}
+
+impl Default for TimePickerTheme {
+    fn default() -> Self {
+        TimePickerTheme {
+            spin_box: SpinBoxTheme::default(),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            button_color: bin::Color::srgb_hex("404048"),
+            button_text_color: bin::Color::srgb_hex("ffffff"),
+            text_height: 12.0,
+            spin_box_width: 48.0,
+            height: 24.0,
+            spacing: 4.0,
+            am_label: String::from("AM"),
+            pm_label: String::from("PM"),
+        }
+    }
+}
+
+/// A time input with hour & minute spin boxes.
+///
+/// In the `H12` format, a button after the minutes switches between AM & PM.
+pub struct TimePicker {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    pub hour: Arc<SpinBox>,
+    pub minute: Arc<SpinBox>,
+    pub separator: Arc<Bin>,
+    pub period_button: Arc<Bin>,
+    theme: TimePickerTheme,
+    state: Mutex<State>,
+    on_change: Mutex<Vec<Box<dyn FnMut(Time) + Send + 'static>>>,
+}
+
+struct State {
+    time: Time,
+    format: TimeFormat,
+    /// Set while the spin boxes are being updated to the time.
+    syncing: bool,
+}
+
+impl TimePicker {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: TimePickerTheme,
+        format: TimeFormat,
+        parent: Option<Arc<Bin>>,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(3);
+        let container = bins.pop().unwrap();
+        let separator = bins.pop().unwrap();
+        let period_button = bins.pop().unwrap();
+        container.add_children(vec![separator.clone(), period_button.clone()]);
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                height: Some(theme.height),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let hour = SpinBox::new(
+            basalt.clone(),
+            theme.spin_box.clone(),
+            Some(container.clone()),
+        );
+
+        let minute = SpinBox::new(
+            basalt.clone(),
+            theme.spin_box.clone(),
+            Some(container.clone()),
+        );
+
+        for spin_box in [&hour, &minute] {
+            spin_box.set_digits(2);
+        }
+
+        minute.set_min_max(0.0, 59.0);
+
+        let picker = Arc::new(TimePicker {
+            basalt,
+            container,
+            hour,
+            minute,
+            separator,
+            period_button,
+            theme,
+            state: Mutex::new(State {
+                time: Time::new(0, 0).unwrap(),
+                format,
+                syncing: false,
+            }),
+            on_change: Mutex::new(Vec::new()),
+        });
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        picker.hour.on_change(move |value| {
+            if let Some(picker) = picker_wk.upgrade() {
+                picker.changed(Some(value as u8), None, false);
+            }
+        });
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        picker.minute.on_change(move |value| {
+            if let Some(picker) = picker_wk.upgrade() {
+                picker.changed(None, Some(value as u8), false);
+            }
+        });
+
+        let picker_wk = Arc::downgrade(&picker);
+
+        picker
+            .period_button
+            .on_press(MouseButton::Left, move |_, _, _| {
+                match picker_wk.upgrade() {
+                    Some(picker) => {
+                        picker.changed(None, None, true);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+
+        for key in [Qwerty::Enter, Qwerty::Space] {
+            let picker_wk = Arc::downgrade(&picker);
+
+            picker.period_button.on_press(key, move |_, _, _| {
+                match picker_wk.upgrade() {
+                    Some(picker) => {
+                        picker.changed(None, None, true);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+        }
+
+        picker.layout();
+        picker
+    }
+
+    pub fn time(&self) -> Time {
+        self.state.lock().time
+    }
+
+    pub fn set_time(&self, time: Time) {
+        let changed = {
+            let mut state = self.state.lock();
+            let changed = state.time != time;
+            state.time = time;
+            changed
+        };
+
+        self.sync();
+
+        if changed {
+            self.call_on_change(time);
+        }
+    }
+
+    pub fn format(&self) -> TimeFormat {
+        self.state.lock().format
+    }
+
+    /// Set how hours are displayed. The time is kept.
+    pub fn set_format(&self, format: TimeFormat) {
+        self.state.lock().format = format;
+        self.layout();
+    }
+
+    /// Set the amount the minutes change by when stepped.
+    ///
+    /// **Default**: `1`
+    pub fn set_minute_step(&self, step: u8) {
+        self.minute.set_step_size(step.max(1) as f32);
+    }
+
+    /// Add a function to be called when the time changes.
+    pub fn on_change<F: FnMut(Time) + Send + 'static>(&self, func: F) {
+        self.on_change.lock().push(Box::new(func));
+    }
+
+    fn call_on_change(&self, time: Time) {
+        for func in self.on_change.lock().iter_mut() {
+            func(time);
+        }
+    }
+
+    /// Called when a spin box or the period button changes the time.
+    fn changed(&self, hour: Option<u8>, minute: Option<u8>, toggle_period: bool) {
+        let time = {
+            let mut state = self.state.lock();
+
+            if state.syncing {
+                return;
+            }
+
+            let mut time = state.time;
+
+            if let Some(hour) = hour {
+                time.hour = match state.format {
+                    TimeFormat::H24 => hour.min(23),
+                    TimeFormat::H12 => (hour % 12) + if time.hour >= 12 { 12 } else { 0 },
+                };
+            }
+
+            if let Some(minute) = minute {
+                time.minute = minute.min(59);
+            }
+
+            if toggle_period {
+                time.hour = (time.hour + 12) % 24;
+            }
+
+            if time == state.time {
+                return;
+            }
+
+            state.time = time;
+            time
+        };
+
+        if toggle_period {
+            self.sync();
+        }
+
+        self.call_on_change(time);
+    }
+
+    /// Update the spin boxes & period button to the time.
+    fn sync(&self) {
+        let (time, format) = {
+            let mut state = self.state.lock();
+            state.syncing = true;
+            (state.time, state.format)
+        };
+
+        let (hour, pm) = match format {
+            TimeFormat::H24 => (time.hour(), time.hour() >= 12),
+            TimeFormat::H12 => time.hour12(),
+        };
+
+        self.hour.set(hour as f32);
+        self.minute.set(time.minute() as f32);
+
+        self.period_button
+            .style_update(BinStyle {
+                text: match pm {
+                    true => self.theme.pm_label.clone(),
+                    false => self.theme.am_label.clone(),
+                },
+                ..self.period_button.style_copy()
+            })
+            .expect_valid();
+
+        self.state.lock().syncing = false;
+    }
+
+    fn layout(&self) {
+        let theme = &self.theme;
+        let format = self.state.lock().format;
+        let sep_w = theme.text_height * 0.6;
+        let spin_w = theme.spin_box_width;
+
+        // Prevent the hour from being clamped into a time change while the range changes.
+        self.state.lock().syncing = true;
+
+        match format {
+            TimeFormat::H24 => self.hour.set_min_max(0.0, 23.0),
+            TimeFormat::H12 => self.hour.set_min_max(1.0, 12.0),
+        }
+
+        self.state.lock().syncing = false;
+
+        for (spin_box, left) in [(&self.hour, 0.0), (&self.minute, spin_w + sep_w)] {
+            spin_box
+                .container
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_t: Some(0.0),
+                    pos_from_l: Some(left),
+                    width: Some(spin_w),
+                    height: Some(theme.height),
+                    ..spin_box.container.style_copy()
+                })
+                .expect_valid();
+        }
+
+        self.separator
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(spin_w),
+                width: Some(sep_w),
+                height: Some(theme.height),
+                text: String::from(":"),
+                text_color: Some(theme.text_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let period_w = match format {
+            TimeFormat::H12 => {
+                theme
+                    .am_label
+                    .chars()
+                    .count()
+                    .max(theme.pm_label.chars().count()) as f32
+                    * theme.text_height
+                    * 0.6
+                    + 16.0
+            },
+            TimeFormat::H24 => 0.0,
+        };
+
+        self.period_button
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                hidden: match format {
+                    TimeFormat::H12 => None,
+                    TimeFormat::H24 => Some(true),
+                },
+                pos_from_t: Some(0.0),
+                pos_from_l: Some((spin_w * 2.0) + sep_w + theme.spacing),
+                width: Some(period_w),
+                height: Some(theme.height),
+                back_color: Some(theme.button_color.clone()),
+                border_radius_tl: Some(3.0),
+                border_radius_tr: Some(3.0),
+                border_radius_bl: Some(3.0),
+                border_radius_br: Some(3.0),
+                text_color: Some(theme.button_text_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_hori_align: Some(TextHoriAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..self.period_button.style_copy()
+            })
+            .expect_valid();
+
+        let width = match format {
+            TimeFormat::H12 => (spin_w * 2.0) + sep_w + theme.spacing + period_w,
+            TimeFormat::H24 => (spin_w * 2.0) + sep_w,
+        };
+
+        self.container
+            .style_update(BinStyle {
+                width: Some(width),
+                ..self.container.style_copy()
+            })
+            .expect_valid();
+
+        self.period_button.set_focusable(format == TimeFormat::H12);
+        self.sync();
+        self.container.update_children();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+
+    #[test]
+    fn validation() {
+        assert!(Time::new(0, 0).is_some());
+        assert!(Time::new(23, 59).is_some());
+        assert!(Time::new(24, 0).is_none());
+        assert!(Time::new(12, 60).is_none());
+    }
+
+    #[test]
+    fn hour12() {
+        let cases = [
+            (0, (12, false)),
+            (1, (1, false)),
+            (11, (11, false)),
+            (12, (12, true)),
+            (13, (1, true)),
+            (23, (11, true)),
+        ];
+
+        for (hour, expected) in cases {
+            assert_eq!(Time::new(hour, 0).unwrap().hour12(), expected, "{}", hour);
+        }
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(Time::new(9, 5).unwrap().to_string(), "09:05");
+        assert_eq!(" 9:05 ".parse(), Ok(Time::new(9, 5).unwrap()));
+        assert_eq!("23:59".parse(), Ok(Time::new(23, 59).unwrap()));
+        assert!("24:00".parse::<Time>().is_err());
+        assert!("12:60".parse::<Time>().is_err());
+        assert!("12".parse::<Time>().is_err());
+        assert!("ab:cd".parse::<Time>().is_err());
+
+        for hour in 0..24 {
+            for minute in 0..60 {
+                let time = Time::new(hour, minute).unwrap();
+                assert_eq!(time.to_string().parse(), Ok(time));
+            }
+        }
+    }
+}
@@ -0,0 +1,669 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextHoriAlign, TextVertAlign, TextWrap,
+};
+use crate::interface::overlay::{OverlayID, OverlayLayer};
+use crate::interval::{IntvlHookCtrl, IntvlHookID};
+use crate::Basalt;
+
+impl KeepAlive for Toasts {}
+
+/// How long a toast is displayed unless changed with `Toast::duration`.
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+/// Time between frames of the animations.
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+/// Height of action buttons.
+const ACTION_HEIGHT: f32 = 22.0;
+/// Size of the close button.
+const CLOSE_SIZE: f32 = 14.0;
+
+/// The severity of a `Toast` which determines its accent color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// The corner of the window toasts are stacked in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// An ID of a toast shown by `Toasts`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ToastID(u64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToastTheme {
+    pub back_color: bin::Color,
+    pub title_color: bin::Color,
+    pub text_color: bin::Color,
+    pub info_color: bin::Color,
+    pub success_color: bin::Color,
+    pub warning_color: bin::Color,
+    pub error_color: bin::Color,
+    pub action_color: bin::Color,
+    pub action_text_color: bin::Color,
+    pub text_height: f32,
+    pub width: f32,
+    pub pad: f32,
+    /// Space between toasts.
+    pub spacing: f32,
+    /// Space between the toasts and the edges of the window.
+    pub margin: f32,
+    /// Maximum amount of toasts displayed at once. Additional toasts wait until one is dismissed.
+    pub max_visible: usize,
+    /// Duration of the slide & fade animations.
+    pub animation_time: Duration,
+}
+
+impl Default for ToastTheme {
+    fn default() -> Self {
+        ToastTheme {
+            back_color: bin::Color::srgb_hex("2a2a30f0"),
+            title_color: bin::Color::srgb_hex("ffffff"),
+            text_color: bin::Color::srgb_hex("d0d0d8"),
+            info_color: bin::Color::srgb_hex("3a7bd5"),
+            success_color: bin::Color::srgb_hex("3aa55d"),
+            warning_color: bin::Color::srgb_hex("d5a33a"),
+            error_color: bin::Color::srgb_hex("d53a3a"),
+            action_color: bin::Color::srgb_hex("404048"),
+            action_text_color: bin::Color::srgb_hex("ffffff"),
+            text_height: 12.0,
+            width: 300.0,
+            pad: 10.0,
+            spacing: 8.0,
+            margin: 16.0,
+            max_visible: 5,
+            animation_time: Duration::from_millis(200),
+        }
+    }
+}
+
+/// A notification displayed by `Toasts`.
+#[derive(Clone)]
+pub struct Toast {
+    severity: ToastSeverity,
+    title: Option<String>,
+    message: String,
+    duration: Option<Duration>,
+    actions: Vec<(String, Arc<dyn Fn() + Send + Sync>)>,
+}
+
+impl Toast {
+    pub fn new<T: Into<String>>(severity: ToastSeverity, message: T) -> Self {
+        Toast {
+            severity,
+            title: None,
+            message: message.into(),
+            duration: Some(DEFAULT_DURATION),
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn info<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Info, message)
+    }
+
+    pub fn success<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Success, message)
+    }
+
+    pub fn warning<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Warning, message)
+    }
+
+    pub fn error<T: Into<String>>(message: T) -> Self {
+        Self::new(ToastSeverity::Error, message)
+    }
+
+    /// Display a title above the message.
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set how long the toast is displayed. The time doesn't pass while the cursor is over it.
+    ///
+    /// **Default**: 5 seconds
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Display the toast until it is closed.
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Add a button that calls the function and dismisses the toast.
+    pub fn action<T: Into<String>, F: Fn() + Send + Sync + 'static>(
+        mut self,
+        label: T,
+        func: F,
+    ) -> Self {
+        self.actions.push((label.into(), Arc::new(func)));
+        self
+    }
+}
+
+/// A stack of toasts in a corner of the window.
+///
+/// Toasts slide in from the edge of the window, are dismissed once their duration has passed or
+/// they're closed, and then fade out while the remaining toasts slide into place.
+pub struct Toasts {
+    pub basalt: Arc<Basalt>,
+    theme: ToastTheme,
+    state: Mutex<State>,
+    intvl_id: IntvlHookID,
+}
+
+struct State {
+    corner: ToastCorner,
+    next_id: u64,
+    entries: Vec<Entry>,
+    queued: VecDeque<(ToastID, Toast)>,
+    running: bool,
+}
+
+struct Entry {
+    id: ToastID,
+    container: Arc<Bin>,
+    _children: Vec<Arc<Bin>>,
+    overlay: OverlayID,
+    height: f32,
+    y: Option<f32>,
+    /// Progress of the entrance animation from `0.0` to `1.0`.
+    appear: f32,
+    /// Progress of the exit animation from `0.0` to `1.0`, `None` while not leaving.
+    leave: Option<f32>,
+    remaining: Option<Duration>,
+    hovered: bool,
+}
+
+impl Drop for Toasts {
+    fn drop(&mut self) {
+        self.basalt.interval_ref().remove(self.intvl_id);
+        let overlays = self.basalt.interface_ref().overlays();
+
+        for entry in self.state.get_mut().entries.drain(..) {
+            overlays.hide(entry.overlay);
+        }
+    }
+}
+
+impl Toasts {
+    pub fn new(basalt: Arc<Basalt>, theme: ToastTheme, corner: ToastCorner) -> Arc<Self> {
+        Arc::new_cyclic(|toasts_wk| {
+            let toasts_wk = toasts_wk.clone();
+
+            let intvl_id = basalt
+                .interval_ref()
+                .do_every(FRAME_INTERVAL, None, move |last| {
+                    match toasts_wk.upgrade() {
+                        Some(toasts) => toasts.tick(last.unwrap_or(FRAME_INTERVAL)),
+                        None => IntvlHookCtrl::Remove,
+                    }
+                });
+
+            Toasts {
+                basalt,
+                theme,
+                state: Mutex::new(State {
+                    corner,
+                    next_id: 0,
+                    entries: Vec::new(),
+                    queued: VecDeque::new(),
+                    running: false,
+                }),
+                intvl_id,
+            }
+        })
+    }
+
+    /// Display a toast.
+    ///
+    /// If `ToastTheme::max_visible` toasts are displayed, it is displayed once one is dismissed.
+    pub fn push(self: &Arc<Self>, toast: Toast) -> ToastID {
+        let id = {
+            let mut state = self.state.lock();
+            let id = ToastID(state.next_id);
+            state.next_id += 1;
+            state.queued.push_back((id, toast));
+            id
+        };
+
+        self.show_queued();
+        id
+    }
+
+    /// Dismiss a toast. Returns `false` if it was already dismissed.
+    pub fn dismiss(&self, id: ToastID) -> bool {
+        let mut state = self.state.lock();
+
+        if let Some(i) = state.queued.iter().position(|(queued, _)| *queued == id) {
+            state.queued.remove(i);
+            return true;
+        }
+
+        match state
+            .entries
+            .iter_mut()
+            .find(|entry| entry.id == id && entry.leave.is_none())
+        {
+            Some(entry) => {
+                entry.leave = Some(0.0);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Dismiss all toasts including ones waiting to be displayed.
+    pub fn clear(&self) {
+        let mut state = self.state.lock();
+        state.queued.clear();
+
+        for entry in state.entries.iter_mut() {
+            if entry.leave.is_none() {
+                entry.leave = Some(0.0);
+            }
+        }
+    }
+
+    /// Returns `true` if the toast is displayed or waiting to be displayed.
+    pub fn is_shown(&self, id: ToastID) -> bool {
+        let state = self.state.lock();
+
+        state
+            .entries
+            .iter()
+            .any(|entry| entry.id == id && entry.leave.is_none())
+            || state.queued.iter().any(|(queued, _)| *queued == id)
+    }
+
+    pub fn corner(&self) -> ToastCorner {
+        self.state.lock().corner
+    }
+
+    /// Move the toasts to another corner.
+    pub fn set_corner(&self, corner: ToastCorner) {
+        let mut state = self.state.lock();
+        state.corner = corner;
+
+        for entry in state.entries.iter_mut() {
+            entry.y = None;
+        }
+    }
+
+    fn show_queued(self: &Arc<Self>) {
+        loop {
+            let (id, toast) = {
+                let mut state = self.state.lock();
+                let visible = state.entries.iter().filter(|e| e.leave.is_none()).count();
+
+                if visible >= self.theme.max_visible {
+                    break;
+                }
+
+                match state.queued.pop_front() {
+                    Some(some) => some,
+                    None => break,
+                }
+            };
+
+            let entry = self.create(id, toast);
+            let mut state = self.state.lock();
+            state.entries.insert(0, entry);
+
+            if !state.running {
+                state.running = true;
+                self.basalt.interval_ref().start(self.intvl_id);
+            }
+        }
+    }
+
+    fn create(self: &Arc<Self>, id: ToastID, toast: Toast) -> Entry {
+        let theme = &self.theme;
+        let mut bins = self
+            .basalt
+            .interface_ref()
+            .new_bins(4 + toast.actions.len());
+        let container = bins.pop().unwrap();
+        let title = bins.pop().unwrap();
+        let message = bins.pop().unwrap();
+        let close = bins.pop().unwrap();
+        let actions = bins;
+
+        container.add_children(vec![title.clone(), message.clone(), close.clone()]);
+        container.add_children(actions.clone());
+
+        let accent = match toast.severity {
+            ToastSeverity::Info => theme.info_color.clone(),
+            ToastSeverity::Success => theme.success_color.clone(),
+            ToastSeverity::Warning => theme.warning_color.clone(),
+            ToastSeverity::Error => theme.error_color.clone(),
+        };
+
+        // Bins can't be sized to their text, so the amount of lines is estimated.
+        let text_w = theme.width - (theme.pad * 2.0) - CLOSE_SIZE - 4.0;
+        let char_w = theme.text_height * 0.6;
+        let line_h = theme.text_height * 1.3;
+        let lines = ((toast.message.chars().count() as f32 * char_w) / text_w)
+            .ceil()
+            .max(1.0);
+
+        let title_h = match toast.title.is_some() {
+            true => line_h + 4.0,
+            false => 0.0,
+        };
+
+        let message_h = lines * line_h;
+
+        let actions_h = match toast.actions.is_empty() {
+            true => 0.0,
+            false => ACTION_HEIGHT + theme.pad,
+        };
+
+        let height = (theme.pad * 2.0) + title_h + message_h + actions_h;
+
+        container
+            .style_update(BinStyle {
+                opacity: Some(0.0),
+                width: Some(theme.width),
+                height: Some(height),
+                back_color: Some(theme.back_color.clone()),
+                border_size_l: Some(4.0),
+                border_color_l: Some(accent),
+                border_radius_tr: Some(3.0),
+                border_radius_br: Some(3.0),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        title
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                hidden: match toast.title.is_some() {
+                    true => None,
+                    false => Some(true),
+                },
+                pos_from_t: Some(theme.pad),
+                pos_from_l: Some(theme.pad),
+                width: Some(text_w),
+                height: Some(line_h),
+                text: toast.title.clone().unwrap_or_default(),
+                text_color: Some(theme.title_color.clone()),
+                text_height: Some(theme.text_height),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        message
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(theme.pad + title_h),
+                pos_from_l: Some(theme.pad),
+                width: Some(text_w),
+                height: Some(message_h),
+                text: toast.message.clone(),
+                text_color: Some(theme.text_color.clone()),
+                text_height: Some(theme.text_height),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let cross = |a: (f32, f32), b: (f32, f32)| {
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let len = (dx * dx + dy * dy).sqrt();
+            let (nx, ny) = (-dy / len * 0.75, dx / len * 0.75);
+
+            [
+                (a.0 + nx, a.1 + ny),
+                (a.0 - nx, a.1 - ny),
+                (b.0 + nx, b.1 + ny),
+                (b.0 + nx, b.1 + ny),
+                (a.0 - nx, a.1 - ny),
+                (b.0 - nx, b.1 - ny),
+            ]
+        };
+
+        let (lo, hi) = (CLOSE_SIZE * 0.25, CLOSE_SIZE * 0.75);
+
+        close
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(theme.pad / 2.0),
+                pos_from_r: Some(theme.pad / 2.0),
+                width: Some(CLOSE_SIZE),
+                height: Some(CLOSE_SIZE),
+                custom_verts: cross((lo, lo), (hi, hi))
+                    .into_iter()
+                    .chain(cross((hi, lo), (lo, hi)))
+                    .map(|(x, y)| {
+                        BinVert {
+                            position: (x, y, 0),
+                            color: theme.text_color.clone(),
+                        }
+                    })
+                    .collect(),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let toasts_wk = Arc::downgrade(self);
+
+        close.on_press(MouseButton::Left, move |_, _, _| {
+            match toasts_wk.upgrade() {
+                Some(toasts) => {
+                    toasts.dismiss(id);
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        let mut right = theme.pad;
+
+        for (button, (label, func)) in actions.iter().zip(toast.actions.into_iter()).rev() {
+            let width = (label.chars().count() as f32 * char_w) + 16.0;
+
+            button
+                .style_update(BinStyle {
+                    position: Some(BinPosition::Parent),
+                    pos_from_b: Some(theme.pad),
+                    pos_from_r: Some(right),
+                    width: Some(width),
+                    height: Some(ACTION_HEIGHT),
+                    back_color: Some(theme.action_color.clone()),
+                    border_radius_tl: Some(3.0),
+                    border_radius_tr: Some(3.0),
+                    border_radius_bl: Some(3.0),
+                    border_radius_br: Some(3.0),
+                    text: label,
+                    text_color: Some(theme.action_text_color.clone()),
+                    text_height: Some(theme.text_height),
+                    text_vert_align: Some(TextVertAlign::Center),
+                    text_hori_align: Some(TextHoriAlign::Center),
+                    text_wrap: Some(TextWrap::None),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+
+            let toasts_wk = Arc::downgrade(self);
+
+            button.on_press(MouseButton::Left, move |_, _, _| {
+                match toasts_wk.upgrade() {
+                    Some(toasts) => {
+                        func();
+                        toasts.dismiss(id);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+
+            right += width + 6.0;
+        }
+
+        for hovered in [true, false] {
+            let toasts_wk = Arc::downgrade(self);
+
+            let method = move |_, _: &_| {
+                let toasts = match toasts_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                if let Some(entry) = toasts
+                    .state
+                    .lock()
+                    .entries
+                    .iter_mut()
+                    .find(|entry| entry.id == id)
+                {
+                    entry.hovered = hovered;
+                }
+
+                Default::default()
+            };
+
+            match hovered {
+                true => container.on_enter(method),
+                false => container.on_leave(method),
+            };
+        }
+
+        let overlay = self
+            .basalt
+            .interface_ref()
+            .overlays()
+            .show(container.clone(), OverlayLayer::Toast);
+
+        let mut children = vec![title, message, close];
+        children.extend(actions);
+
+        Entry {
+            id,
+            container,
+            _children: children,
+            overlay,
+            height,
+            y: None,
+            appear: 0.0,
+            leave: None,
+            remaining: toast.duration,
+            hovered: false,
+        }
+    }
+
+    fn tick(self: &Arc<Self>, elapsed: Duration) -> IntvlHookCtrl {
+        let anim = self.theme.animation_time.as_secs_f32().max(f32::EPSILON);
+        let step = elapsed.as_secs_f32() / anim;
+        let overlays = self.basalt.interface_ref().overlays();
+        let mut removed = false;
+
+        let mut state = self.state.lock();
+        let corner = state.corner;
+
+        state.entries.retain_mut(|entry| {
+            match entry.leave.as_mut() {
+                Some(leave) => {
+                    *leave += step;
+
+                    if *leave >= 1.0 {
+                        overlays.hide(entry.overlay);
+                        removed = true;
+                        return false;
+                    }
+                },
+                None => {
+                    entry.appear = (entry.appear + step).min(1.0);
+
+                    if !entry.hovered {
+                        if let Some(remaining) = entry.remaining.as_mut() {
+                            *remaining = remaining.saturating_sub(elapsed);
+
+                            if remaining.is_zero() {
+                                entry.leave = Some(0.0);
+                            }
+                        }
+                    }
+                },
+            }
+
+            true
+        });
+
+        let mut target_y = 0.0;
+        let ease = (elapsed.as_secs_f32() * 12.0).min(1.0);
+
+        for entry in state.entries.iter_mut() {
+            let y = match entry.y {
+                Some(y) => y + ((target_y - y) * ease),
+                None => target_y,
+            };
+
+            entry.y = Some(y);
+            target_y += entry.height + self.theme.spacing;
+
+            // Slide in from & out to the edge of the window while fading.
+            let visible = entry.appear * (1.0 - entry.leave.unwrap_or(0.0));
+            let x = self.theme.margin - ((1.0 - visible) * (self.theme.width / 2.0));
+            let y = self.theme.margin + y;
+
+            let (t, b, l, r) = match corner {
+                ToastCorner::TopLeft => (Some(y), None, Some(x), None),
+                ToastCorner::TopRight => (Some(y), None, None, Some(x)),
+                ToastCorner::BottomLeft => (None, Some(y), Some(x), None),
+                ToastCorner::BottomRight => (None, Some(y), None, Some(x)),
+            };
+
+            entry
+                .container
+                .style_update(BinStyle {
+                    hidden: None,
+                    pos_from_t: t,
+                    pos_from_b: b,
+                    pos_from_l: l,
+                    pos_from_r: r,
+                    opacity: Some(visible),
+                    ..entry.container.style_copy()
+                })
+                .expect_valid();
+
+            entry.container.update_children();
+        }
+
+        let idle = state.entries.is_empty();
+
+        if idle {
+            state.running = false;
+        }
+
+        drop(state);
+
+        if removed {
+            self.show_queued();
+        }
+
+        match idle && !self.state.lock().running {
+            true => IntvlHookCtrl::Pause,
+            false => IntvlHookCtrl::Continue,
+        }
+    }
+}
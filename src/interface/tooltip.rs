@@ -0,0 +1,509 @@
+//! Tooltips displayed when the cursor rests on a `Bin`.
+//!
+//! A tooltip is set with `Bin::set_tooltip`. Once the cursor has rested on the `Bin` for the delay
+//! of the `TooltipTheme`, the tooltip is shown near the cursor within the `Tooltip` overlay layer.
+//! Tooltips are hidden when the cursor leaves the `Bin` or a mouse button is pressed or the
+//! window is scrolled. Only one tooltip is shown at a time.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::input::state::WindowState;
+use crate::input::{InputHookCtrl, InputHookID, MouseButton};
+use crate::interface::bin::{self, Bin, BinID, BinPosition, BinStyle, TextWrap};
+use crate::interface::overlay::{OverlayID, OverlayLayer, DISMISS_WEIGHT};
+use crate::interval::{IntvlHookCtrl, IntvlHookID};
+use crate::Basalt;
+
+/// The content of a tooltip.
+#[derive(Clone)]
+pub enum Tooltip {
+    /// Text displayed with the style of the `TooltipTheme`.
+    Text(String),
+    /// A function that creates the `Bin` displayed.
+    ///
+    /// The `Bin`'s `width` & `height` style fields are used to place it.
+    Bin(Arc<dyn Fn(&Arc<Basalt>) -> Arc<Bin> + Send + Sync>),
+}
+
+impl Tooltip {
+    pub fn text<T: Into<String>>(text: T) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub fn bin<F: Fn(&Arc<Basalt>) -> Arc<Bin> + Send + Sync + 'static>(func: F) -> Self {
+        Self::Bin(Arc::new(func))
+    }
+}
+
+impl From<&str> for Tooltip {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for Tooltip {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooltipTheme {
+    pub back_color: bin::Color,
+    pub border_color: bin::Color,
+    pub text_color: bin::Color,
+    pub text_height: f32,
+    /// Padding around the text of text tooltips.
+    pub pad: f32,
+    /// Width text tooltips wrap at.
+    pub max_width: f32,
+    /// How long the cursor has to rest on a `Bin` before its tooltip is shown.
+    pub delay: Duration,
+    /// Offset from the cursor the tooltip is shown at.
+    pub offset: [f32; 2],
+}
+
+impl Default for TooltipTheme {
+    fn default() -> Self {
+        TooltipTheme {
+            back_color: bin::Color::srgb_hex("1e1e22"),
+            border_color: bin::Color::srgb_hex("4a4a52"),
+            text_color: bin::Color::srgb_hex("e6e6e6"),
+            text_height: 12.0,
+            pad: 6.0,
+            max_width: 300.0,
+            delay: Duration::from_millis(600),
+            offset: [12.0, 20.0],
+        }
+    }
+}
+
+/// Manages the tooltips of an `Interface`.
+///
+/// Accessed via `Interface::tooltips`.
+pub struct Tooltips {
+    basalt: Mutex<Weak<Basalt>>,
+    state: Mutex<State>,
+}
+
+struct State {
+    theme: TooltipTheme,
+    targets: HashMap<BinID, Target>,
+    resting: Option<Resting>,
+    shown: Option<Shown>,
+    intvl_id: Option<IntvlHookID>,
+}
+
+struct Target {
+    bin: Weak<Bin>,
+    tooltip: Tooltip,
+    hooks: Vec<InputHookID>,
+}
+
+struct Resting {
+    bin_id: BinID,
+    since: Instant,
+    cursor: [f32; 2],
+}
+
+struct Shown {
+    bin_id: BinID,
+    overlay: OverlayID,
+}
+
+impl Tooltips {
+    pub(crate) fn new() -> Self {
+        Tooltips {
+            basalt: Mutex::new(Weak::new()),
+            state: Mutex::new(State {
+                theme: TooltipTheme::default(),
+                targets: HashMap::new(),
+                resting: None,
+                shown: None,
+                intvl_id: None,
+            }),
+        }
+    }
+
+    pub(crate) fn attach_basalt(&self, basalt: &Arc<Basalt>) {
+        *self.basalt.lock() = Arc::downgrade(basalt);
+        let window = basalt.window();
+
+        for key in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
+            let basalt_wk = Arc::downgrade(basalt);
+
+            basalt
+                .input_ref()
+                .hook()
+                .window(&window)
+                .on_press()
+                .keys(key)
+                .weight(DISMISS_WEIGHT)
+                .call(move |_, _, _| {
+                    match basalt_wk.upgrade() {
+                        Some(basalt) => {
+                            basalt.interface_ref().tooltips().hide();
+                            Default::default()
+                        },
+                        None => InputHookCtrl::Remove,
+                    }
+                })
+                .finish()
+                .unwrap();
+        }
+
+        for smooth in [false, true] {
+            let basalt_wk = Arc::downgrade(basalt);
+
+            basalt
+                .input_ref()
+                .hook()
+                .window(&window)
+                .on_scroll()
+                .enable_smooth(smooth)
+                .weight(DISMISS_WEIGHT)
+                .call(move |_, _, _, _| {
+                    match basalt_wk.upgrade() {
+                        Some(basalt) => {
+                            basalt.interface_ref().tooltips().hide();
+                            Default::default()
+                        },
+                        None => InputHookCtrl::Remove,
+                    }
+                })
+                .finish()
+                .unwrap();
+        }
+
+        // Checks if the cursor has rested long enough. Paused while the cursor isn't resting.
+        let basalt_wk = Arc::downgrade(basalt);
+
+        let intvl_id = basalt
+            .interval_ref()
+            .do_every(Duration::from_millis(50), None, move |_| {
+                match basalt_wk.upgrade() {
+                    Some(basalt) => basalt.interface_ref().tooltips().check_resting(),
+                    None => IntvlHookCtrl::Remove,
+                }
+            });
+
+        self.state.lock().intvl_id = Some(intvl_id);
+    }
+
+    /// Set the theme used for tooltips shown after this is called.
+    pub fn set_theme(&self, theme: TooltipTheme) {
+        self.state.lock().theme = theme;
+    }
+
+    pub fn theme(&self) -> TooltipTheme {
+        self.state.lock().theme.clone()
+    }
+
+    /// Hide the tooltip that is currently shown.
+    pub fn hide(&self) {
+        let shown = {
+            let mut state = self.state.lock();
+            state.resting = None;
+            state.shown.take()
+        };
+
+        if let Some(shown) = shown {
+            if let Some(basalt) = self.basalt.lock().upgrade() {
+                basalt.interface_ref().overlays().hide(shown.overlay);
+            }
+        }
+    }
+
+    /// The tooltip of a `Bin`.
+    pub fn get(&self, bin: &Arc<Bin>) -> Option<Tooltip> {
+        self.state
+            .lock()
+            .targets
+            .get(&bin.id())
+            .map(|target| target.tooltip.clone())
+    }
+
+    pub(crate) fn set(&self, bin: &Arc<Bin>, tooltip: Option<Tooltip>) {
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return,
+        };
+
+        let old = {
+            let mut state = self.state.lock();
+            state
+                .targets
+                .retain(|_, target| target.bin.strong_count() > 0);
+
+            match tooltip {
+                Some(tooltip) => {
+                    if let Some(target) = state.targets.get_mut(&bin.id()) {
+                        target.tooltip = tooltip;
+                        return;
+                    }
+
+                    state.targets.insert(
+                        bin.id(),
+                        Target {
+                            bin: Arc::downgrade(bin),
+                            tooltip,
+                            hooks: Vec::new(),
+                        },
+                    );
+
+                    None
+                },
+                None => state.targets.remove(&bin.id()),
+            }
+        };
+
+        if let Some(old) = old {
+            for hook in old.hooks {
+                basalt.input_ref().remove_hook(hook);
+            }
+
+            if self.shown_for(bin.id()) {
+                self.hide();
+            }
+
+            return;
+        }
+
+        let bin_id = bin.id();
+        let basalt_wk = Arc::downgrade(&basalt);
+
+        let enter_hook = bin.on_enter(move |_, window| {
+            match basalt_wk.upgrade() {
+                Some(basalt) => {
+                    basalt.interface_ref().tooltips().rest(bin_id, window);
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        let basalt_wk = Arc::downgrade(&basalt);
+
+        let cursor_hook = bin.on_cursor(move |_, window, _| {
+            match basalt_wk.upgrade() {
+                Some(basalt) => {
+                    let tooltips = basalt.interface_ref().tooltips();
+
+                    if !tooltips.shown_for(bin_id) {
+                        tooltips.rest(bin_id, window);
+                    }
+
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        let basalt_wk = Arc::downgrade(&basalt);
+
+        let leave_hook = bin.on_leave(move |_, _| {
+            match basalt_wk.upgrade() {
+                Some(basalt) => {
+                    basalt.interface_ref().tooltips().leave(bin_id);
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        if let Some(target) = self.state.lock().targets.get_mut(&bin_id) {
+            target.hooks = vec![enter_hook, cursor_hook, leave_hook];
+        }
+    }
+
+    fn shown_for(&self, bin_id: BinID) -> bool {
+        self.state
+            .lock()
+            .shown
+            .as_ref()
+            .map(|shown| shown.bin_id == bin_id)
+            .unwrap_or(false)
+    }
+
+    fn rest(&self, bin_id: BinID, window: &WindowState) {
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return,
+        };
+
+        let scale = basalt.interface_ref().current_effective_scale();
+        let [x, y] = window.cursor_pos();
+
+        let intvl_id = {
+            let mut state = self.state.lock();
+
+            state.resting = Some(Resting {
+                bin_id,
+                since: Instant::now(),
+                cursor: [x / scale, y / scale],
+            });
+
+            state.intvl_id
+        };
+
+        if let Some(intvl_id) = intvl_id {
+            basalt.interval_ref().start(intvl_id);
+        }
+    }
+
+    fn leave(&self, bin_id: BinID) {
+        let is_source = {
+            let mut state = self.state.lock();
+
+            if state
+                .resting
+                .as_ref()
+                .map(|resting| resting.bin_id == bin_id)
+                .unwrap_or(false)
+            {
+                state.resting = None;
+            }
+
+            state
+                .shown
+                .as_ref()
+                .map(|shown| shown.bin_id == bin_id)
+                .unwrap_or(false)
+        };
+
+        if is_source {
+            self.hide();
+        }
+    }
+
+    fn check_resting(&self) -> IntvlHookCtrl {
+        let (bin_id, cursor, tooltip, theme) = {
+            let mut state = self.state.lock();
+
+            let resting = match state.resting.as_ref() {
+                Some(some) => some,
+                None => return IntvlHookCtrl::Pause,
+            };
+
+            if resting.since.elapsed() < state.theme.delay {
+                return IntvlHookCtrl::Continue;
+            }
+
+            let resting = state.resting.take().unwrap();
+
+            let tooltip = match state.targets.get(&resting.bin_id) {
+                Some(target) if target.bin.strong_count() > 0 => target.tooltip.clone(),
+                _ => return IntvlHookCtrl::Pause,
+            };
+
+            (resting.bin_id, resting.cursor, tooltip, state.theme.clone())
+        };
+
+        self.show(bin_id, cursor, tooltip, theme);
+        IntvlHookCtrl::Pause
+    }
+
+    fn show(&self, bin_id: BinID, cursor: [f32; 2], tooltip: Tooltip, theme: TooltipTheme) {
+        let basalt = match self.basalt.lock().upgrade() {
+            Some(some) => some,
+            None => return,
+        };
+
+        self.hide();
+
+        let tip = match tooltip {
+            Tooltip::Text(text) => {
+                let tip = basalt.interface_ref().new_bin();
+
+                // Bins can't be sized to their text, so the size is estimated from the amount of
+                // characters.
+                let text_w = text.chars().count() as f32 * theme.text_height * 0.6;
+                let max_w = (theme.max_width - (theme.pad * 2.0)).max(1.0);
+                let lines = (text_w / max_w).ceil().max(1.0);
+
+                tip.style_update(BinStyle {
+                    position: Some(BinPosition::Window),
+                    pos_from_t: Some(0.0),
+                    pos_from_l: Some(0.0),
+                    width: Some(text_w.min(max_w) + (theme.pad * 2.0)),
+                    height: Some((lines * theme.text_height * 1.25) + (theme.pad * 2.0)),
+                    pad_t: Some(theme.pad),
+                    pad_b: Some(theme.pad),
+                    pad_l: Some(theme.pad),
+                    pad_r: Some(theme.pad),
+                    back_color: Some(theme.back_color.clone()),
+                    border_size_t: Some(1.0),
+                    border_size_b: Some(1.0),
+                    border_size_l: Some(1.0),
+                    border_size_r: Some(1.0),
+                    border_color_t: Some(theme.border_color.clone()),
+                    border_color_b: Some(theme.border_color.clone()),
+                    border_color_l: Some(theme.border_color.clone()),
+                    border_color_r: Some(theme.border_color.clone()),
+                    text,
+                    text_color: Some(theme.text_color.clone()),
+                    text_height: Some(theme.text_height),
+                    text_wrap: Some(TextWrap::Normal),
+                    ..BinStyle::default()
+                })
+                .expect_valid();
+
+                tip
+            },
+            Tooltip::Bin(func) => func(&basalt),
+        };
+
+        let extent = basalt.window().inner_dimensions();
+        let scale = basalt.interface_ref().current_effective_scale();
+        let win_w = extent[0] as f32 / scale;
+        let win_h = extent[1] as f32 / scale;
+        let style = tip.style_copy();
+        let border_t = style.border_size_t.unwrap_or(0.0);
+        let border_l = style.border_size_l.unwrap_or(0.0);
+        let width = style.width.unwrap_or(0.0) + border_l + style.border_size_r.unwrap_or(0.0);
+        let height = style.height.unwrap_or(0.0) + border_t + style.border_size_b.unwrap_or(0.0);
+        let mut top = cursor[1] + theme.offset[1];
+        let left = cursor[0] + theme.offset[0];
+
+        // Display above the cursor if there isn't enough space below it.
+        if top + height > win_h {
+            top = cursor[1] - height - (theme.offset[1] - theme.text_height).max(0.0);
+        }
+
+        let top = top.min(win_h - height).max(0.0);
+        let left = left.min(win_w - width).max(0.0);
+
+        tip.style_update(BinStyle {
+            position: Some(BinPosition::Window),
+            pos_from_t: Some(top + border_t),
+            pos_from_b: None,
+            pos_from_l: Some(left + border_l),
+            pos_from_r: None,
+            ..tip.style_copy()
+        })
+        .expect_valid();
+
+        let overlays = basalt.interface_ref().overlays();
+        let overlay = overlays.show(tip, OverlayLayer::Tooltip);
+        let basalt_wk = Arc::downgrade(&basalt);
+
+        overlays.on_dismiss(overlay, move || {
+            if let Some(basalt) = basalt_wk.upgrade() {
+                let mut state = basalt.interface_ref().tooltips().state.lock();
+
+                if state.shown.as_ref().map(|shown| shown.overlay) == Some(overlay) {
+                    state.shown = None;
+                }
+            }
+        });
+
+        self.state.lock().shown = Some(Shown {
+            bin_id,
+            overlay,
+        });
+    }
+}
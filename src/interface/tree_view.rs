@@ -0,0 +1,702 @@
+use std::sync::{Arc, Weak};
+
+use parking_lot::Mutex;
+
+use crate::input::{InputHookCtrl, MouseButton, Qwerty};
+use crate::interface::bin::{
+    self, Bin, BinPosition, BinStyle, BinVert, KeepAlive, TextVertAlign, TextWrap,
+};
+use crate::interface::scroll_bar::{ScrollBar, ScrollBarStyle, ScrollTo};
+use crate::Basalt;
+
+impl KeepAlive for TreeView {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeViewTheme {
+    pub back_color: bin::Color,
+    pub selected_color: bin::Color,
+    pub text_color: bin::Color,
+    pub chevron_color: bin::Color,
+    pub text_height: f32,
+    pub row_height: f32,
+    /// Amount each level of the tree is indented by.
+    pub indent: f32,
+    pub scroll_bar: ScrollBarStyle,
+}
+
+impl Default for TreeViewTheme {
+    fn default() -> Self {
+        TreeViewTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            selected_color: bin::Color::srgb_hex("3a5a8c"),
+            text_color: bin::Color::srgb_hex("e0e0e0"),
+            chevron_color: bin::Color::srgb_hex("c0c0c0"),
+            text_height: 12.0,
+            row_height: 20.0,
+            indent: 16.0,
+            scroll_bar: ScrollBarStyle::default(),
+        }
+    }
+}
+
+/// A tree of expandable & collapsible nodes.
+///
+/// # Keyboard Navigation
+/// Once a node has been clicked, the tree will have focus.
+/// - `ArrowUp` & `ArrowDown` select the previous or next visible node.
+/// - `ArrowRight` expands the selected node or selects its first child if already expanded.
+/// - `ArrowLeft` collapses the selected node or selects its parent if already collapsed.
+pub struct TreeView {
+    pub basalt: Arc<Basalt>,
+    pub container: Arc<Bin>,
+    /// Scrolled area containing the rows of visible nodes.
+    pub view: Arc<Bin>,
+    pub scroll_bar: Arc<ScrollBar>,
+    theme: TreeViewTheme,
+    state: Mutex<TreeState>,
+    on_select: Mutex<Vec<Box<dyn FnMut(&Arc<TreeNode>) + Send + 'static>>>,
+    on_load_children: Mutex<Vec<Box<dyn FnMut(&Arc<TreeNode>) + Send + 'static>>>,
+}
+
+struct TreeState {
+    roots: Vec<Arc<TreeNode>>,
+    selected: Option<Weak<TreeNode>>,
+    visible: Vec<Arc<TreeNode>>,
+}
+
+/// A node of a `TreeView`.
+pub struct TreeNode {
+    tree: Weak<TreeView>,
+    pub row: Arc<Bin>,
+    pub chevron: Arc<Bin>,
+    pub label: Arc<Bin>,
+    state: Mutex<NodeState>,
+}
+
+struct NodeState {
+    label: String,
+    parent: Option<Weak<TreeNode>>,
+    children: Vec<Arc<TreeNode>>,
+    expanded: bool,
+    lazy: bool,
+    // Row index, depth, expanded, has children & selected of the last layout.
+    last_layout: Option<(usize, usize, bool, bool, bool)>,
+}
+
+impl TreeView {
+    pub fn new(basalt: Arc<Basalt>, theme: TreeViewTheme, parent: Option<Arc<Bin>>) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(2);
+        let container = bins.pop().unwrap();
+        let view = bins.pop().unwrap();
+        container.add_child(view.clone());
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                back_color: Some(theme.back_color.clone()),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        view.style_update(BinStyle {
+            position: Some(BinPosition::Parent),
+            pos_from_t: Some(0.0),
+            pos_from_b: Some(0.0),
+            pos_from_l: Some(0.0),
+            pos_from_r: Some(15.0),
+            ..BinStyle::default()
+        })
+        .expect_valid();
+
+        let scroll_bar = ScrollBar::new(
+            basalt.clone(),
+            Some(theme.scroll_bar.clone()),
+            Some(container.clone()),
+            view.clone(),
+        );
+
+        let tree = Arc::new(TreeView {
+            basalt,
+            container,
+            view,
+            scroll_bar,
+            theme,
+            state: Mutex::new(TreeState {
+                roots: Vec::new(),
+                selected: None,
+                visible: Vec::new(),
+            }),
+            on_select: Mutex::new(Vec::new()),
+            on_load_children: Mutex::new(Vec::new()),
+        });
+
+        for key in [
+            Qwerty::ArrowUp,
+            Qwerty::ArrowDown,
+            Qwerty::ArrowLeft,
+            Qwerty::ArrowRight,
+        ] {
+            let tree_wk = Arc::downgrade(&tree);
+
+            tree.view.on_press(key, move |_, _, _| {
+                match tree_wk.upgrade() {
+                    Some(tree) => {
+                        tree.navigate(key);
+                        Default::default()
+                    },
+                    None => InputHookCtrl::Remove,
+                }
+            });
+        }
+
+        tree
+    }
+
+    /// Add a node to the top level of the tree.
+    pub fn add_root<L: Into<String>>(self: &Arc<Self>, label: L) -> Arc<TreeNode> {
+        let node = TreeNode::new(self, label.into(), None);
+        self.state.lock().roots.push(node.clone());
+        self.relayout();
+        node
+    }
+
+    /// Remove a node from the top level of the tree.
+    pub fn remove_root(&self, node: &Arc<TreeNode>) {
+        self.state
+            .lock()
+            .roots
+            .retain(|root| !Arc::ptr_eq(root, node));
+        self.relayout();
+    }
+
+    /// Remove every node from the tree.
+    pub fn clear(&self) {
+        self.state.lock().roots.clear();
+        self.relayout();
+    }
+
+    pub fn roots(&self) -> Vec<Arc<TreeNode>> {
+        self.state.lock().roots.clone()
+    }
+
+    /// Nodes that are currently visible in the order they are displayed.
+    pub fn visible_nodes(&self) -> Vec<Arc<TreeNode>> {
+        self.state.lock().visible.clone()
+    }
+
+    pub fn selected(&self) -> Option<Arc<TreeNode>> {
+        self.state
+            .lock()
+            .selected
+            .as_ref()
+            .and_then(|selected| selected.upgrade())
+    }
+
+    /// Select a node, expanding its ancestors & scrolling it into view.
+    pub fn select(&self, node: &Arc<TreeNode>) {
+        let mut ancestor_op = node.parent();
+
+        while let Some(ancestor) = ancestor_op {
+            ancestor.state.lock().expanded = true;
+            ancestor_op = ancestor.parent();
+        }
+
+        self.state.lock().selected = Some(Arc::downgrade(node));
+        self.relayout();
+        self.scroll_into_view(node);
+
+        for func in self.on_select.lock().iter_mut() {
+            func(node);
+        }
+    }
+
+    /// Add a function to be called when a node is selected.
+    pub fn on_select<F: FnMut(&Arc<TreeNode>) + Send + 'static>(&self, func: F) {
+        self.on_select.lock().push(Box::new(func));
+    }
+
+    /// Add a function to be called when a lazy node is expanded for the first time.
+    ///
+    /// The function should add the children of the node. See `TreeNode::set_lazy`.
+    pub fn on_load_children<F: FnMut(&Arc<TreeNode>) + Send + 'static>(&self, func: F) {
+        self.on_load_children.lock().push(Box::new(func));
+    }
+
+    fn scroll_into_view(&self, node: &Arc<TreeNode>) {
+        let index = match self
+            .state
+            .lock()
+            .visible
+            .iter()
+            .position(|visible| Arc::ptr_eq(visible, node))
+        {
+            Some(some) => some,
+            None => return,
+        };
+
+        let top = index as f32 * self.theme.row_height;
+        let bottom = top + self.theme.row_height;
+        let post = self.view.post_update();
+        let view_h = post.bli[1] - post.tli[1];
+        let scroll_y = self.view.style().scroll_y.unwrap_or(0.0);
+
+        if top < scroll_y {
+            self.scroll_bar.update(ScrollTo::Set(top));
+        } else if bottom > scroll_y + view_h {
+            self.scroll_bar.update(ScrollTo::Set(bottom - view_h));
+        }
+    }
+
+    fn navigate(&self, key: Qwerty) {
+        let visible = self.visible_nodes();
+
+        let selected = match self.selected() {
+            Some(some) => some,
+            None => {
+                if let Some(first) = visible.first() {
+                    self.select(first);
+                }
+
+                return;
+            },
+        };
+
+        let index = match visible
+            .iter()
+            .position(|visible| Arc::ptr_eq(visible, &selected))
+        {
+            Some(some) => some,
+            None => return,
+        };
+
+        match key {
+            Qwerty::ArrowUp => {
+                if index > 0 {
+                    self.select(&visible[index - 1]);
+                }
+            },
+            Qwerty::ArrowDown => {
+                if let Some(next) = visible.get(index + 1) {
+                    self.select(next);
+                }
+            },
+            Qwerty::ArrowRight => {
+                if !selected.has_children() {
+                    return;
+                }
+
+                if selected.is_expanded() {
+                    if let Some(first) = selected.children().first() {
+                        self.select(first);
+                    }
+                } else {
+                    selected.expand();
+                }
+            },
+            Qwerty::ArrowLeft => {
+                if selected.is_expanded() {
+                    selected.collapse();
+                } else if let Some(parent) = selected.parent() {
+                    self.select(&parent);
+                }
+            },
+            _ => (),
+        }
+    }
+
+    fn relayout(&self) {
+        let mut state = self.state.lock();
+        let selected = state
+            .selected
+            .as_ref()
+            .and_then(|selected| selected.upgrade());
+        let mut visible = Vec::new();
+        let mut depths = Vec::new();
+        let mut stack: Vec<_> = state
+            .roots
+            .iter()
+            .rev()
+            .map(|root| (root.clone(), 0))
+            .collect();
+
+        while let Some((node, depth)) = stack.pop() {
+            let node_state = node.state.lock();
+
+            if node_state.expanded {
+                stack.extend(
+                    node_state
+                        .children
+                        .iter()
+                        .rev()
+                        .map(|child| (child.clone(), depth + 1)),
+                );
+            }
+
+            drop(node_state);
+            visible.push(node);
+            depths.push(depth);
+        }
+
+        let changed = visible.len() != state.visible.len()
+            || visible
+                .iter()
+                .zip(state.visible.iter())
+                .any(|(a, b)| !Arc::ptr_eq(a, b));
+
+        if changed {
+            self.view.take_children();
+            self.view
+                .add_children(visible.iter().map(|node| node.row.clone()).collect());
+        }
+
+        for (index, (node, depth)) in visible.iter().zip(depths).enumerate() {
+            let is_selected = selected
+                .as_ref()
+                .map(|selected| Arc::ptr_eq(selected, node))
+                .unwrap_or(false);
+
+            node.layout(&self.theme, index, depth, is_selected);
+        }
+
+        state.visible = visible;
+    }
+}
+
+impl TreeNode {
+    fn new(tree: &Arc<TreeView>, label: String, parent: Option<Weak<TreeNode>>) -> Arc<Self> {
+        let mut bins = tree.basalt.interface_ref().new_bins(3);
+        let row = bins.pop().unwrap();
+        let chevron = bins.pop().unwrap();
+        let label_bin = bins.pop().unwrap();
+        row.add_child(chevron.clone());
+        row.add_child(label_bin.clone());
+
+        let node = Arc::new(TreeNode {
+            tree: Arc::downgrade(tree),
+            row,
+            chevron,
+            label: label_bin,
+            state: Mutex::new(NodeState {
+                label,
+                parent,
+                children: Vec::new(),
+                expanded: false,
+                lazy: false,
+                last_layout: None,
+            }),
+        });
+
+        let node_wk = Arc::downgrade(&node);
+
+        node.chevron.on_press(MouseButton::Left, move |_, _, _| {
+            match node_wk.upgrade() {
+                Some(node) => {
+                    node.toggle();
+                    Default::default()
+                },
+                None => InputHookCtrl::Remove,
+            }
+        });
+
+        for bin in [node.row.clone(), node.label.clone()] {
+            let node_wk = Arc::downgrade(&node);
+
+            bin.on_press(MouseButton::Left, move |_, _, local| {
+                let node = match node_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                if let Some(tree) = node.tree.upgrade() {
+                    tree.basalt.input_ref().set_bin_focused(&tree.view);
+                    tree.select(&node);
+
+                    if local.click_count() == 2 {
+                        node.toggle();
+                    }
+                }
+
+                Default::default()
+            });
+        }
+
+        node
+    }
+
+    /// Add a child node.
+    pub fn add_child<L: Into<String>>(self: &Arc<Self>, label: L) -> Arc<TreeNode> {
+        self.add_children([label]).pop().unwrap()
+    }
+
+    /// Add multiple child nodes.
+    pub fn add_children<L: Into<String>, I: IntoIterator<Item = L>>(
+        self: &Arc<Self>,
+        labels: I,
+    ) -> Vec<Arc<TreeNode>> {
+        let tree = match self.tree.upgrade() {
+            Some(some) => some,
+            None => return Vec::new(),
+        };
+
+        let children: Vec<_> = labels
+            .into_iter()
+            .map(|label| TreeNode::new(&tree, label.into(), Some(Arc::downgrade(self))))
+            .collect();
+
+        self.state.lock().children.extend(children.iter().cloned());
+        tree.relayout();
+        children
+    }
+
+    /// Remove a child node.
+    pub fn remove_child(&self, child: &Arc<TreeNode>) {
+        self.state
+            .lock()
+            .children
+            .retain(|node| !Arc::ptr_eq(node, child));
+        self.relayout();
+    }
+
+    /// Remove every child node.
+    ///
+    /// # Notes
+    /// - If the node is lazy, children will be loaded again the next time it is expanded.
+    pub fn clear_children(&self) {
+        let mut state = self.state.lock();
+        state.children.clear();
+
+        if state.lazy {
+            state.expanded = false;
+        }
+
+        drop(state);
+        self.relayout();
+    }
+
+    pub fn children(&self) -> Vec<Arc<TreeNode>> {
+        self.state.lock().children.clone()
+    }
+
+    pub fn parent(&self) -> Option<Arc<TreeNode>> {
+        self.state
+            .lock()
+            .parent
+            .as_ref()
+            .and_then(|parent| parent.upgrade())
+    }
+
+    pub fn label_text(&self) -> String {
+        self.state.lock().label.clone()
+    }
+
+    pub fn set_label<L: Into<String>>(&self, label: L) {
+        let mut state = self.state.lock();
+        state.label = label.into();
+
+        self.label
+            .style_update(BinStyle {
+                text: state.label.clone(),
+                ..self.label.style_copy()
+            })
+            .expect_valid();
+    }
+
+    /// Mark this node as having children that are loaded when it is first expanded.
+    ///
+    /// See `TreeView::on_load_children`.
+    pub fn set_lazy(&self, lazy: bool) {
+        self.state.lock().lazy = lazy;
+        self.relayout();
+    }
+
+    /// Returns true if the node has children or is lazy.
+    pub fn has_children(&self) -> bool {
+        let state = self.state.lock();
+        state.lazy || !state.children.is_empty()
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        self.state.lock().expanded
+    }
+
+    pub fn expand(self: &Arc<Self>) {
+        let load = {
+            let mut state = self.state.lock();
+
+            if state.expanded || (!state.lazy && state.children.is_empty()) {
+                return;
+            }
+
+            state.expanded = true;
+            state.lazy && state.children.is_empty()
+        };
+
+        if let Some(tree) = self.tree.upgrade() {
+            if load {
+                for func in tree.on_load_children.lock().iter_mut() {
+                    func(self);
+                }
+            }
+
+            tree.relayout();
+        }
+    }
+
+    pub fn collapse(&self) {
+        {
+            let mut state = self.state.lock();
+
+            if !state.expanded {
+                return;
+            }
+
+            state.expanded = false;
+        }
+
+        if let Some(tree) = self.tree.upgrade() {
+            // Move the selection out of the collapsed children.
+            let selected_is_descendant = tree
+                .selected()
+                .map(|selected| {
+                    let mut ancestor_op = selected.parent();
+
+                    while let Some(ancestor) = ancestor_op {
+                        if std::ptr::eq(ancestor.as_ref(), self) {
+                            return true;
+                        }
+
+                        ancestor_op = ancestor.parent();
+                    }
+
+                    false
+                })
+                .unwrap_or(false);
+
+            if selected_is_descendant {
+                tree.state.lock().selected = None;
+            }
+
+            tree.relayout();
+        }
+    }
+
+    pub fn toggle(self: &Arc<Self>) {
+        if self.is_expanded() {
+            self.collapse();
+        } else {
+            self.expand();
+        }
+    }
+
+    fn relayout(&self) {
+        if let Some(tree) = self.tree.upgrade() {
+            tree.relayout();
+        }
+    }
+
+    fn layout(&self, theme: &TreeViewTheme, index: usize, depth: usize, selected: bool) {
+        let mut state = self.state.lock();
+        let has_children = state.lazy || !state.children.is_empty();
+        let layout = (index, depth, state.expanded, has_children, selected);
+
+        if state.last_layout == Some(layout) {
+            return;
+        }
+
+        let indent = depth as f32 * theme.indent;
+        let size = theme.row_height;
+        let c = size / 2.0;
+
+        self.row
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(index as f32 * size),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                height: Some(size),
+                back_color: match selected {
+                    true => Some(theme.selected_color.clone()),
+                    false => None,
+                },
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        let custom_verts = match (has_children, state.expanded) {
+            (false, _) => Vec::new(),
+            (true, false) => {
+                vec![
+                    BinVert {
+                        position: (c - 2.0, c - 4.0, 0),
+                        color: theme.chevron_color.clone(),
+                    },
+                    BinVert {
+                        position: (c - 2.0, c + 4.0, 0),
+                        color: theme.chevron_color.clone(),
+                    },
+                    BinVert {
+                        position: (c + 3.0, c, 0),
+                        color: theme.chevron_color.clone(),
+                    },
+                ]
+            },
+            (true, true) => {
+                vec![
+                    BinVert {
+                        position: (c + 4.0, c - 2.0, 0),
+                        color: theme.chevron_color.clone(),
+                    },
+                    BinVert {
+                        position: (c - 4.0, c - 2.0, 0),
+                        color: theme.chevron_color.clone(),
+                    },
+                    BinVert {
+                        position: (c, c + 3.0, 0),
+                        color: theme.chevron_color.clone(),
+                    },
+                ]
+            },
+        };
+
+        self.chevron
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(indent),
+                width: Some(size),
+                height: Some(size),
+                custom_verts,
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        self.label
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(indent + size),
+                pos_from_r: Some(0.0),
+                text: state.label.clone(),
+                text_color: Some(theme.text_color.clone()),
+                text_height: Some(theme.text_height),
+                text_vert_align: Some(TextVertAlign::Center),
+                text_wrap: Some(TextWrap::None),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        state.last_layout = Some(layout);
+    }
+}
@@ -0,0 +1,543 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::interface::bin::{self, Bin, BinPosition, BinStyle, KeepAlive};
+use crate::interface::scroll_bar::{ScrollBar, ScrollBarStyle, ScrollTo};
+use crate::Basalt;
+
+impl KeepAlive for VirtualList {}
+
+/// Provides the rows of a `VirtualList`.
+pub trait VirtualListSource: Send + Sync {
+    /// The total amount of rows.
+    fn row_count(&self) -> usize;
+
+    /// The height of every row.
+    ///
+    /// Return `None` to have each row measured individually with `measure_row`.
+    fn row_height(&self) -> Option<f32>;
+
+    /// The height of an individual row.
+    ///
+    /// # Notes
+    /// - Only called if `row_height` returns `None`.
+    /// - Called for every row when the list is created or `reload` is called.
+    fn measure_row(&self, _index: usize) -> f32 {
+        0.0
+    }
+
+    /// Bind the data of a row to a `Bin`.
+    ///
+    /// # Notes
+    /// - `Bin`'s are recycled, so any previous content should be replaced.
+    /// - Position & size fields of the style are controlled by the list and will be overwritten.
+    fn bind_row(&self, index: usize, row: &Arc<Bin>);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualListTheme {
+    /// Color of the area behind the rows.
+    pub back_color: bin::Color,
+    /// Amount of rows to keep bound beyond the visible area in each direction.
+    pub overscan: usize,
+    /// Style of the scroll bar.
+    pub scroll_bar: ScrollBarStyle,
+}
+
+impl Default for VirtualListTheme {
+    fn default() -> Self {
+        VirtualListTheme {
+            back_color: bin::Color::srgb_hex("2a2a30"),
+            overscan: 4,
+            scroll_bar: ScrollBarStyle::default(),
+        }
+    }
+}
+
+/// A scrollable list that only keeps `Bin`'s for the rows that are visible.
+///
+/// Rows are provided by a `VirtualListSource`. As the list is scrolled, `Bin`'s of rows that leave
+/// the visible area are recycled for the rows that enter it.
+pub struct VirtualList {
+    pub basalt: Arc<Basalt>,
+    /// Outer container of the list.
+    pub container: Arc<Bin>,
+    /// Scrolled area containing the rows.
+    pub view: Arc<Bin>,
+    pub scroll_bar: Arc<ScrollBar>,
+    spacer: Arc<Bin>,
+    source: Mutex<Arc<dyn VirtualListSource>>,
+    state: Mutex<State>,
+}
+
+struct State {
+    count: usize,
+    row_height: Option<f32>,
+    offsets: Vec<f32>,
+    overscan: usize,
+    rows: Vec<(usize, Arc<Bin>)>,
+    free: Vec<Arc<Bin>>,
+}
+
+impl State {
+    fn offset(&self, index: usize) -> f32 {
+        match self.row_height {
+            Some(height) => index as f32 * height,
+            None => self.offsets[index],
+        }
+    }
+
+    fn height(&self, index: usize) -> f32 {
+        match self.row_height {
+            Some(height) => height,
+            None => self.offsets[index + 1] - self.offsets[index],
+        }
+    }
+
+    fn total_height(&self) -> f32 {
+        self.offset(self.count)
+    }
+
+    fn index_at(&self, y: f32) -> usize {
+        if self.count == 0 || y <= 0.0 {
+            return 0;
+        }
+
+        let index = match self.row_height {
+            Some(height) if height > 0.0 => (y / height).floor() as usize,
+            Some(_) => 0,
+            None => {
+                self.offsets
+                    .partition_point(|offset| *offset <= y)
+                    .saturating_sub(1)
+            },
+        };
+
+        index.min(self.count - 1)
+    }
+
+    fn measure(&mut self, source: &Arc<dyn VirtualListSource>) {
+        self.count = source.row_count();
+        self.row_height = source.row_height();
+        self.offsets.clear();
+
+        if self.row_height.is_none() {
+            self.offsets.reserve(self.count + 1);
+            let mut offset = 0.0;
+            self.offsets.push(offset);
+
+            for i in 0..self.count {
+                offset += source.measure_row(i).max(0.0);
+                self.offsets.push(offset);
+            }
+        }
+    }
+}
+
+impl VirtualList {
+    pub fn new(
+        basalt: Arc<Basalt>,
+        theme: VirtualListTheme,
+        parent: Option<Arc<Bin>>,
+        source: Arc<dyn VirtualListSource>,
+    ) -> Arc<Self> {
+        let mut bins = basalt.interface_ref().new_bins(3);
+        let container = bins.pop().unwrap();
+        let view = bins.pop().unwrap();
+        let spacer = bins.pop().unwrap();
+        container.add_child(view.clone());
+        view.add_child(spacer.clone());
+
+        if let Some(parent) = parent.as_ref() {
+            parent.add_child(container.clone());
+        }
+
+        container
+            .style_update(BinStyle {
+                position: Some(match parent.is_some() {
+                    true => BinPosition::Parent,
+                    false => BinPosition::Window,
+                }),
+                pos_from_t: Some(0.0),
+                pos_from_b: Some(0.0),
+                pos_from_l: Some(0.0),
+                pos_from_r: Some(0.0),
+                back_color: Some(theme.back_color),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+
+        view.style_update(BinStyle {
+            position: Some(BinPosition::Parent),
+            pos_from_t: Some(0.0),
+            pos_from_b: Some(0.0),
+            pos_from_l: Some(0.0),
+            pos_from_r: Some(15.0),
+            ..BinStyle::default()
+        })
+        .expect_valid();
+
+        let scroll_bar = ScrollBar::new(
+            basalt.clone(),
+            Some(theme.scroll_bar),
+            Some(container.clone()),
+            view.clone(),
+        );
+
+        let mut state = State {
+            count: 0,
+            row_height: None,
+            offsets: Vec::new(),
+            overscan: theme.overscan,
+            rows: Vec::new(),
+            free: Vec::new(),
+        };
+
+        state.measure(&source);
+
+        let list = Arc::new(VirtualList {
+            basalt,
+            container,
+            view,
+            scroll_bar,
+            spacer,
+            source: Mutex::new(source),
+            state: Mutex::new(state),
+        });
+
+        list.update_spacer();
+        let list_wk = Arc::downgrade(&list);
+
+        // Scrolling & resizing both cause the view to update. Rows can't be added to the view
+        // while its hooks are being called, so the refresh is deferred to the spacer's update.
+        list.view.on_update(move |_, _| {
+            if let Some(list) = list_wk.upgrade() {
+                list.spacer.force_update();
+                let list_wk = Arc::downgrade(&list);
+
+                list.spacer.on_update_once(move |_, _| {
+                    if let Some(list) = list_wk.upgrade() {
+                        list.refresh(false);
+                    }
+                });
+            }
+        });
+
+        list
+    }
+
+    /// Replace the source of the rows. This will rebind every row.
+    pub fn set_source(&self, source: Arc<dyn VirtualListSource>) {
+        *self.source.lock() = source;
+        self.reload();
+    }
+
+    /// Remeasure & rebind every row.
+    ///
+    /// Should be called when the row count or row heights of the source change.
+    pub fn reload(&self) {
+        let source = self.source.lock().clone();
+        self.state.lock().measure(&source);
+        self.update_spacer();
+        self.scroll_bar.update(ScrollTo::Same);
+        self.refresh(true);
+    }
+
+    /// Rebind a row if it is currently bound.
+    ///
+    /// Should be called when the content of a row changes, but not its height.
+    pub fn reload_row(&self, index: usize) {
+        if let Some(row) = self.row_bin(index) {
+            let source = self.source.lock().clone();
+            source.bind_row(index, &row);
+            self.place_row(index, &row);
+        }
+    }
+
+    /// Set the amount of rows to keep bound beyond the visible area in each direction.
+    pub fn set_overscan(&self, overscan: usize) {
+        self.state.lock().overscan = overscan;
+        self.refresh(false);
+    }
+
+    pub fn overscan(&self) -> usize {
+        self.state.lock().overscan
+    }
+
+    /// The total amount of rows.
+    pub fn row_count(&self) -> usize {
+        self.state.lock().count
+    }
+
+    /// The `Bin` currently bound to the row, if any.
+    pub fn row_bin(&self, index: usize) -> Option<Arc<Bin>> {
+        self.state
+            .lock()
+            .rows
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, row)| row.clone())
+    }
+
+    /// The range of rows that are currently visible, excluding overscan.
+    pub fn visible_range(&self) -> Range<usize> {
+        let state = self.state.lock();
+        let (first, last) = self.visible_rows(&state);
+        first..last
+    }
+
+    /// Scroll so that the row is at the top of the view.
+    pub fn scroll_to_row(&self, index: usize) {
+        let offset = {
+            let state = self.state.lock();
+
+            if state.count == 0 {
+                return;
+            }
+
+            state.offset(index.min(state.count - 1))
+        };
+
+        self.scroll_bar.update(ScrollTo::Set(offset));
+    }
+
+    /// Scroll the least amount needed for the row to be fully visible.
+    pub fn scroll_into_view(&self, index: usize) {
+        let (top, bottom) = {
+            let state = self.state.lock();
+
+            if state.count == 0 {
+                return;
+            }
+
+            let index = index.min(state.count - 1);
+            let top = state.offset(index);
+            (top, top + state.height(index))
+        };
+
+        let post = self.view.post_update();
+        let view_h = post.bli[1] - post.tli[1];
+        let scroll_y = self.view.style().scroll_y.unwrap_or(0.0);
+
+        if top < scroll_y {
+            self.scroll_bar.update(ScrollTo::Set(top));
+        } else if bottom > scroll_y + view_h {
+            self.scroll_bar.update(ScrollTo::Set(bottom - view_h));
+        }
+    }
+
+    fn visible_rows(&self, state: &State) -> (usize, usize) {
+        if state.count == 0 {
+            return (0, 0);
+        }
+
+        let post = self.view.post_update();
+        let view_h = post.bli[1] - post.tli[1];
+        let scroll_y = self.view.style().scroll_y.unwrap_or(0.0);
+        let first = state.index_at(scroll_y);
+        let last = state.index_at(scroll_y + view_h.max(0.0)) + 1;
+        (first, last)
+    }
+
+    fn update_spacer(&self) {
+        let total_height = self.state.lock().total_height();
+
+        self.spacer
+            .style_update(BinStyle {
+                position: Some(BinPosition::Parent),
+                pos_from_t: Some(0.0),
+                pos_from_l: Some(0.0),
+                width: Some(1.0),
+                height: Some(total_height),
+                ..BinStyle::default()
+            })
+            .expect_valid();
+    }
+
+    fn refresh(&self, rebind_all: bool) {
+        let mut state = self.state.lock();
+        let (first, last) = self.visible_rows(&state);
+        let start = first.saturating_sub(state.overscan);
+        let end = (last + state.overscan).min(state.count);
+        let mut keep = Vec::with_capacity(end - start);
+
+        for (index, row) in state.rows.split_off(0) {
+            if index >= start && index < end && !rebind_all {
+                keep.push((index, row));
+            } else {
+                state.free.push(row);
+            }
+        }
+
+        let mut bind = Vec::new();
+
+        for index in start..end {
+            if keep.iter().any(|(i, _)| *i == index) {
+                continue;
+            }
+
+            let row = match state.free.pop() {
+                Some(some) => some,
+                None => {
+                    let row = self.basalt.interface_ref().new_bin();
+                    self.view.add_child(row.clone());
+                    row
+                },
+            };
+
+            keep.push((index, row.clone()));
+            bind.push((index, row));
+        }
+
+        for row in state.free.iter() {
+            if row.style().hidden != Some(true) {
+                row.style_update(BinStyle {
+                    hidden: Some(true),
+                    ..row.style_copy()
+                })
+                .expect_valid();
+            }
+        }
+
+        state.rows = keep;
+        drop(state);
+
+        if bind.is_empty() {
+            return;
+        }
+
+        let source = self.source.lock().clone();
+
+        for (index, row) in bind {
+            source.bind_row(index, &row);
+            self.place_row(index, &row);
+        }
+    }
+
+    fn place_row(&self, index: usize, row: &Arc<Bin>) {
+        let (top, height) = {
+            let state = self.state.lock();
+
+            if index >= state.count {
+                return;
+            }
+
+            (state.offset(index), state.height(index))
+        };
+
+        row.style_update(BinStyle {
+            position: Some(BinPosition::Parent),
+            hidden: None,
+            pos_from_t: Some(top),
+            pos_from_b: None,
+            pos_from_l: Some(0.0),
+            pos_from_r: Some(0.0),
+            pos_from_t_pct: None,
+            pos_from_b_pct: None,
+            pos_from_l_pct: None,
+            pos_from_r_pct: None,
+            pos_from_l_offset: None,
+            pos_from_t_offset: None,
+            pos_from_r_offset: None,
+            pos_from_b_offset: None,
+            width: None,
+            width_pct: None,
+            width_offset: None,
+            height: Some(height),
+            height_pct: None,
+            height_offset: None,
+            ..row.style_copy()
+        })
+        .expect_valid();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{State, VirtualListSource};
+    use crate::interface::bin::Bin;
+
+    struct Source {
+        heights: Vec<f32>,
+        fixed: Option<f32>,
+    }
+
+    impl VirtualListSource for Source {
+        fn row_count(&self) -> usize {
+            self.heights.len()
+        }
+
+        fn row_height(&self) -> Option<f32> {
+            self.fixed
+        }
+
+        fn measure_row(&self, index: usize) -> f32 {
+            self.heights[index]
+        }
+
+        fn bind_row(&self, _index: usize, _row: &Arc<Bin>) {}
+    }
+
+    fn measured(heights: Vec<f32>, fixed: Option<f32>) -> State {
+        let source: Arc<dyn VirtualListSource> = Arc::new(Source {
+            heights,
+            fixed,
+        });
+
+        let mut state = State {
+            count: 0,
+            row_height: None,
+            offsets: Vec::new(),
+            overscan: 0,
+            rows: Vec::new(),
+            free: Vec::new(),
+        };
+
+        state.measure(&source);
+        state
+    }
+
+    #[test]
+    fn fixed_height() {
+        let state = measured(vec![0.0; 10], Some(20.0));
+        assert_eq!(state.total_height(), 200.0);
+        assert_eq!(state.offset(3), 60.0);
+        assert_eq!(state.height(3), 20.0);
+        assert_eq!(state.index_at(-5.0), 0);
+        assert_eq!(state.index_at(19.9), 0);
+        assert_eq!(state.index_at(20.0), 1);
+        assert_eq!(state.index_at(199.0), 9);
+        assert_eq!(state.index_at(1000.0), 9);
+    }
+
+    #[test]
+    fn measured_heights() {
+        let state = measured(vec![10.0, 0.0, 30.0, -5.0, 20.0], None);
+        assert_eq!(state.offsets, vec![0.0, 10.0, 10.0, 40.0, 40.0, 60.0]);
+        assert_eq!(state.total_height(), 60.0);
+        assert_eq!(state.height(2), 30.0);
+        assert_eq!(state.height(3), 0.0);
+        assert_eq!(state.index_at(5.0), 0);
+        // Zero height rows are skipped over.
+        assert_eq!(state.index_at(10.0), 2);
+        assert_eq!(state.index_at(39.9), 2);
+        assert_eq!(state.index_at(40.0), 4);
+        assert_eq!(state.index_at(60.0), 4);
+        assert_eq!(state.index_at(1000.0), 4);
+    }
+
+    #[test]
+    fn empty() {
+        let state = measured(Vec::new(), None);
+        assert_eq!(state.total_height(), 0.0);
+        assert_eq!(state.index_at(50.0), 0);
+
+        let state = measured(vec![0.0; 3], Some(0.0));
+        assert_eq!(state.total_height(), 0.0);
+        assert_eq!(state.index_at(50.0), 0);
+    }
+}
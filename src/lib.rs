@@ -1181,6 +1181,7 @@ impl Basalt {
         }
 
         basalt_ret.interface.attach_basalt(basalt_ret.clone());
+        basalt_ret.input.attach_basalt(&basalt_ret);
         let is_app_loop = basalt_ret.options.app_loop;
 
         basalt_ret
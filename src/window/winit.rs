@@ -16,13 +16,14 @@ use super::{
     BasaltWindow, BstWindowID, FullScreenBehavior, FullScreenError, Monitor, MonitorHandle,
     MonitorMode, MonitorModeHandle, WindowType,
 };
-use crate::input::{InputEvent, InputHookID, MouseButton, Qwerty};
+use crate::input::{InputEvent, InputHookID, Key, LogicalKey, MouseButton, Qwerty};
 use crate::{Basalt, BstEvent, BstOptions, BstWinEv};
 
 mod winit_ty {
     pub use winit::dpi::PhysicalSize;
     pub use winit::event::{
-        DeviceEvent, ElementState, Event, KeyboardInput, MouseButton, MouseScrollDelta, WindowEvent,
+        DeviceEvent, ElementState, Event, KeyboardInput, MouseButton, MouseScrollDelta,
+        VirtualKeyCode, WindowEvent,
     };
     pub use winit::event_loop::{ControlFlow, EventLoop};
     pub use winit::monitor::MonitorHandle;
@@ -560,10 +561,33 @@ pub fn open_surface(
     };
 
     let window_type = *window.window_type.lock();
+    let mut unmapped_press: Option<Qwerty> = None;
 
     event_loop.run(move |event: winit_ty::Event<'_, ()>, _, control_flow| {
         *control_flow = winit_ty::ControlFlow::Wait;
 
+        // The press of a key without a known meaning is held until the next event, so that the
+        // character it produces can be used as its meaning when that event is the character.
+        if let Some(physical) = unmapped_press.take() {
+            if let winit_ty::Event::WindowEvent {
+                event: winit_ty::WindowEvent::ReceivedCharacter(c),
+                ..
+            } = &event
+            {
+                if !c.is_control() {
+                    basalt.input_ref().set_key_layout(
+                        physical,
+                        LogicalKey::Char(c.to_lowercase().next().unwrap_or(*c)),
+                    );
+                }
+            }
+
+            basalt.input_ref().send_event(InputEvent::Press {
+                win: window.id(),
+                key: Key::Keyboard(physical),
+            });
+        }
+
         match event {
             winit_ty::Event::WindowEvent {
                 event: winit_ty::WindowEvent::CloseRequested,
@@ -594,6 +618,7 @@ pub fn open_surface(
                             winit_ty::KeyboardInput {
                                 scancode,
                                 state,
+                                virtual_keycode,
                                 ..
                             },
                         ..
@@ -607,17 +632,33 @@ pub fn open_surface(
                     }
                 }
 
+                let physical = Qwerty::from(scancode);
+
                 match state {
                     winit_ty::ElementState::Pressed => {
+                        match virtual_keycode.and_then(logical_key_from_winit) {
+                            Some(logical) => basalt.input_ref().set_key_layout(physical, logical),
+                            None => {
+                                if let LogicalKey::Char(_) = LogicalKey::from(physical) {
+                                    unmapped_press = Some(physical);
+                                    return;
+                                }
+
+                                basalt
+                                    .input_ref()
+                                    .set_key_layout(physical, LogicalKey::Named(physical));
+                            },
+                        }
+
                         basalt.input_ref().send_event(InputEvent::Press {
                             win: window.id(),
-                            key: Qwerty::from(scancode).into(),
+                            key: Key::Keyboard(physical),
                         });
                     },
                     winit_ty::ElementState::Released => {
                         basalt.input_ref().send_event(InputEvent::Release {
                             win: window.id(),
-                            key: Qwerty::from(scancode).into(),
+                            key: Key::Keyboard(physical),
                         });
                     },
                 }
@@ -803,3 +844,65 @@ pub fn open_surface(
         }
     });
 }
+
+fn logical_key_from_winit(virtual_keycode: winit_ty::VirtualKeyCode) -> Option<LogicalKey> {
+    use winit_ty::VirtualKeyCode as Vkc;
+
+    let c = match virtual_keycode {
+        Vkc::Key1 => '1',
+        Vkc::Key2 => '2',
+        Vkc::Key3 => '3',
+        Vkc::Key4 => '4',
+        Vkc::Key5 => '5',
+        Vkc::Key6 => '6',
+        Vkc::Key7 => '7',
+        Vkc::Key8 => '8',
+        Vkc::Key9 => '9',
+        Vkc::Key0 => '0',
+        Vkc::A => 'a',
+        Vkc::B => 'b',
+        Vkc::C => 'c',
+        Vkc::D => 'd',
+        Vkc::E => 'e',
+        Vkc::F => 'f',
+        Vkc::G => 'g',
+        Vkc::H => 'h',
+        Vkc::I => 'i',
+        Vkc::J => 'j',
+        Vkc::K => 'k',
+        Vkc::L => 'l',
+        Vkc::M => 'm',
+        Vkc::N => 'n',
+        Vkc::O => 'o',
+        Vkc::P => 'p',
+        Vkc::Q => 'q',
+        Vkc::R => 'r',
+        Vkc::S => 's',
+        Vkc::T => 't',
+        Vkc::U => 'u',
+        Vkc::V => 'v',
+        Vkc::W => 'w',
+        Vkc::X => 'x',
+        Vkc::Y => 'y',
+        Vkc::Z => 'z',
+        Vkc::Grave => '`',
+        Vkc::Minus => '-',
+        Vkc::Equals => '=',
+        Vkc::LBracket => '[',
+        Vkc::RBracket => ']',
+        Vkc::Backslash => '\\',
+        Vkc::Semicolon => ';',
+        Vkc::Apostrophe => '\'',
+        Vkc::Comma => ',',
+        Vkc::Period => '.',
+        Vkc::Slash => '/',
+        Vkc::Colon => ':',
+        Vkc::Asterisk => '*',
+        Vkc::Plus => '+',
+        Vkc::At => '@',
+        Vkc::Caret => '^',
+        _ => return None,
+    };
+
+    Some(LogicalKey::Char(c))
+}